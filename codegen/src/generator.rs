@@ -20,25 +20,144 @@ impl Module {
             use std::error::Error;
             use std::fmt;
 
-            use serde_json;
+            use crate::requests::{{{multipart}RequestBody, SlackWebRequest, SlackWebRequestSender}};
 
-            use ::requests::SlackWebRequestSender;
+            {methods}
 
-            {methods}",
+            /// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+            /// client.
+            pub struct Methods<'a, S> {{
+                slack: &'a crate::Slack<S>,
+            }}
+
+            impl<S> crate::Slack<S> {{
+                /// Returns the methods of the [`{name}`]({name}/index.html) module.
+                pub fn {name}(&self) -> Methods<'_, S> {{
+                    Methods {{ slack: self }}
+                }}
+            }}
+
+            impl<'a, S> Methods<'a, S>
+                where S: SlackWebRequestSender
+            {{
+                {client_methods}
+            }}",
             docs = self.description.as_ref().map(|d| format_docs("//!", d)).unwrap_or_default(),
+            multipart = if self.has_multipart() { "MultipartFile, " } else { "" },
             methods = self.methods
                 .iter()
                 .map(Method::generate)
                 .collect::<Vec<String>>()
+                .join("\n"),
+            name = self.get_safe_name(),
+            client_methods = self.methods
+                .iter()
+                .map(Method::generate_client_method)
+                .collect::<Vec<String>>()
+                .join("\n")
+        )
+    }
+
+    pub fn generate_async(&self) -> String {
+        format!(
+            "{docs}
+
+            pub use crate::{name}::*;
+
+            use crate::requests::{{AsyncSlackWebRequestSender, {multipart}RequestBody, SlackWebRequest}};
+
+            {methods}",
+            docs = self.description.as_ref().map(|d| format_docs("//!", d)).unwrap_or_default(),
+            multipart = if self.has_multipart() { "MultipartFile, " } else { "" },
+            name = self.get_safe_name(),
+            methods = self.methods
+                .iter()
+                .map(Method::generate_async)
+                .collect::<Vec<String>>()
                 .join("\n")
         )
     }
 
+    fn has_multipart(&self) -> bool {
+        self.methods.iter().any(|m| m.multipart_param().is_some())
+    }
+
     pub fn get_safe_name(&self) -> String {
         self.name.replace('.', "_")
     }
+
+    /// Adds the parameters that the schemas do not describe yet, and corrects the docs of those
+    /// that take an ID where the schemas also allow a name. The channels that Slack does take by
+    /// name are given a `ChannelRef` instead.
+    pub fn add_missing_params(&mut self) {
+        for method in self.methods.iter_mut().filter(|m| BLOCKS_METHODS.contains(&&m.name[..])) {
+            let i = method.params.iter().position(|p| p.name == "attachments").map_or(method.params.len(), |i| i + 1);
+            method.params.insert(i, Param {
+                name: "blocks".into(),
+                description: "Layout blocks of the message.".into(),
+                ty: "string".into(),
+                optional: true,
+            });
+            // a message of only attachments or blocks has no text
+            for text in method.params.iter_mut().filter(|p| p.name == "text") {
+                text.optional = true;
+                text.description = if text.description.contains("only attachments") {
+                    text.description.replace("only attachments", "only attachments or blocks")
+                } else {
+                    text.description.clone() + " Can be left out when the message has attachments or blocks."
+                };
+            }
+        }
+        for method in self.methods.iter_mut().filter(|m| ITEM_METHODS.contains(&&m.name[..])) {
+            method.merge_item_params();
+        }
+        for param in self.methods.iter_mut().flat_map(|m| m.params.iter_mut()).filter(|p| p.typed().is_some()) {
+            if param.name == "channel" && param.description.contains(NAME_ALLOWED) {
+                param.ty = "channel_or_name".into();
+                param.description = param.description.replace(NAME_ALLOWED, " Can be an ID, or a name such as `#general`.");
+            } else {
+                param.description = param.description.replace(NAME_ALLOWED, " Must be an ID.");
+            }
+        }
+    }
 }
 
+/// How the schemas describe a parameter that can be given an ID or a name.
+const NAME_ALLOWED: &'static str = " Can be an encoded ID, or a name.";
+
+/// Methods that are sent with a JSON body instead of a form, because they take JSON-encoded
+/// arguments that would otherwise have to be encoded twice.
+const JSON_BODY_METHODS: &'static [&'static str] = &["chat.postMessage", "chat.update", "chat.unfurl"];
+
+/// Parameters of JSON body methods that take a typed value, which is serialized into the body, and
+/// the type they take.
+const JSON_PARAMS: &'static [(&'static str, &'static str)] = &[
+    ("attachments", "[crate::attachments::Attachment]"), ("blocks", "[crate::blocks::Block]"),
+    ("unfurls", "crate::attachments::Unfurls"),
+];
+
+/// Methods that take layout blocks, which the schemas do not describe yet.
+const BLOCKS_METHODS: &'static [&'static str] = &["chat.postMessage", "chat.update"];
+
+/// Methods that take one starred, pinned or reacted item as an `ItemRef`, instead of the `file`,
+/// `file_comment`, `channel` and `timestamp` parameters that identify it.
+const ITEM_METHODS: &'static [&'static str] = &[
+    "pins.add", "pins.remove", "reactions.add", "reactions.get", "reactions.remove", "stars.add", "stars.remove",
+];
+
+/// Methods that respond with the item they were given, flattened next to `ok`.
+const ITEM_RESPONSE_METHODS: &'static [&'static str] = &["reactions.get"];
+
+/// Methods that upload a file, and the parameter the file is sent as in a multipart body.
+const MULTIPART_PARAMS: &'static [(&'static str, &'static str)] = &[("files.upload", "file"), ("users.setPhoto", "image")];
+
+/// Parameters that take an ID or a timestamp rather than any string, and the type they take.
+const TYPED_PARAMS: &'static [(&'static str, &'static str)] = &[
+    ("channel", "ChannelId"), ("user", "UserId"), ("file", "FileId"), ("file_comment", "FileCommentId"),
+    ("bot", "BotId"), ("usergroup", "UsergroupId"),
+    ("ts", "Ts"), ("timestamp", "Ts"), ("latest", "Ts"), ("oldest", "Ts"), ("thread_ts", "Ts"),
+];
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct Method {
     pub name: String,
@@ -51,34 +170,186 @@ pub struct Method {
 
 impl Method {
     pub fn generate(&self) -> String {
-        // HACK: these methods requires multipart support, which is not yet supported by this library
-        if self.name == "files.upload" || self.name == "users.setPhoto" {
-            return String::new();
+        let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
+        let request_struct_name = type_prefix.clone() + "Request";
+        let response_struct_name = type_prefix.clone() + "Response";
+        let error_enum_name = type_prefix.clone() + "Error";
+
+        let request = if self.has_request_struct() {
+            format!("{}\n\n{}", self.get_request_struct(&request_struct_name), self.get_request_builder(&request_struct_name))
+        } else {
+            String::new()
+        };
+
+        format!("\
+            {function}
+
+            {request}
+
+            {response}
+            ",
+            function = self.generate_fn(false),
+            request = request,
+            response = if ITEM_RESPONSE_METHODS.contains(&&self.name[..]) {
+                self.response.generate_item(&response_struct_name, &error_enum_name)
+            } else {
+                self.response.generate(&response_struct_name, &error_enum_name)
+            }
+        )
+    }
+
+    pub fn generate_async(&self) -> String {
+        self.generate_fn(true)
+    }
+
+    /// Generates the method of the module's `Methods` struct that calls this method's function.
+    pub fn generate_client_method(&self) -> String {
+        let fn_name = self.name.split('.').last().unwrap().to_snake_case();
+        let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
+        let has_token = self.params.iter().any(|p| p.ty == "auth_token");
+        let mut args = vec!["self.slack.sender()"];
+        if has_token {
+            args.push("self.slack.token()");
+        }
+        if self.has_request_struct() {
+            args.push("request");
         }
+        format!("\
+            {documentation}pub fn {method_name}(&self{request}) -> Result<{response_type}, {error_type}<S::Error>> {{
+                {method_name}({args})
+            }}
+            ",
+            documentation = format_docs("///", &[
+                &self.description,
+                "",
+                &format!("Wraps {}", self.documentation_url)
+            ].join("\n")),
+            method_name = fn_name,
+            request = if self.has_request_struct() {
+                format!(", request: &{}Request", type_prefix)
+            } else {
+                String::new()
+            },
+            response_type = type_prefix.clone() + "Response",
+            error_type = type_prefix + "Error",
+            args = args.join(", ")
+        )
+    }
+
+    /// Replaces the parameters that identify an item with a single `item` parameter. Pins keep
+    /// their channel, which is checked against the item and can be left out for a message.
+    fn merge_item_params(&mut self) {
+        let keeps_channel = self.params.iter().any(|p| p.name == "channel" && !p.optional);
+        let is_item = |p: &Param| match &p.name[..] {
+            "file" | "file_comment" | "timestamp" => true,
+            "channel" => !keeps_channel,
+            _ => false,
+        };
+        let i = match self.params.iter().position(|p| is_item(p)) {
+            Some(i) => i,
+            None => return,
+        };
+        let description = self.params.iter()
+            .find(|p| p.name == "file")
+            .map_or("Item to act on.".into(), |p| p.description.replacen("File", "Item", 1));
+        self.params.retain(|p| !is_item(p));
+        for channel in self.params.iter_mut().filter(|p| keeps_channel && p.name == "channel") {
+            channel.ty = "item_channel".into();
+            channel.optional = true;
+            channel.description += " Can be left out when the item is a message, and must be its channel if set.";
+        }
+        self.params.insert(i, Param {
+            name: "item".into(),
+            description: description,
+            ty: if keeps_channel { "item_in_channel" } else { "item" }.into(),
+            optional: false,
+        });
+    }
 
+    fn multipart_param(&self) -> Option<&Param> {
+        MULTIPART_PARAMS.iter()
+            .find(|&&(method, _)| method == self.name)
+            .and_then(|&(_, name)| self.params.iter().find(|p| p.name == name))
+    }
+
+    fn has_request_struct(&self) -> bool {
+        !(self.params.is_empty() || (self.params.len() == 1 && self.params[0].ty == "auth_token"))
+    }
+
+    fn generate_fn(&self, is_async: bool) -> String {
         let fn_name = self.name.split('.').last().unwrap().to_snake_case();
         let type_prefix = self.name.split('.').last().unwrap().to_pascal_case();
         let request_struct_name = type_prefix.clone() + "Request";
         let response_struct_name = type_prefix.clone() + "Response";
         let error_enum_name = type_prefix.clone() + "Error";
-        let response = self.response.generate(&response_struct_name, &error_enum_name);
         let response_type = self.response.get_response_type(&response_struct_name);
+        let has_token = self.params.iter().any(|p| p.ty == "auth_token");
+        let sends_json = JSON_BODY_METHODS.contains(&&self.name[..]);
+
+        let multipart_param = self.multipart_param();
 
         let send_call = {
+            let multipart_body = match multipart_param {
+                Some(file) => {
+                    let body = format!("\
+                        RequestBody::Multipart(&params[..], MultipartFile {{
+                            name: \"{name}\",
+                            filename: {filename},
+                            contents: {{contents}},
+                        }})",
+                        name = file.name,
+                        filename = if self.params.iter().any(|p| p.name == "filename") {
+                            format!("request.filename.unwrap_or(\"{}\")", file.name)
+                        } else {
+                            format!("\"{}\"", file.name)
+                        });
+                    if file.optional {
+                        format!("\
+                            let body = match request.{name} {{
+                                Some({name}) => {body},
+                                None => RequestBody::Form(&params[..]),
+                            }};",
+                            name = file.name,
+                            body = body.replace("{contents}", &file.name))
+                    } else {
+                        format!("let body = {};", body.replace("{contents}", &format!("request.{}", file.name)))
+                    }
+                }
+                None => String::new(),
+            };
+            let body = if multipart_param.is_some() {
+                "body"
+            } else if !self.has_request_struct() {
+                "body: RequestBody::Form(&[])"
+            } else if sends_json {
+                "body: RequestBody::Json(&body)"
+            } else {
+                "body: RequestBody::Form(&params[..])"
+            };
             let mut base_call = format!("\
-                let url = ::get_slack_url_for_method(\"{name}\");
-                client.send(&url, &params[..])
+                let url = crate::get_slack_url_for_method(client.base_url(), \"{name}\");
+                {multipart_body}
+                client.send(SlackWebRequest {{
+                        url: &url,
+                        token: {token},
+                        {body},
+                    }}){await_call}
                     .map_err({error_type}::Client)
                     .and_then(|result| {{
-                        serde_json::from_str::<{response_type}>(&result)
+                        serde_json::from_str::<{response_type}>(&result.body)
                             .map_err({error_type}::MalformedResponse)
                     }})",
                 name = self.name,
+                token = if has_token { "Some(token)" } else { "None" },
+                multipart_body = multipart_body,
+                body = body,
+                await_call = if is_async { ".await" } else { "" },
                 response_type = response_struct_name,
                 error_type = error_enum_name
             );
 
             match response_type {
+                _ if ITEM_RESPONSE_METHODS.contains(&&self.name[..]) => base_call.push_str(".and_then(|o| o.into())"),
                 PropType::Obj(ref o) => if o.has_ok() { base_call.push_str(".and_then(|o| o.into())") },
                 PropType::Enum(ref e) => if e.has_ok() { base_call.push_str(".and_then(|o| o.into())") },
                 _ => panic!("Top-level response for {} is not an object or enum.", fn_name)
@@ -89,118 +360,197 @@ impl Method {
 
         if self.params.is_empty() {
             format!("\
-                {documentation}
-                pub fn {method_name}<R>(client: &R) -> Result<{response_type}, {error_type}<R::Error>>
-                    where R: SlackWebRequestSender
+                {documentation}pub {asyncness}fn {method_name}<R>(client: &R) -> Result<{response_type}, {error_type}<R::Error>>
+                    where R: {sender}
                 {{
-                    let params = &[];
                     {send_call}
                 }}
-
-                {response}
                 ",
                 documentation = format_docs("///", &[
                     &self.description,
                     "",
                     &format!("Wraps {}", self.documentation_url)
                 ].join("\n")),
+                asyncness = if is_async { "async " } else { "" },
                 method_name = fn_name,
+                sender = if is_async { "AsyncSlackWebRequestSender" } else { "SlackWebRequestSender" },
                 response_type = response_struct_name,
                 error_type = error_enum_name,
-                response = response,
                 send_call = send_call
             )
         } else if self.params.len() == 1 && self.params[0].ty == "auth_token" {
             format!("\
-                {documentation}
-                pub fn {method_name}<R>(client: &R, token: &str) -> Result<{response_type}, {error_type}<R::Error>>
-                    where R: SlackWebRequestSender
+                {documentation}pub {asyncness}fn {method_name}<R>(client: &R, token: &str) -> Result<{response_type}, {error_type}<R::Error>>
+                    where R: {sender}
                 {{
-                    let params = &[(\"token\", token)];
                     {send_call}
                 }}
-
-                {response}
                 ",
                 documentation = format_docs("///", &[
                     &self.description,
                     "",
                     &format!("Wraps {}", self.documentation_url)
                 ].join("\n")),
+                asyncness = if is_async { "async " } else { "" },
                 method_name = fn_name,
+                sender = if is_async { "AsyncSlackWebRequestSender" } else { "SlackWebRequestSender" },
                 response_type = response_struct_name,
                 error_type = error_enum_name,
-                response = response,
                 send_call = send_call
             )
         } else {
-            let has_token = self.params.iter().any(|p| p.ty == "auth_token");
+            // async fns can't elide the request's lifetime
+            let request_type = if is_async && self.request_struct_has_lifetime() {
+                request_struct_name.clone() + "<'_>"
+            } else {
+                request_struct_name.clone()
+            };
             let method_params = if has_token {
-                format!("client: &R, token: &str, request: &{}", request_struct_name)
+                format!("client: &R, token: &str, request: &{}", request_type)
             } else {
-                format!("client: &R, request: &{}", request_struct_name)
+                format!("client: &R, request: &{}", request_type)
             };
-            format!("\
-                {documentation}
-                pub fn {method_name}<R>({method_params}) -> Result<{response_type}, {error_type}<R::Error>>
-                    where R: SlackWebRequestSender
-                {{
+            let params = self.params.iter()
+                .filter(|p| p.ty != "auth_token") // passed in method params instead
+                .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+                .filter(|p| p.ty != "item_channel") // sent with the item, which checks it
+                .filter(|p| multipart_param.map_or(true, |f| f.name != p.name)); // sent as a multipart file instead
+            let build_body = if sends_json {
+                format!("\
+                    {check_limits}
+                    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+                        {param_pairs}
+                    ];
+                    let body = params.into_iter().flatten().collect::<serde_json::Value>();",
+                    check_limits = if self.params.iter().any(|p| p.name == "attachments") {
+                        format!("\
+                            if request.attachments.is_some_and(|attachments| attachments.len() > crate::attachments::MAX_ATTACHMENTS) {{
+                                return Err({}::TooManyAttachments);
+                            }}",
+                            error_enum_name)
+                    } else {
+                        String::new()
+                    },
+                    param_pairs = params
+                        .map(Param::get_json_pair)
+                        .collect::<Vec<String>>()
+                        .join(",\n")
+                )
+            } else {
+                format!("\
                     {local_vars}
                     let params = vec![
-                        {token}
                         {param_pairs}
                     ];
-                    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+                    let params = params.into_iter().flatten().collect::<Vec<_>>();",
+                    local_vars = params.clone()
+                        .filter_map(|p| p.lifted())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                    param_pairs = params
+                        .map(Param::get_pair)
+                        .collect::<Vec<String>>()
+                        .join(",\n")
+                )
+            };
+            format!("\
+                {documentation}pub {asyncness}fn {method_name}<R>({method_params}) -> Result<{response_type}, {error_type}<R::Error>>
+                    where R: {sender}
+                {{
+                    {build_body}
                     {send_call}
                 }}
-
-                {request}
-
-                {response}
                 ",
                 documentation = format_docs("///", &[
                     &self.description,
                     "",
                     &format!("Wraps {}", self.documentation_url)
                 ].join("\n")),
+                asyncness = if is_async { "async " } else { "" },
                 method_name = fn_name,
+                sender = if is_async { "AsyncSlackWebRequestSender" } else { "SlackWebRequestSender" },
                 response_type = response_struct_name,
                 error_type = error_enum_name,
-                response = response,
-                request = self.get_request_struct(&request_struct_name),
                 method_params = method_params,
-                token = if has_token { "Some((\"token\", token))," } else { "" },
-                local_vars = self.params.iter()
-                    .filter(|p| p.ty != "auth_token") // passed in method params instead
-                    .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                    .filter_map(|p| p.lifted())
-                    .collect::<Vec<_>>()
-                    .join("\n"),
-                param_pairs = self.params.iter()
-                    .filter(|p| p.ty != "auth_token") // passed in method params instead
-                    .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                    .map(Param::get_pair)
-                    .collect::<Vec<String>>()
-                    .join(",\n"),
+                build_body = build_body,
                 send_call = send_call
             )
         }
     }
 
+    fn request_struct_has_lifetime(&self) -> bool {
+        !self.params.iter()
+            .filter(|p| p.ty != "auth_token")
+            .all(|p| p.ty == "integer" || p.ty == "boolean")
+    }
+
     fn get_request_struct(&self, ty_name: &str) -> String {
         format!("\
-            #[derive(Clone, Default, Debug)]
+            #[derive({derives})]
             pub struct {request_type}{lifetime} {{
                 {request_params}
             }}",
+            // A required ID has no valid default, so requests with one are made with their builder
+            derives = if self.params.iter().all(Param::has_default) { "Clone, Default, Debug" } else { "Clone, Debug" },
             request_type = ty_name,
             request_params = self.params.iter()
                 .filter(|p| p.ty != "auth_token") // passed in method params instead
                 .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
-                .map(Param::generate).collect::<Vec<String>>().join("\n"),
-            lifetime = if self.params.iter()
-                .filter(|p| p.ty != "auth_token")
-                .all(|p| p.ty == "integer" || p.ty == "boolean") { "" } else { "<'a>" }
+                .map(|p| if self.multipart_param().map_or(false, |f| f.name == p.name) {
+                    p.generate_file()
+                } else {
+                    p.generate()
+                })
+                .collect::<Vec<String>>().join("\n"),
+            lifetime = if self.request_struct_has_lifetime() { "<'a>" } else { "" }
+        )
+    }
+
+    /// Generates an owned builder for the request struct, which checks that every required field
+    /// has been set.
+    fn get_request_builder(&self, ty_name: &str) -> String {
+        let lifetime = if self.request_struct_has_lifetime() { "<'a>" } else { "" };
+        let params = self.params.iter()
+            .filter(|p| p.ty != "auth_token")
+            .filter(|p| p.name != "simple_latest")
+            .map(|p| (p, self.multipart_param().map_or(false, |f| f.name == p.name)))
+            .collect::<Vec<_>>();
+        format!("\
+            impl{lifetime} {request_type}{lifetime} {{
+                /// Returns a builder for an owned version of this request.
+                pub fn builder() -> {request_type}Builder {{
+                    Default::default()
+                }}
+            }}
+
+            /// An owned builder for a [`{request_type}`], which can be kept and sent between threads.
+            #[derive(Clone, Default, Debug)]
+            pub struct {request_type}Builder {{
+                {fields}
+            }}
+
+            impl {request_type}Builder {{
+                {setters}
+
+                /// Returns the request, or the name of the first required field that has not been set.
+                pub fn build(&self) -> Result<{request_type}{build_lifetime}, crate::MissingField> {{
+                    Ok({request_type} {{
+                        {values}
+                    }})
+                }}
+            }}",
+            request_type = ty_name,
+            lifetime = lifetime,
+            build_lifetime = if lifetime.is_empty() { "" } else { "<'_>" },
+            fields = params.iter()
+                .map(|&(p, is_file)| format!("{}: Option<{}>,", p.name, p.get_owned_type(is_file)))
+                .collect::<Vec<_>>().join("\n"),
+            setters = params.iter()
+                .map(|&(p, is_file)| p.generate_builder_setter(is_file))
+                .collect::<Vec<_>>().join("\n\n"),
+            values = params.iter()
+                .map(|&(p, is_file)| p.get_builder_value(is_file))
+                .collect::<Vec<_>>().join("\n")
         )
     }
 }
@@ -244,12 +594,12 @@ fn generate_matches<F>(enm: &JsonEnum, var_name: &str, f: F) -> Vec<String>
 fn get_obj_to_response_impl(obj: &JsonObject, error_type: &str) -> Option<String> {
     if obj.has_ok() {
         Some(format!("\
-            impl<E: Error> Into<Result<{name}, {error_ty}<E>>> for {name} {{
-                fn into(self) -> Result<{name}, {error_ty}<E>> {{
-                    if self.ok {{
-                        Ok(self)
+            impl<E: Error> From<{name}> for Result<{name}, {error_ty}<E>> {{
+                fn from(response: {name}) -> Self {{
+                    if response.ok {{
+                        Ok(response)
                     }} else {{
-                        Err(self.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())
+                        Err(response.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())
                     }}
                 }}
             }}",
@@ -264,9 +614,9 @@ fn get_obj_to_response_impl(obj: &JsonObject, error_type: &str) -> Option<String
 fn get_enum_to_response_impl(enm: &JsonEnum, error_type: &str) -> Option<String> {
     if enm.has_ok() {
         Some(format!("\
-            impl<E: Error> Into<Result<{name}, {error_ty}<E>>> for {name} {{
-                fn into(self) -> Result<{name}, {error_ty}<E>> {{
-                    match self {{
+            impl<E: Error> From<{name}> for Result<{name}, {error_ty}<E>> {{
+                fn from(response: {name}) -> Self {{
+                    match response {{
                         {matches}
                     }}
                 }}
@@ -326,6 +676,69 @@ impl Response {
         )
     }
 
+    /// Generates a response that holds the item it was asked about, whatever its type, next to
+    /// `ok` and `error`.
+    pub fn generate_item(&self, ty_name: &str, error_ty: &str) -> String {
+        format!("\
+            #[derive(Clone, Debug, Serialize)]
+            pub struct {name} {{
+                #[serde(skip_serializing_if = \"Option::is_none\")]
+                error: Option<String>,
+                /// The item, which is always set when the response is `ok`
+                #[serde(flatten)]
+                pub item: Option<crate::Item>,
+                ok: bool,
+                /// Fields that are not in the schema, which the item keeps instead when there is one
+                #[serde(flatten)]
+                pub extra: HashMap<String, serde_json::Value>,
+            }}
+
+            impl<'de> ::serde::Deserialize<'de> for {name} {{
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where D: ::serde::Deserializer<'de>
+                {{
+                    use serde::de::Error as SerdeError;
+
+                    let mut fields = serde_json::Map::deserialize(deserializer)?;
+                    let mut take = |name: &str| fields.remove(name).unwrap_or(serde_json::Value::Null);
+                    let ok = Option::<bool>::deserialize(take(\"ok\")).map_err(D::Error::custom)?.unwrap_or_default();
+                    let error = Option::<String>::deserialize(take(\"error\")).map_err(D::Error::custom)?;
+                    // The item is flattened next to `ok`, so only a failed response can leave it out
+                    let item = if ok || fields.contains_key(\"type\") {{
+                        let item = crate::Item::deserialize(serde_json::Value::Object(fields.clone())).map_err(D::Error::custom)?;
+                        if let serde_json::Value::Object(own) = serde_json::to_value(&item).map_err(D::Error::custom)? {{
+                            fields.retain(|name, _| !own.contains_key(name));
+                        }}
+                        Some(item)
+                    }} else {{
+                        None
+                    }};
+                    Ok({name} {{
+                        error: error,
+                        item: item,
+                        ok: ok,
+                        extra: fields.into_iter().collect(),
+                    }})
+                }}
+            }}
+
+            impl<E: Error> From<{name}> for Result<{name}, {error_ty}<E>> {{
+                fn from(response: {name}) -> Self {{
+                    if response.ok {{
+                        Ok(response)
+                    }} else {{
+                        Err(response.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())
+                    }}
+                }}
+            }}
+
+            {errors}",
+            name = ty_name,
+            error_ty = error_ty,
+            errors = self.get_error_enum(error_ty),
+        )
+    }
+
     pub fn get_response_type(&self, ty_name: &str) -> PropType {
         PropType::from_schema(&self.schema, ty_name)
     }
@@ -354,27 +767,34 @@ impl Response {
 
             impl<E: Error> fmt::Display for {error_type}<E> {{
                 fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{
-                     write!(f, \"{{}}\", self.description())
-                }}
-            }}
-
-            impl<E: Error> Error for {error_type}<E> {{
-                fn description(&self) -> &str {{
                     match *self {{
                         {description_matches}
-                        {error_type}::MalformedResponse(ref e) => e.description(),
-                        {error_type}::Unknown(ref s) => s,
-                        {error_type}::Client(ref inner) => inner.description()
+                        {error_type}::MalformedResponse(ref e) => write!(f, \"{{}}\", e),
+                        {error_type}::Unknown(ref s) => write!(f, \"{{}}\", s),
+                        {error_type}::Client(ref inner) => write!(f, \"{{}}\", inner)
                     }}
                 }}
+            }}
 
-                fn cause(&self) -> Option<&Error> {{
+            impl<E: Error + 'static> Error for {error_type}<E> {{
+                fn source(&self) -> Option<&(dyn Error + 'static)> {{
                     match *self {{
                         {error_type}::MalformedResponse(ref e) => Some(e),
                         {error_type}::Client(ref inner) => Some(inner),
                         _ => None
                     }}
                 }}
+            }}
+
+            impl<E: Error> From<{error_type}<E>> for crate::SlackError<E> {{
+                fn from(err: {error_type}<E>) -> Self {{
+                    match err {{
+                        {code_matches}
+                        {error_type}::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+                        {error_type}::Unknown(s) => crate::SlackError::Api(s),
+                        {error_type}::Client(inner) => crate::SlackError::Client(inner)
+                    }}
+                }}
             }}",
             error_type = error_ty,
             variants = self.errors
@@ -400,11 +820,23 @@ impl Response {
                 })
                 .collect::<Vec<String>>()
                 .join("\n"),
+            code_matches = self.errors
+                .iter()
+                .map(|e| {
+                    format!(
+                        "{error_ty}::{ty_name} => crate::SlackError::Api(\"{str_name}\".to_owned()),",
+                        error_ty = error_ty,
+                        str_name = e.name,
+                        ty_name = e.name.to_pascal_case()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
             description_matches = self.errors
                 .iter()
                 .map(|e| {
                     format!(
-                        "{error_ty}::{ty_name} => \"{str_name}: {description}\",",
+                        "{error_ty}::{ty_name} => write!(f, \"{str_name}: {description}\"),",
                         error_ty = error_ty,
                         str_name = e.name,
                         description = e.description,
@@ -435,8 +867,22 @@ impl Param {
         )
     }
 
+    /// Generates the field for a parameter that holds the contents of a file.
+    fn generate_file(&self) -> String {
+        format!(
+            "{documentation}\npub {name}: {ty},",
+            documentation = format_docs("///", &self.description),
+            name = self.name,
+            ty = if self.optional { "Option<&'a [u8]>" } else { "&'a [u8]" }
+        )
+    }
+
     pub fn lifted(&self) -> Option<String> {
         match (&self.ty[..], self.optional) {
+            ("item", _) => Some(format!("let [{name}, {name}_ts] = request.{name}.params();", name = self.name)),
+            ("item_in_channel", _) => {
+                Some(format!("let [{name}, {name}_ts] = request.{name}.params_in(request.channel)?;", name = self.name))
+            },
             ("integer", true) => Some(format!("let {name} = request.{name}.map(|{name}| {name}.to_string());", name = self.name)),
             ("integer", false) => Some(format!("let {name} = request.{name}.to_string();", name = self.name)),
             _ => None
@@ -445,6 +891,8 @@ impl Param {
 
     pub fn get_pair(&self) -> String {
         match (&self.ty[..], self.optional) {
+            // lifted into local variables, holding the one or two pairs that identify the item
+            ("item", _) | ("item_in_channel", _) => format!("{name},\n{name}_ts", name = self.name),
             ("boolean", true) => {
                 format!("request.{name}.map(|{name}| (\"{name}\", if {name} {{ \"1\" }} else {{ \"0\" }}))", name = self.name)
             },
@@ -460,24 +908,121 @@ impl Param {
                 format!("Some((\"{name}\", &{name}[..]))", name = self.name)
             },
             (_, true) => {
-                format!("request.{name}.map(|{name}| (\"{name}\", {name}))", name = self.name)
+                format!("request.{name}.map(|{name}| (\"{name}\", {name}{as_str}))", name = self.name, as_str = self.as_str())
             },
             (_, false) => {
-                format!("Some((\"{name}\", request.{name}))", name = self.name)
+                format!("Some((\"{name}\", request.{name}{as_str}))", name = self.name, as_str = self.as_str())
             }
         }
     }
 
+    pub fn get_json_pair(&self) -> String {
+        let value = |name: &str| if self.json_type().is_some() {
+            format!("crate::json_value({})", name)
+        } else if self.typed().is_some() {
+            format!("{}.as_str().into()", name)
+        } else {
+            format!("{}.into()", name)
+        };
+        if self.optional {
+            format!("request.{name}.map(|{name}| (\"{name}\", {value}))", name = self.name, value = value(&self.name))
+        } else {
+            format!("Some((\"{name}\", {value}))", name = self.name, value = value(&format!("request.{}", self.name)))
+        }
+    }
+
+    /// Generates the setter of this parameter on a request builder.
+    fn generate_builder_setter(&self, is_file: bool) -> String {
+        let owned = self.get_owned_type(is_file);
+        let (generics, ty, value) = match &owned[..] {
+            "String" => ("<S: Into<String>>", "S", format!("{}.into()", self.name)),
+            "Vec<u8>" => ("<B: Into<Vec<u8>>>", "B", format!("{}.into()", self.name)),
+            _ => ("", &owned[..], self.name.clone()),
+        };
+        format!(
+            "{documentation}\npub fn {name}{generics}(mut self, {name}: {ty}) -> Self {{
+                self.{name} = Some({value});
+                self
+            }}",
+            documentation = format_docs("///", &self.description),
+            name = self.name,
+            generics = generics,
+            ty = ty,
+            value = value
+        )
+    }
+
+    /// Returns the expression borrowing this parameter from a request builder.
+    fn get_builder_value(&self, is_file: bool) -> String {
+        let borrowed = match &self.get_owned_type(is_file)[..] {
+            owned if owned == "String" || owned.starts_with("Vec<") => format!("self.{}.as_deref()", self.name),
+            _ if self.typed().is_some() || self.json_type().is_some() || self.is_item() => format!("self.{}.as_ref()", self.name),
+            _ => format!("self.{}", self.name),
+        };
+        if self.optional {
+            format!("{}: {},", self.name, borrowed)
+        } else {
+            format!("{name}: {borrowed}.ok_or(crate::MissingField(\"{name}\"))?,", name = self.name, borrowed = borrowed)
+        }
+    }
+
+    fn get_owned_type(&self, is_file: bool) -> String {
+        match (&self.ty[..], self.typed()) {
+            _ if is_file => "Vec<u8>".into(),
+            ("boolean", _) => "bool".into(),
+            ("integer", _) => "u32".into(),
+            _ if self.is_item() => "crate::ItemRef".into(),
+            _ if self.json_type().is_some() => {
+                let ty = self.json_type().unwrap();
+                if ty.starts_with('[') { format!("Vec<{}>", &ty[1..ty.len() - 1]) } else { ty.to_owned() }
+            },
+            (_, Some(id)) => format!("crate::{}", id),
+            _ => "String".into(),
+        }
+    }
+
+    /// Returns the type this parameter takes, if it takes an ID or a timestamp.
+    fn typed(&self) -> Option<&'static str> {
+        if self.ty == "channel_or_name" {
+            return Some("ChannelRef");
+        }
+        TYPED_PARAMS.iter().find(|&&(name, _)| name == self.name).map(|&(_, id)| id)
+    }
+
+    /// Returns whether the `Default` of this parameter's type is a value that can be sent.
+    fn has_default(&self) -> bool {
+        let is_slice = self.json_type().map_or(false, |ty| ty.starts_with('['));
+        self.optional || self.ty == "auth_token" || !(self.is_item() || self.typed().is_some() || (self.json_type().is_some() && !is_slice))
+    }
+
+    /// Returns whether this parameter is the `ItemRef` that replaced the parameters of an item.
+    fn is_item(&self) -> bool {
+        self.ty == "item" || self.ty == "item_in_channel"
+    }
+
+    /// Returns the type this parameter takes, if it is serialized into a JSON body.
+    fn json_type(&self) -> Option<&'static str> {
+        JSON_PARAMS.iter().find(|&&(name, _)| name == self.name).map(|&(_, ty)| ty)
+    }
+
+    /// Returns the call that borrows this parameter as a string, if it is not one already.
+    fn as_str(&self) -> &'static str {
+        if self.typed().is_some() { ".as_str()" } else { "" }
+    }
+
     fn get_rust_type(&self) -> String {
-        let ty = match &self.ty[..] {
-            "boolean" => "bool",
-            "integer" => "u32",
-            _ => "&'a str",
+        let ty: String = match (&self.ty[..], self.typed()) {
+            ("boolean", _) => "bool".into(),
+            ("integer", _) => "u32".into(),
+            _ if self.is_item() => "&'a crate::ItemRef".into(),
+            _ if self.json_type().is_some() => format!("&'a {}", self.json_type().unwrap()),
+            (_, Some(id)) => format!("&'a crate::{}", id),
+            _ => "&'a str".into(),
         };
         if self.optional {
             format!("Option<{}>", ty)
         } else {
-            ty.to_owned()
+            ty
         }
     }
 }
@@ -494,6 +1039,11 @@ impl JsonObjectFieldInfo {
             prefix.push_str("#[serde(default)]\n");
         }
 
+        // Absent fields are left out rather than serialized as null, as Slack does
+        if let PropType::Optional(_) = self.ty {
+            prefix.push_str("#[serde(skip_serializing_if = \"Option::is_none\")]\n");
+        }
+
         if self.name == "ok" {
             prefix.push_str("#[serde(default)]");
         } else if self.name != "error" && self.name != "ok" {
@@ -524,28 +1074,102 @@ impl JsonEnumVariant {
     }
 }
 
+/// The variant of `Message` for subtypes that are not in the schema.
+const MESSAGE_UNKNOWN: &'static str = "\
+    /// A message with a subtype that is not known, which keeps the JSON it was sent as.
+    ///
+    /// The fields most messages have are read from the JSON for the accessors of `Message`. A field that
+    /// does not hold what it should is left out rather than failing the message.
+    #[derive(Clone, Debug)]
+    pub struct MessageUnknown {
+        pub(crate) bot_id: Option<crate::BotId>,
+        pub(crate) channel: Option<crate::ChannelId>,
+        pub(crate) subtype: Option<String>,
+        pub(crate) text: Option<String>,
+        pub(crate) thread_ts: Option<crate::Ts>,
+        pub(crate) ts: Option<crate::Ts>,
+        pub(crate) user: Option<crate::UserId>,
+        json: serde_json::Value,
+    }
+
+    impl MessageUnknown {
+        /// Returns the message as it was sent.
+        pub fn json(&self) -> &serde_json::Value {
+            &self.json
+        }
+
+        /// Returns the message as it was sent, consuming it.
+        pub fn into_json(self) -> serde_json::Value {
+            self.json
+        }
+    }
+
+    impl<'de> ::serde::Deserialize<'de> for MessageUnknown {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            fn field<T: ::serde::de::DeserializeOwned>(json: &serde_json::Value, name: &str) -> Option<T> {
+                json.get(name).and_then(|value| T::deserialize(value).ok())
+            }
+
+            let json = <serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+            Ok(MessageUnknown {
+                bot_id: field(&json, \"bot_id\"),
+                channel: field(&json, \"channel\"),
+                subtype: field(&json, \"subtype\"),
+                text: field(&json, \"text\"),
+                thread_ts: field(&json, \"thread_ts\"),
+                ts: field(&json, \"ts\"),
+                user: field(&json, \"user\"),
+                json,
+            })
+        }
+    }
+
+    impl ::serde::Serialize for MessageUnknown {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            ::serde::Serialize::serialize(&self.json, serializer)
+        }
+    }";
+
 impl JsonEnum {
     pub fn to_code(&self) -> String {
-        // Hack to work around message having a different identifier here
-        let (variant_field, on_missing_field) = if self.name == "Message" {
+        // Hack to work around message having a different identifier here, and keeping messages
+        // with subtypes that are not in the schema instead of failing the whole response
+        let (variant_field, on_missing_field, on_unknown_variant) = if self.name == "Message" {
             ("subtype", "::serde_json::from_value::<MessageStandard>(value.clone())
                .map(Message::Standard)
-               .map_err(|e| D::Error::custom(&format!(\"{}\", e)))")
+               .map_err(D::Error::custom)", "::serde_json::from_value::<MessageUnknown>(value.clone())
+               .map(Message::Unknown)
+               .map_err(D::Error::custom)")
         } else {
-            ("type", "Err(D::Error::missing_field(\"type\"))")
+            ("type", "Err(D::Error::missing_field(\"type\"))", "Err(D::Error::unknown_variant(ty, VARIANTS))")
         };
+        let has_unknown = self.name == "Message";
 
         let mut subobjs = self.variants.clone();
 
         subobjs.sort_by_key(|v| v.name.clone());
 
-        let subobjs = subobjs.iter()
+        let mut subobjs = subobjs.iter()
             .flat_map(|v| obj_recur(&v.inner))
-            .collect::<Vec<_>>()
-            .join("\n");
+            .collect::<Vec<_>>();
+        let mut variants = self.variants
+            .iter()
+            .map(|v| v.to_code())
+            .collect::<Vec<_>>();
+        if has_unknown {
+            subobjs.push(MESSAGE_UNKNOWN.to_owned());
+            variants.push("Unknown(MessageUnknown),".to_owned());
+        }
 
         format!("\
-            #[derive(Clone, Debug)]
+            #[derive(Clone, Debug, Serialize)]
+            #[serde(untagged)]
             pub enum {name} {{
                 {variants}
             }}
@@ -556,14 +1180,13 @@ impl JsonEnum {
                 {{
                     use ::serde::de::Error as SerdeError;
 
-                    const VARIANTS: &'static [&'static str] = &[{variant_names}];
-
+                    {variant_names}
                     let value = ::serde_json::Value::deserialize(deserializer)?;
                     if let Some(ty_val) = value.get(\"{variant_field}\") {{
                         if let Some(ty) = ty_val.as_str() {{
                             match ty {{
                                 {variant_matches}
-                                _ => Err(D::Error::unknown_variant(ty, VARIANTS))
+                                _ => {on_unknown_variant}
                             }}
                         }} else {{
                             Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &\"a string\"))
@@ -576,23 +1199,23 @@ impl JsonEnum {
 
             {subobjs}",
             name = self.name,
-            variants = self.variants
-                .iter()
-                .map(|v| v.to_code())
-                .collect::<Vec<_>>()
-                .join("\n"),
-            variant_names = self.variants
-                .iter()
-                .map(|v| format!("\"{}\"", v.name.to_snake_case()))
-                .collect::<Vec<_>>()
-                .join(","),
+            variants = variants.join("\n"),
+            variant_names = if has_unknown {
+                String::new()
+            } else {
+                format!("const VARIANTS: &[&str] = &[{}];\n", self.variants
+                    .iter()
+                    .map(|v| format!("\"{}\"", v.name.to_snake_case()))
+                    .collect::<Vec<_>>()
+                    .join(","))
+            },
             variant_matches = self.variants
                 .iter()
                 .map(|v| format!("\
                     \"{type_name}\" => {{
                         ::serde_json::from_value::<{variant_type}>(value.clone())
                            .map({variant_name})
-                           .map_err(|e| D::Error::custom(&format!(\"{{}}\", e)))
+                           .map_err(D::Error::custom)
                     }}",
                     type_name = v.name.to_snake_case(),
                     variant_type = v.inner.to_rs_type(),
@@ -600,9 +1223,10 @@ impl JsonEnum {
                 ))
                 .collect::<Vec<_>>()
                 .join("\n"),
-            subobjs = subobjs,
+            subobjs = subobjs.join("\n"),
             variant_field = variant_field,
-            on_missing_field = on_missing_field
+            on_missing_field = on_missing_field,
+            on_unknown_variant = on_unknown_variant
         )
     }
 }
@@ -633,9 +1257,12 @@ impl JsonObject {
             .collect::<Vec<_>>();
 
         format!("\
-            #[derive(Clone, Debug, Deserialize)]
+            #[derive(Clone, Debug, Deserialize, Serialize)]
             pub struct {name} {{
                 {fields}
+                /// Fields that are not in the schema
+                #[serde(flatten)]
+                pub extra: HashMap<String, serde_json::Value>,
             }}
 
             {subobjs}",
@@ -70,7 +70,159 @@ pub enum PropType {
     Null,
 }
 
+/// Fields holding the ID of another object or a timestamp, by the name of the field.
+const TYPED_FIELDS: &[(&str, &str)] = &[
+    ("user", "UserId"), ("creator", "UserId"), ("created_by", "UserId"), ("updated_by", "UserId"),
+    ("deleted_by", "UserId"), ("accepted_user", "UserId"), ("parent_user_id", "UserId"),
+    ("user_id", "UserId"), ("members", "UserId"), ("users", "UserId"),
+    ("channel", "ChannelId"), ("group", "ChannelId"), ("channel_id", "ChannelId"),
+    ("pinned_to", "ChannelId"), ("channels", "ChannelId"), ("groups", "ChannelId"), ("ims", "ChannelId"),
+    ("team", "TeamId"), ("team_id", "TeamId"), ("source_team", "TeamId"),
+    ("bot_id", "BotId"),
+    ("ts", "Ts"), ("thread_ts", "Ts"), ("event_ts", "Ts"), ("deleted_ts", "Ts"), ("last_read", "Ts"),
+    ("latest", "Ts"),
+];
+
+/// The type of the `id` field of objects, by the name of the object.
+const ID_OBJECTS: &[(&str, &str)] = &[
+    ("Bot", "BotId"), ("InfoResponseBot", "BotId"),
+    ("Channel", "ChannelId"), ("Group", "ChannelId"), ("Im", "ChannelId"), ("Mpim", "ChannelId"),
+    ("RenameResponseChannel", "ChannelId"),
+    ("File", "FileId"), ("FileComment", "FileCommentId"),
+    ("Team", "TeamId"), ("ConnectResponseTeam", "TeamId"),
+    ("User", "UserId"), ("ConnectResponseSelf", "UserId"),
+    ("Usergroup", "UsergroupId"),
+];
+
+/// Fields named like a typed field that hold a name instead, by object and field name.
+const NOT_TYPED_FIELDS: &[(&str, &str)] = &[
+    ("TestResponse", "team"), ("TestResponse", "user"), ("IntegrationLogsResponseLog", "channel"),
+];
+
+/// Message objects that carry layout blocks, which the schemas do not describe yet.
+const BLOCKS_OBJECTS: &[&str] = &[
+    "MessageBotMessage", "MessageMessageChangedMessage", "MessageStandard", "MessageThreadBroadcast",
+];
+
+/// Message subtypes that Slack sends but the schemas do not describe yet, as the schema each would have.
+const EXTRA_MESSAGE_SUBTYPES: &[&str] = &[
+    r#"{"title": "bot_add", "type": "object", "properties": {
+        "bot_id": {"type": "string"}, "bot_link": {"type": "string"}, "subtype": {"type": "string"},
+        "text": {"type": "string"}, "ts": {"type": "string"}, "type": {"type": "string"},
+        "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "bot_remove", "type": "object", "properties": {
+        "bot_id": {"type": "string"}, "bot_link": {"type": "string"}, "subtype": {"type": "string"},
+        "text": {"type": "string"}, "ts": {"type": "string"}, "type": {"type": "string"},
+        "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "channel_convert_to_private", "type": "object", "properties": {
+        "subtype": {"type": "string"}, "text": {"type": "string"}, "ts": {"type": "string"},
+        "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "channel_posting_permissions", "type": "object", "properties": {
+        "subtype": {"type": "string"}, "text": {"type": "string"}, "ts": {"type": "string"},
+        "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "ekm_access_denied", "type": "object", "properties": {
+        "subtype": {"type": "string"}, "text": {"type": "string"}, "ts": {"type": "string"},
+        "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "reminder_add", "type": "object", "properties": {
+        "subtype": {"type": "string"}, "text": {"type": "string"}, "ts": {"type": "string"},
+        "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "slackbot_response", "type": "object", "properties": {
+        "channel": {"type": "string"}, "subtype": {"type": "string"}, "text": {"type": "string"},
+        "ts": {"type": "string"}, "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "thread_broadcast", "type": "object", "properties": {
+        "root": {"type": "object", "properties": {
+            "reply_count": {"type": "integer"}, "text": {"type": "string"}, "thread_ts": {"type": "string"},
+            "ts": {"type": "string"}, "type": {"type": "string"}, "user": {"type": "string"}
+        }},
+        "subtype": {"type": "string"}, "text": {"type": "string"}, "thread_ts": {"type": "string"},
+        "ts": {"type": "string"}, "type": {"type": "string"}, "user": {"type": "string"}
+    }}"#,
+    r#"{"title": "tombstone", "type": "object", "properties": {
+        "hidden": {"type": "boolean"}, "subtype": {"type": "string"}, "text": {"type": "string"},
+        "thread_ts": {"type": "string"}, "ts": {"type": "string"}, "type": {"type": "string"},
+        "user": {"type": "string"}
+    }}"#,
+];
+
+/// Enums of the items that stars, pins and reactions list, which all share `Item` instead.
+const ITEM_ENUMS: &[&str] = &["ListResponseItem"];
+
+/// The variants of `Item`, and the fields of each besides `type`, `created` and `created_by`.
+const ITEM_VARIANTS: &[(&str, &[(&str, &str)])] = &[
+    ("Message", &[("channel", "ChannelId"), ("message", "Message")]),
+    ("File", &[("file", "File")]),
+    ("FileComment", &[("comment", "FileComment"), ("file", "File")]),
+    ("Channel", &[("channel", "ChannelId")]),
+    ("Im", &[("channel", "ChannelId")]),
+    ("Group", &[("group", "ChannelId")]),
+];
+
+impl JsonObjectFieldInfo {
+    fn new(name: &str, ty: PropType) -> Self {
+        JsonObjectFieldInfo {
+            name: name.into(),
+            ty: ty,
+            rename: None,
+            deserialize_with: None,
+            default: false,
+        }
+    }
+}
+
+impl JsonEnum {
+    /// Returns `Item`, the starred, pinned or reacted item that no object schema describes.
+    pub fn item() -> Self {
+        JsonEnum {
+            name: "Item".into(),
+            variants: ITEM_VARIANTS.iter()
+                .map(|&(variant, fields)| {
+                    let mut fields = fields.iter()
+                        .map(|&(name, ty)| JsonObjectFieldInfo::new(name, PropType::Ref(ty.into())))
+                        .collect::<Vec<_>>();
+                    fields.push(JsonObjectFieldInfo::new("created", PropType::Optional(Box::new(PropType::Num))));
+                    fields.push(JsonObjectFieldInfo::new("created_by",
+                        PropType::Optional(Box::new(PropType::Ref("UserId".into())))));
+                    fields.push(JsonObjectFieldInfo {
+                        rename: Some("type".into()),
+                        ..JsonObjectFieldInfo::new("ty", PropType::Str)
+                    });
+                    JsonEnumVariant {
+                        name: variant.into(),
+                        qualified_name: format!("Item::{}", variant),
+                        inner: PropType::Obj(JsonObject { name: format!("Item{}", variant), fields: fields }),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Returns the type of field `field` of object `obj`, if it holds IDs or timestamps.
+fn typed_field(obj: &str, field: &str) -> Option<&'static str> {
+    if NOT_TYPED_FIELDS.contains(&(obj, field)) {
+        return None;
+    }
+    let (key, table) = if field == "id" { (obj, ID_OBJECTS) } else { (field, TYPED_FIELDS) };
+    table.iter().find(|&&(k, _)| k == key).map(|&(_, id)| id)
+}
+
 impl PropType {
+    /// Replaces strings, or the strings of an array, with the type `ty`.
+    fn with_type(self, ty: &str) -> Self {
+        match self {
+            PropType::Str => PropType::Ref(ty.to_owned()),
+            PropType::Arr(inner) => PropType::Arr(Box::new(inner.with_type(ty))),
+            other => other,
+        }
+    }
+
     pub fn from_schema(schema: &JsonSchema, name: &str) -> Self {
         if let Some(ref def) = schema.definition_ref {
             // TODO: This ignores `#/` and assumes filenames refer to an existing struct with that
@@ -85,21 +237,33 @@ impl PropType {
         }
 
         if let Some(ref one_of) = schema.one_of {
+            if ITEM_ENUMS.contains(&name) {
+                return PropType::Ref("Item".into());
+            }
+            let mut one_of = one_of.clone();
+            if name == "Message" {
+                one_of.extend(EXTRA_MESSAGE_SUBTYPES.iter()
+                    .map(|s| ::serde_json::from_str(s).expect("Invalid schema for extra message subtype")));
+            }
+            let mut variants = one_of.iter()
+                .map(|o| {
+                    // TODO: Have this just check title. id is not reliable
+                    let variant_name =
+                        o.title.as_ref().or_else(|| o.id.as_ref()).unwrap().to_pascal_case();
+                    let obj_name = name.to_owned() + &variant_name;
+                    JsonEnumVariant {
+                        name: variant_name.clone(),
+                        qualified_name: format!("{}::{}", name.to_owned(), variant_name),
+                        inner: Self::from_schema(o, &obj_name),
+                    }
+                })
+                .collect::<Vec<_>>();
+            if name == "Message" {
+                variants.sort_by_key(|v| (v.name != "Standard", v.name.clone()));
+            }
             return PropType::Enum(JsonEnum {
                 name: name.to_owned(),
-                variants: one_of.iter()
-                    .map(|o| {
-                        // TODO: Have this just check title. id is not reliable
-                        let variant_name =
-                            o.title.as_ref().or_else(|| o.id.as_ref()).unwrap().to_pascal_case();
-                        let obj_name = name.to_owned() + &variant_name;
-                        JsonEnumVariant {
-                            name: variant_name.clone(),
-                            qualified_name: format!("{}::{}", name.to_owned(), variant_name),
-                            inner: Self::from_schema(o, &obj_name),
-                        }
-                    })
-                    .collect(),
+                variants: variants,
             });
         }
 
@@ -135,7 +299,7 @@ impl PropType {
                                 println!("{} is an object but has no properties. Likely an error.",
                                          &name);
                             }
-                            let fields = p.iter()
+                            let mut fields: Vec<JsonObjectFieldInfo> = p.iter()
                                 .map(|(orig_name, p)| {
                                     let (field_name, rename) = match &orig_name[..] {
                                         "type" => ("ty", Some("type".into())),
@@ -145,6 +309,9 @@ impl PropType {
                                     let field_ty_name = name.to_owned() +
                                                         &orig_name.to_pascal_case();
                                     let mut ty = Self::from_schema(p, &field_ty_name);
+                                    if let Some(typed) = typed_field(name, field_name) {
+                                        ty = ty.with_type(typed);
+                                    }
                                     if let Some(ref req) = schema.required {
                                         if !req.contains(orig_name) {
                                             ty = PropType::Optional(Box::new(ty));
@@ -156,7 +323,7 @@ impl PropType {
                                     let default;
                                     let deserialize_with;
                                     if name == "UserProfile" && field_name == "fields" {
-                                        deserialize_with = Some("::optional_struct_or_empty_array");
+                                        deserialize_with = Some("crate::optional_struct_or_empty_array");
                                         default = true;
                                     } else {
                                         deserialize_with = None;
@@ -171,6 +338,20 @@ impl PropType {
                                     }
                                 })
                                 .collect();
+                            if BLOCKS_OBJECTS.contains(&name) {
+                                let i = fields.iter()
+                                    .position(|f| f.rename.as_ref().unwrap_or(&f.name).as_str() > "blocks")
+                                    .unwrap_or(fields.len());
+                                fields.insert(i, JsonObjectFieldInfo {
+                                    name: "blocks".into(),
+                                    ty: PropType::Optional(Box::new(PropType::Arr(Box::new(
+                                        PropType::Ref("blocks::Block".into()))))),
+                                    rename: None,
+                                    // received blocks that do not parse are kept as JSON
+                                    deserialize_with: Some("crate::blocks::deserialize_received"),
+                                    default: true,
+                                });
+                            }
 
                             JsonObject {
                                 name: name.to_owned(),
@@ -192,7 +373,7 @@ impl PropType {
             PropType::Bool => "bool".into(),
             PropType::Null => "()".into(),
             PropType::Obj(ref obj) => obj.name.clone(),
-            PropType::Ref(ref name) => format!("::{}", name),
+            PropType::Ref(ref name) => format!("crate::{}", name),
             PropType::Arr(ref prop) => format!("Vec<{}>", prop.to_rs_type()),
             PropType::Map(ref prop) => format!("HashMap<String, {}>", prop.to_rs_type()),
             PropType::Optional(ref prop) => format!("Option<{}>", prop.to_rs_type()),
@@ -14,7 +14,7 @@ use inflector::Inflector;
 use clap::{Arg, App};
 
 mod json_schema;
-use json_schema::{JsonSchema, PropType};
+use json_schema::{JsonEnum, JsonSchema, PropType};
 
 mod generator;
 use generator::*;
@@ -59,6 +59,9 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
         }
     }
 
+    types_file.write_all(JsonEnum::item().to_code().as_bytes())?;
+    types_file.write_all(b"\n#[cfg(test)]\nmod tests;\n")?;
+
     {
         let mut rustfmt_config = rustfmt::config::Config::default();
         rustfmt_config.set().write_mode(rustfmt::config::WriteMode::Overwrite);
@@ -68,7 +71,7 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
     Ok(())
 }
 
-fn generate_modules(output_path: &Path) -> io::Result<()> {
+fn generate_modules(output_path: &Path, async_output_path: &Path) -> io::Result<()> {
     let mut mods = vec![];
 
     let schema_path = Path::new(SCHEMA_DIR);
@@ -81,8 +84,9 @@ fn generate_modules(output_path: &Path) -> io::Result<()> {
                 let mut schema_contents = String::new();
                 schema_file.read_to_string(&mut schema_contents)?;
 
-                let module = serde_json::from_str::<Module>(&schema_contents)
+                let mut module = serde_json::from_str::<Module>(&schema_contents)
                     .expect(&format!("Could not parse module schema for {}", path.display()));
+                module.add_missing_params();
                 mods.push(module.get_safe_name());
 
                 let out_filepath = output_path.join(format!("{}.rs", module.get_safe_name()));
@@ -94,23 +98,41 @@ fn generate_modules(output_path: &Path) -> io::Result<()> {
                     .open(&out_filepath)?;
 
                 out_file.write_all(module.generate().as_bytes())?;
+                // tests of a module are kept apart, so that regenerating it keeps them
+                if output_path.join(module.get_safe_name()).join("tests.rs").is_file() {
+                    out_file.write_all(b"\n#[cfg(test)]\nmod tests;\n")?;
+                }
+
+                let async_out_filepath = async_output_path.join(format!("{}.rs", module.get_safe_name()));
+
+                let mut async_out_file = OpenOptions::new()
+                    .write(true)
+                    .truncate(true)
+                    .create(true)
+                    .open(&async_out_filepath)?;
+
+                async_out_file.write_all(module.generate_async().as_bytes())?;
 
-                {
+                for filepath in vec![out_filepath, async_out_filepath] {
                     let mut rustfmt_config = rustfmt::config::Config::default();
                     rustfmt_config.set().write_mode(rustfmt::config::WriteMode::Overwrite);
-                    let _ = rustfmt::run(rustfmt::Input::File(out_filepath), &rustfmt_config);
+                    let _ = rustfmt::run(rustfmt::Input::File(filepath), &rustfmt_config);
                 }
             }
         }
     }
 
-    let mut mod_file = OpenOptions::new()
-        .write(true)
-        .truncate(true)
-        .create(true)
-        .open(output_path.join("mod.rs"))?;
+    let mod_contents = mods.iter().map(|modname| format!("pub mod {};", modname)).collect::<Vec<_>>().join("\n");
 
-    mod_file.write_all(mods.iter().map(|modname| format!("pub mod {};", modname)).collect::<Vec<_>>().join("\n").as_bytes())?;
+    for dir in &[output_path, async_output_path] {
+        let mut mod_file = OpenOptions::new()
+            .write(true)
+            .truncate(true)
+            .create(true)
+            .open(dir.join("mod.rs"))?;
+
+        mod_file.write_all(mod_contents.as_bytes())?;
+    }
 
     Ok(())
 }
@@ -142,6 +164,11 @@ fn main() {
         let _ = fs::create_dir(&moddir);
     }
 
-    generate_modules(&moddir).unwrap();
+    let async_moddir = outdir.join("async_impl").join("mods");
+    if !async_moddir.exists() {
+        let _ = fs::create_dir_all(&async_moddir);
+    }
+
+    generate_modules(&moddir, &async_moddir).unwrap();
     generate_types(outdir).unwrap();
 }
\ No newline at end of file
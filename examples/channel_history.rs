@@ -1,18 +1,13 @@
 extern crate slack_api as slack;
-extern crate reqwest;
 
 use std::env;
 
 fn main() {
     let token = env::var("SLACK_API_TOKEN").expect("SLACK_API_TOKEN not set.");
-    let client = reqwest::Client::new().unwrap();
+    let client = slack::default_client().unwrap();
 
-    let response = slack::channels::history(&client,
-                                            &token,
-                                            &slack::channels::HistoryRequest {
-                                                channel: &env::args().nth(1).unwrap(),
-                                                ..slack::channels::HistoryRequest::default()
-                                            });
+    let request = slack::channels::HistoryRequest::builder().channel(env::args().nth(1).unwrap().parse().unwrap());
+    let response = slack::channels::history(&client, &token, &request.build().unwrap());
 
     if let Ok(response) = response {
         if let Some(messages) = response.messages {
@@ -0,0 +1,89 @@
+//! Non-blocking versions of every Web API method.
+//!
+//! Each module here mirrors the blocking module of the same name: the functions have the same names
+//! and take the same request types, but are `async` and send requests through an
+//! [`AsyncSlackWebRequestSender`](../requests/trait.AsyncSlackWebRequestSender.html). Request,
+//! response and error types are re-exported from the blocking modules, so both APIs can be used
+//! interchangeably.
+//!
+//! This module is only available with the `async` feature enabled.
+
+mod mods;
+pub use self::mods::*;
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use crate::requests::{AsyncSlackWebRequestSender, BoxFuture, RequestBody, SlackWebRequest, SlackWebResponse};
+
+    #[derive(Debug)]
+    struct NeverError;
+
+    impl fmt::Display for NeverError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "never")
+        }
+    }
+
+    impl std::error::Error for NeverError {}
+
+    struct CannedSender(&'static str);
+
+    impl AsyncSlackWebRequestSender for CannedSender {
+        type Error = NeverError;
+
+        fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, NeverError>> {
+            assert_eq!("https://slack.com/api/api.test", request.url);
+            assert_eq!(None, request.token);
+            match request.body {
+                RequestBody::Form(params) => assert_eq!(&[("foo", "bar")], params),
+                _ => panic!("api.test should be sent as a form"),
+            }
+            Box::pin(async move {
+                Ok(SlackWebResponse {
+                    status: 200,
+                    headers: vec![],
+                    body: self.0.to_owned(),
+                })
+            })
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_method_shares_sync_types() {
+        let client = CannedSender(r#"{"ok": true, "args": {"foo": true}}"#);
+        let request = super::api::TestRequest {
+            foo: Some("bar"),
+            ..Default::default()
+        };
+        let response: crate::api::TestResponse = block_on(super::api::test(&client, &request)).unwrap();
+        assert_eq!(Some(&true), response.args.unwrap().get("foo"));
+    }
+
+    #[test]
+    fn test_async_method_maps_errors() {
+        let client = CannedSender(r#"{"ok": false, "error": "invalid_arg_name"}"#);
+        let request = super::api::TestRequest {
+            foo: Some("bar"),
+            ..Default::default()
+        };
+        match block_on(super::api::test(&client, &request)) {
+            Err(crate::api::TestError::InvalidArgName) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
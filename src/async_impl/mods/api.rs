@@ -0,0 +1,30 @@
+pub use crate::api::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Checks API calling code.
+///
+/// Wraps https://api.slack.com/methods/api.test
+pub async fn test<R>(client: &R, request: &TestRequest<'_>) -> Result<TestResponse, TestError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.error.map(|error| ("error", error)),
+        request.foo.map(|foo| ("foo", foo)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "api.test");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: None,
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(TestError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<TestResponse>(&result.body).map_err(TestError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
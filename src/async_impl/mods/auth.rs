@@ -0,0 +1,57 @@
+pub use crate::auth::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Revokes a token.
+///
+/// Wraps https://api.slack.com/methods/auth.revoke
+pub async fn revoke<R>(
+    client: &R,
+    token: &str,
+    request: &RevokeRequest,
+) -> Result<RevokeResponse, RevokeError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.test.map(
+            |test| ("test", if test { "1" } else { "0" })
+        ),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.revoke");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RevokeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RevokeResponse>(&result.body).map_err(RevokeError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Checks authentication & identity.
+///
+/// Wraps https://api.slack.com/methods/auth.test
+pub async fn test<R>(client: &R, token: &str) -> Result<TestResponse, TestError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.test");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(TestError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<TestResponse>(&result.body).map_err(TestError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,31 @@
+pub use crate::bots::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Gets information about a bot user.
+///
+/// Wraps https://api.slack.com/methods/bots.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![request.bot.map(|bot| ("bot", bot.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "bots.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
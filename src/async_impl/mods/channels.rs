@@ -0,0 +1,489 @@
+//! Get info on your team's Slack channels, create or archive channels, invite users, set the topic and purpose, and mark a channel as read.
+
+pub use crate::channels::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Archives a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.archive
+pub async fn archive<R>(
+    client: &R,
+    token: &str,
+    request: &ArchiveRequest<'_>,
+) -> Result<ArchiveResponse, ArchiveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.archive");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ArchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ArchiveResponse>(&result.body).map_err(
+                ArchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Creates a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.create
+pub async fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("name", request.name)),
+        request.validate.map(|validate| {
+            ("validate", if validate { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.create");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CreateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CreateResponse>(&result.body).map_err(CreateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Fetches history of messages and events from a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.history
+pub async fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest<'_>,
+) -> Result<HistoryResponse, HistoryError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        request.latest.map(|latest| ("latest", latest.as_str())),
+        request.oldest.map(|oldest| ("oldest", oldest.as_str())),
+        request.inclusive.map(|inclusive| {
+            ("inclusive", if inclusive { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        request.unreads.map(|unreads| {
+            ("unreads", if unreads { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.history");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(HistoryError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<HistoryResponse>(&result.body).map_err(
+                HistoryError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Invites a user to a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.invite
+pub async fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest<'_>,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.invite");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InviteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InviteResponse>(&result.body).map_err(InviteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Joins a channel, creating it if needed.
+///
+/// Wraps https://api.slack.com/methods/channels.join
+pub async fn join<R>(
+    client: &R,
+    token: &str,
+    request: &JoinRequest<'_>,
+) -> Result<JoinResponse, JoinError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("name", request.name)),
+        request.validate.map(|validate| {
+            ("validate", if validate { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.join");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(JoinError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<JoinResponse>(&result.body).map_err(JoinError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Removes a user from a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.kick
+pub async fn kick<R>(
+    client: &R,
+    token: &str,
+    request: &KickRequest<'_>,
+) -> Result<KickResponse, KickError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.kick");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(KickError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<KickResponse>(&result.body).map_err(KickError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Leaves a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.leave
+pub async fn leave<R>(
+    client: &R,
+    token: &str,
+    request: &LeaveRequest<'_>,
+) -> Result<LeaveResponse, LeaveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.leave");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(LeaveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<LeaveResponse>(&result.body).map_err(LeaveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists all channels in a Slack team.
+///
+/// Wraps https://api.slack.com/methods/channels.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.exclude_archived.map(|exclude_archived| {
+            ("exclude_archived", if exclude_archived { "1" } else { "0" })
+        }),
+        request.exclude_members.map(|exclude_members| {
+            ("exclude_members", if exclude_members { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the read cursor in a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.mark
+pub async fn mark<R>(
+    client: &R,
+    token: &str,
+    request: &MarkRequest<'_>,
+) -> Result<MarkResponse, MarkError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("ts", request.ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.mark");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(MarkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MarkResponse>(&result.body).map_err(MarkError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Renames a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.rename
+pub async fn rename<R>(
+    client: &R,
+    token: &str,
+    request: &RenameRequest<'_>,
+) -> Result<RenameResponse, RenameError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("name", request.name)),
+        request.validate.map(|validate| {
+            ("validate", if validate { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.rename");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RenameError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RenameResponse>(&result.body).map_err(RenameError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Retrieve a thread of messages posted to a channel
+///
+/// Wraps https://api.slack.com/methods/channels.replies
+pub async fn replies<R>(
+    client: &R,
+    token: &str,
+    request: &RepliesRequest<'_>,
+) -> Result<RepliesResponse, RepliesError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("thread_ts", request.thread_ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.replies");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RepliesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RepliesResponse>(&result.body).map_err(
+                RepliesError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the purpose for a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.setPurpose
+pub async fn set_purpose<R>(
+    client: &R,
+    token: &str,
+    request: &SetPurposeRequest<'_>,
+) -> Result<SetPurposeResponse, SetPurposeError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("purpose", request.purpose)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.setPurpose");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetPurposeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPurposeResponse>(&result.body)
+                .map_err(SetPurposeError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the topic for a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.setTopic
+pub async fn set_topic<R>(
+    client: &R,
+    token: &str,
+    request: &SetTopicRequest<'_>,
+) -> Result<SetTopicResponse, SetTopicError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("topic", request.topic)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.setTopic");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetTopicError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetTopicResponse>(&result.body).map_err(
+                SetTopicError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Unarchives a channel.
+///
+/// Wraps https://api.slack.com/methods/channels.unarchive
+pub async fn unarchive<R>(
+    client: &R,
+    token: &str,
+    request: &UnarchiveRequest<'_>,
+) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.unarchive");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(UnarchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UnarchiveResponse>(&result.body).map_err(
+                UnarchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,198 @@
+//! Post chat messages to Slack.
+
+pub use crate::chat::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Deletes a message.
+///
+/// Wraps https://api.slack.com/methods/chat.delete
+pub async fn delete<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRequest<'_>,
+) -> Result<DeleteResponse, DeleteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("ts", request.ts.as_str())),
+        Some(("channel", request.channel.as_str())),
+        request.as_user.map(|as_user| {
+            ("as_user", if as_user { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.delete");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(DeleteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Share a me message into a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.meMessage
+pub async fn me_message<R>(
+    client: &R,
+    token: &str,
+    request: &MeMessageRequest<'_>,
+) -> Result<MeMessageResponse, MeMessageError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("text", request.text)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.meMessage");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(MeMessageError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MeMessageResponse>(&result.body).map_err(
+                MeMessageError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sends a message to a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.postMessage
+pub async fn post_message<R>(
+    client: &R,
+    token: &str,
+    request: &PostMessageRequest<'_>,
+) -> Result<PostMessageResponse, PostMessageError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    if request
+        .attachments
+        .is_some_and(|attachments| attachments.len() > crate::attachments::MAX_ATTACHMENTS)
+    {
+        return Err(PostMessageError::TooManyAttachments);
+    }
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("channel", request.channel.as_str().into())),
+        request.text.map(|text| ("text", text.into())),
+        request.parse.map(|parse| ("parse", parse.into())),
+        request.link_names.map(|link_names| ("link_names", link_names.into())),
+        request.attachments.map(|attachments| ("attachments", crate::json_value(attachments))),
+        request.blocks.map(|blocks| ("blocks", crate::json_value(blocks))),
+        request.unfurl_links.map(|unfurl_links| ("unfurl_links", unfurl_links.into())),
+        request.unfurl_media.map(|unfurl_media| ("unfurl_media", unfurl_media.into())),
+        request.username.map(|username| ("username", username.into())),
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
+        request.icon_url.map(|icon_url| ("icon_url", icon_url.into())),
+        request.icon_emoji.map(|icon_emoji| ("icon_emoji", icon_emoji.into())),
+        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts.as_str().into())),
+        request.reply_broadcast.map(|reply_broadcast| ("reply_broadcast", reply_broadcast.into())),
+    ];
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.postMessage");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
+        .await
+        .map_err(PostMessageError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<PostMessageResponse>(&result.body)
+                .map_err(PostMessageError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Unfurl a URL that a user posted
+///
+/// Wraps https://api.slack.com/methods/chat.unfurl
+pub async fn unfurl<R>(
+    client: &R,
+    token: &str,
+    request: &UnfurlRequest<'_>,
+) -> Result<UnfurlResponse, UnfurlError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("channel", request.channel.as_str().into())),
+        Some(("ts", request.ts.as_str().into())),
+        Some(("unfurls", crate::json_value(request.unfurls))),
+        request.user_auth_required.map(|user_auth_required| ("user_auth_required", user_auth_required.into())),
+    ];
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.unfurl");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
+        .await
+        .map_err(UnfurlError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UnfurlResponse>(&result.body).map_err(UnfurlError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Updates a message.
+///
+/// Wraps https://api.slack.com/methods/chat.update
+pub async fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest<'_>,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    if request
+        .attachments
+        .is_some_and(|attachments| attachments.len() > crate::attachments::MAX_ATTACHMENTS)
+    {
+        return Err(UpdateError::TooManyAttachments);
+    }
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("ts", request.ts.as_str().into())),
+        Some(("channel", request.channel.as_str().into())),
+        request.text.map(|text| ("text", text.into())),
+        request.attachments.map(|attachments| ("attachments", crate::json_value(attachments))),
+        request.blocks.map(|blocks| ("blocks", crate::json_value(blocks))),
+        request.parse.map(|parse| ("parse", parse.into())),
+        request.link_names.map(|link_names| ("link_names", link_names.into())),
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
+    ];
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.update");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
+        .await
+        .map_err(UpdateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UpdateResponse>(&result.body).map_err(UpdateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,146 @@
+//! Adjust and view Do Not Disturb settings for team members.
+
+pub use crate::dnd::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Ends the current user's Do Not Disturb session immediately.
+///
+/// Wraps https://api.slack.com/methods/dnd.endDnd
+pub async fn end_dnd<R>(client: &R, token: &str) -> Result<EndDndResponse, EndDndError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endDnd");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(EndDndError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<EndDndResponse>(&result.body).map_err(EndDndError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Ends the current user's snooze mode immediately.
+///
+/// Wraps https://api.slack.com/methods/dnd.endSnooze
+pub async fn end_snooze<R>(client: &R, token: &str) -> Result<EndSnoozeResponse, EndSnoozeError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endSnooze");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(EndSnoozeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<EndSnoozeResponse>(&result.body).map_err(
+                EndSnoozeError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Retrieves a user's current Do Not Disturb status.
+///
+/// Wraps https://api.slack.com/methods/dnd.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Turns on Do Not Disturb mode for the current user, or changes its duration.
+///
+/// Wraps https://api.slack.com/methods/dnd.setSnooze
+pub async fn set_snooze<R>(
+    client: &R,
+    token: &str,
+    request: &SetSnoozeRequest,
+) -> Result<SetSnoozeResponse, SetSnoozeError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let num_minutes = request.num_minutes.to_string();
+    let params = vec![
+        Some(("num_minutes", &num_minutes[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.setSnooze");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetSnoozeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetSnoozeResponse>(&result.body).map_err(
+                SetSnoozeError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Retrieves the Do Not Disturb status for users on a team.
+///
+/// Wraps https://api.slack.com/methods/dnd.teamInfo
+pub async fn team_info<R>(
+    client: &R,
+    token: &str,
+    request: &TeamInfoRequest<'_>,
+) -> Result<TeamInfoResponse, TeamInfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.users.map(|users| ("users", users)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.teamInfo");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(TeamInfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<TeamInfoResponse>(&result.body).map_err(
+                TeamInfoError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,25 @@
+pub use crate::emoji::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Lists custom emoji for a team.
+///
+/// Wraps https://api.slack.com/methods/emoji.list
+pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "emoji.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,214 @@
+//! Get info on files uploaded to Slack, upload new files to Slack.
+
+pub use crate::files::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, MultipartFile, RequestBody, SlackWebRequest};
+
+/// Deletes a file.
+///
+/// Wraps https://api.slack.com/methods/files.delete
+pub async fn delete<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRequest<'_>,
+) -> Result<DeleteResponse, DeleteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.delete");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(DeleteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about a team file.
+///
+/// Wraps https://api.slack.com/methods/files.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        Some(("file", request.file.as_str())),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists & filters team files.
+///
+/// Wraps https://api.slack.com/methods/files.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let ts_from = request.ts_from.map(|ts_from| ts_from.to_string());
+    let ts_to = request.ts_to.map(|ts_to| ts_to.to_string());
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+        request.channel.map(|channel| ("channel", channel.as_str())),
+        ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
+        ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
+        request.types.map(|types| ("types", types)),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Revokes public/external sharing access for a file
+///
+/// Wraps https://api.slack.com/methods/files.revokePublicURL
+pub async fn revoke_public_url<R>(
+    client: &R,
+    token: &str,
+    request: &RevokePublicURLRequest<'_>,
+) -> Result<RevokePublicURLResponse, RevokePublicURLError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.revokePublicURL");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RevokePublicURLError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RevokePublicURLResponse>(&result.body)
+                .map_err(RevokePublicURLError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Enables a file for public/external sharing.
+///
+/// Wraps https://api.slack.com/methods/files.sharedPublicURL
+pub async fn shared_public_url<R>(
+    client: &R,
+    token: &str,
+    request: &SharedPublicURLRequest<'_>,
+) -> Result<SharedPublicURLResponse, SharedPublicURLError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.sharedPublicURL");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SharedPublicURLError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SharedPublicURLResponse>(&result.body)
+                .map_err(SharedPublicURLError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+
+/// Uploads or creates a file.
+///
+/// Wraps https://api.slack.com/methods/files.upload
+pub async fn upload<R>(
+    client: &R,
+    token: &str,
+    request: &UploadRequest<'_>,
+) -> Result<UploadResponse, UploadError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.content.map(|content| ("content", content)),
+        request.filetype.map(|filetype| ("filetype", filetype)),
+        request.filename.map(|filename| ("filename", filename)),
+        request.title.map(|title| ("title", title)),
+        request.initial_comment.map(|initial_comment| ("initial_comment", initial_comment)),
+        request.channels.map(|channels| ("channels", channels)),
+        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.upload");
+    let body = match request.file {
+        Some(file) => RequestBody::Multipart(
+            &params[..],
+            MultipartFile {
+                name: "file",
+                filename: request.filename.unwrap_or("file"),
+                contents: file,
+            },
+        ),
+        None => RequestBody::Form(&params[..]),
+    };
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body,
+        })
+        .await
+        .map_err(UploadError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UploadResponse>(&result.body)
+                .map_err(UploadError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,97 @@
+pub use crate::files_comments::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Add a comment to an existing file.
+///
+/// Wraps https://api.slack.com/methods/files.comments.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("file", request.file.as_str())),
+        Some(("comment", request.comment)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.add");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Deletes an existing comment on a file.
+///
+/// Wraps https://api.slack.com/methods/files.comments.delete
+pub async fn delete<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRequest<'_>,
+) -> Result<DeleteResponse, DeleteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("file", request.file.as_str())),
+        Some(("id", request.id)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.delete");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(DeleteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Edit an existing file comment.
+///
+/// Wraps https://api.slack.com/methods/files.comments.edit
+pub async fn edit<R>(
+    client: &R,
+    token: &str,
+    request: &EditRequest<'_>,
+) -> Result<EditResponse, EditError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("file", request.file.as_str())),
+        Some(("id", request.id)),
+        Some(("comment", request.comment)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.edit");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(EditError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<EditResponse>(&result.body).map_err(EditError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
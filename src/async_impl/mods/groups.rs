@@ -0,0 +1,538 @@
+//! Get info on your team's private channels.
+
+pub use crate::groups::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Archives a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.archive
+pub async fn archive<R>(
+    client: &R,
+    token: &str,
+    request: &ArchiveRequest<'_>,
+) -> Result<ArchiveResponse, ArchiveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.archive");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ArchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ArchiveResponse>(&result.body).map_err(
+                ArchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Closes a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.close
+pub async fn close<R>(
+    client: &R,
+    token: &str,
+    request: &CloseRequest<'_>,
+) -> Result<CloseResponse, CloseError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.close");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CloseError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CloseResponse>(&result.body).map_err(CloseError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Creates a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.create
+pub async fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("name", request.name)),
+        request.validate.map(|validate| {
+            ("validate", if validate { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.create");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CreateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CreateResponse>(&result.body).map_err(CreateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Clones and archives a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.createChild
+pub async fn create_child<R>(
+    client: &R,
+    token: &str,
+    request: &CreateChildRequest<'_>,
+) -> Result<CreateChildResponse, CreateChildError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.createChild");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CreateChildError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CreateChildResponse>(&result.body)
+                .map_err(CreateChildError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Fetches history of messages and events from a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.history
+pub async fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest<'_>,
+) -> Result<HistoryResponse, HistoryError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        request.latest.map(|latest| ("latest", latest.as_str())),
+        request.oldest.map(|oldest| ("oldest", oldest.as_str())),
+        request.inclusive.map(|inclusive| {
+            ("inclusive", if inclusive { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        request.unreads.map(|unreads| {
+            ("unreads", if unreads { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.history");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(HistoryError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<HistoryResponse>(&result.body).map_err(
+                HistoryError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Invites a user to a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.invite
+pub async fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest<'_>,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.invite");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InviteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InviteResponse>(&result.body).map_err(InviteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Removes a user from a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.kick
+pub async fn kick<R>(
+    client: &R,
+    token: &str,
+    request: &KickRequest<'_>,
+) -> Result<KickResponse, KickError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.kick");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(KickError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<KickResponse>(&result.body).map_err(KickError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Leaves a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.leave
+pub async fn leave<R>(
+    client: &R,
+    token: &str,
+    request: &LeaveRequest<'_>,
+) -> Result<LeaveResponse, LeaveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.leave");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(LeaveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<LeaveResponse>(&result.body).map_err(LeaveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists private channels that the calling user has access to.
+///
+/// Wraps https://api.slack.com/methods/groups.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.exclude_archived.map(|exclude_archived| {
+            ("exclude_archived", if exclude_archived { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the read cursor in a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.mark
+pub async fn mark<R>(
+    client: &R,
+    token: &str,
+    request: &MarkRequest<'_>,
+) -> Result<MarkResponse, MarkError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("ts", request.ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.mark");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(MarkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MarkResponse>(&result.body).map_err(MarkError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Opens a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.open
+pub async fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest<'_>,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.open");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(OpenError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<OpenResponse>(&result.body).map_err(OpenError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Renames a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.rename
+pub async fn rename<R>(
+    client: &R,
+    token: &str,
+    request: &RenameRequest<'_>,
+) -> Result<RenameResponse, RenameError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("name", request.name)),
+        request.validate.map(|validate| {
+            ("validate", if validate { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.rename");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RenameError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RenameResponse>(&result.body).map_err(RenameError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Retrieve a thread of messages posted to a private channel
+///
+/// Wraps https://api.slack.com/methods/groups.replies
+pub async fn replies<R>(
+    client: &R,
+    token: &str,
+    request: &RepliesRequest<'_>,
+) -> Result<RepliesResponse, RepliesError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("thread_ts", request.thread_ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.replies");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RepliesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RepliesResponse>(&result.body).map_err(
+                RepliesError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the purpose for a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.setPurpose
+pub async fn set_purpose<R>(
+    client: &R,
+    token: &str,
+    request: &SetPurposeRequest<'_>,
+) -> Result<SetPurposeResponse, SetPurposeError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("purpose", request.purpose)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.setPurpose");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetPurposeError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPurposeResponse>(&result.body)
+                .map_err(SetPurposeError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the topic for a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.setTopic
+pub async fn set_topic<R>(
+    client: &R,
+    token: &str,
+    request: &SetTopicRequest<'_>,
+) -> Result<SetTopicResponse, SetTopicError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("topic", request.topic)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.setTopic");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetTopicError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetTopicResponse>(&result.body).map_err(
+                SetTopicError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Unarchives a private channel.
+///
+/// Wraps https://api.slack.com/methods/groups.unarchive
+pub async fn unarchive<R>(
+    client: &R,
+    token: &str,
+    request: &UnarchiveRequest<'_>,
+) -> Result<UnarchiveResponse, UnarchiveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "groups.unarchive");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(UnarchiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UnarchiveResponse>(&result.body).map_err(
+                UnarchiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,204 @@
+//! Get info on your direct messages.
+
+pub use crate::im::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Close a direct message channel.
+///
+/// Wraps https://api.slack.com/methods/im.close
+pub async fn close<R>(
+    client: &R,
+    token: &str,
+    request: &CloseRequest<'_>,
+) -> Result<CloseResponse, CloseError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.close");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CloseError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CloseResponse>(&result.body).map_err(CloseError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Fetches history of messages and events from direct message channel.
+///
+/// Wraps https://api.slack.com/methods/im.history
+pub async fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest<'_>,
+) -> Result<HistoryResponse, HistoryError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        request.latest.map(|latest| ("latest", latest.as_str())),
+        request.oldest.map(|oldest| ("oldest", oldest.as_str())),
+        request.inclusive.map(|inclusive| {
+            ("inclusive", if inclusive { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        request.unreads.map(|unreads| {
+            ("unreads", if unreads { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.history");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(HistoryError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<HistoryResponse>(&result.body).map_err(
+                HistoryError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists direct message channels for the calling user.
+///
+/// Wraps https://api.slack.com/methods/im.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = vec![
+        request.cursor.map(|cursor| ("cursor", cursor)),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Sets the read cursor in a direct message channel.
+///
+/// Wraps https://api.slack.com/methods/im.mark
+pub async fn mark<R>(
+    client: &R,
+    token: &str,
+    request: &MarkRequest<'_>,
+) -> Result<MarkResponse, MarkError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("ts", request.ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.mark");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(MarkError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MarkResponse>(&result.body).map_err(MarkError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Opens a direct message channel.
+///
+/// Wraps https://api.slack.com/methods/im.open
+pub async fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest<'_>,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("user", request.user.as_str())),
+        request.return_im.map(|return_im| {
+            ("return_im", if return_im { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.open");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(OpenError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<OpenResponse>(&result.body).map_err(OpenError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Retrieve a thread of messages posted to a direct message conversation
+///
+/// Wraps https://api.slack.com/methods/im.replies
+pub async fn replies<R>(
+    client: &R,
+    token: &str,
+    request: &RepliesRequest<'_>,
+) -> Result<RepliesResponse, RepliesError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("channel", request.channel.as_str())),
+        Some(("thread_ts", request.thread_ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.replies");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RepliesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RepliesResponse>(&result.body).map_err(
+                RepliesError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
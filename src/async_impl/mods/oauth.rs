@@ -0,0 +1,36 @@
+pub use crate::oauth::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Exchanges a temporary OAuth code for an API token.
+///
+/// Wraps https://api.slack.com/methods/oauth.access
+pub async fn access<R>(
+    client: &R,
+    request: &AccessRequest<'_>,
+) -> Result<AccessResponse, AccessError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("client_id", request.client_id)),
+        Some(("client_secret", request.client_secret)),
+        Some(("code", request.code)),
+        request.redirect_uri.map(|redirect_uri| {
+            ("redirect_uri", redirect_uri)
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "oauth.access");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: None,
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AccessError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AccessResponse>(&result.body).map_err(AccessError::MalformedResponse)
+        })
+}
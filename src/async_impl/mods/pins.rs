@@ -0,0 +1,89 @@
+pub use crate::pins::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Pins an item to a channel.
+///
+/// Wraps https://api.slack.com/methods/pins.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params_in(request.channel)?;
+    let params = vec![item, item_ts];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.add");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists items pinned to a channel.
+///
+/// Wraps https://api.slack.com/methods/pins.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Un-pins an item from a channel.
+///
+/// Wraps https://api.slack.com/methods/pins.remove
+pub async fn remove<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveRequest<'_>,
+) -> Result<RemoveResponse, RemoveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params_in(request.channel)?;
+    let params = vec![item, item_ts];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.remove");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RemoveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RemoveResponse>(&result.body).map_err(RemoveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
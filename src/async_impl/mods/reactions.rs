@@ -0,0 +1,141 @@
+pub use crate::reactions::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Adds a reaction to an item.
+///
+/// Wraps https://api.slack.com/methods/reactions.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params();
+    let params = vec![
+        Some(("name", request.name)),
+        item,
+        item_ts,
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.add");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets reactions for an item.
+///
+/// Wraps https://api.slack.com/methods/reactions.get
+pub async fn get<R>(
+    client: &R,
+    token: &str,
+    request: &GetRequest<'_>,
+) -> Result<GetResponse, GetError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params();
+    let params = vec![
+        item,
+        item_ts,
+        request.full.map(
+            |full| ("full", if full { "1" } else { "0" })
+        ),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.get");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(GetError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists reactions made by a user.
+///
+/// Wraps https://api.slack.com/methods/reactions.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+        request.full.map(
+            |full| ("full", if full { "1" } else { "0" })
+        ),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Removes a reaction from an item.
+///
+/// Wraps https://api.slack.com/methods/reactions.remove
+pub async fn remove<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveRequest<'_>,
+) -> Result<RemoveResponse, RemoveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params();
+    let params = vec![
+        Some(("name", request.name)),
+        item,
+        item_ts,
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.remove");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RemoveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RemoveResponse>(&result.body).map_err(RemoveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,144 @@
+pub use crate::reminders::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Creates a reminder.
+///
+/// Wraps https://api.slack.com/methods/reminders.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let time = request.time.to_string();
+    let params = vec![
+        Some(("text", request.text)),
+        Some(("time", &time[..])),
+        request.user.map(|user| ("user", user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.add");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Marks a reminder as complete.
+///
+/// Wraps https://api.slack.com/methods/reminders.complete
+pub async fn complete<R>(
+    client: &R,
+    token: &str,
+    request: &CompleteRequest<'_>,
+) -> Result<CompleteResponse, CompleteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.complete");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CompleteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CompleteResponse>(&result.body).map_err(
+                CompleteError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Deletes a reminder.
+///
+/// Wraps https://api.slack.com/methods/reminders.delete
+pub async fn delete<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRequest<'_>,
+) -> Result<DeleteResponse, DeleteError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.delete");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(DeleteError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about a reminder.
+///
+/// Wraps https://api.slack.com/methods/reminders.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists all reminders created by or for a given user.
+///
+/// Wraps https://api.slack.com/methods/reminders.list
+pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
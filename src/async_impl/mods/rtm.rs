@@ -0,0 +1,74 @@
+pub use crate::rtm::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Starts a Real Time Messaging session.
+///
+/// Wraps https://api.slack.com/methods/rtm.connect
+pub async fn connect<R>(client: &R, token: &str) -> Result<ConnectResponse, ConnectError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "rtm.connect");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(ConnectError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ConnectResponse>(&result.body).map_err(
+                ConnectError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Starts a Real Time Messaging session.
+///
+/// Wraps https://api.slack.com/methods/rtm.start
+pub async fn start<R>(
+    client: &R,
+    token: &str,
+    request: &StartRequest,
+) -> Result<StartResponse, StartError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.no_unreads.map(|no_unreads| {
+            ("no_unreads", if no_unreads { "1" } else { "0" })
+        }),
+        request.mpim_aware.map(|mpim_aware| {
+            ("mpim_aware", if mpim_aware { "1" } else { "0" })
+        }),
+        request.no_latest.map(|no_latest| {
+            ("no_latest", if no_latest { "1" } else { "0" })
+        }),
+        request.batch_presence_aware.map(|batch_presence_aware| {
+            (
+                "batch_presence_aware",
+                if batch_presence_aware { "1" } else { "0" },
+            )
+        }),
+        request.include_locale.map(|include_locale| {
+            ("include_locale", if include_locale { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "rtm.start");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(StartError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<StartResponse>(&result.body).map_err(StartError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
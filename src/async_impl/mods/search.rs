@@ -0,0 +1,124 @@
+//! Search your team's files and messages.
+
+pub use crate::search::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Searches for messages and files matching a query.
+///
+/// Wraps https://api.slack.com/methods/search.all
+pub async fn all<R>(
+    client: &R,
+    token: &str,
+    request: &AllRequest<'_>,
+) -> Result<AllResponse, AllError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        Some(("query", request.query)),
+        request.sort.map(|sort| ("sort", sort)),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        request.highlight.map(|highlight| {
+            ("highlight", if highlight { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.all");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AllError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AllResponse>(&result.body).map_err(AllError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Searches for files matching a query.
+///
+/// Wraps https://api.slack.com/methods/search.files
+pub async fn files<R>(
+    client: &R,
+    token: &str,
+    request: &FilesRequest<'_>,
+) -> Result<FilesResponse, FilesError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        Some(("query", request.query)),
+        request.sort.map(|sort| ("sort", sort)),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        request.highlight.map(|highlight| {
+            ("highlight", if highlight { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.files");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(FilesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<FilesResponse>(&result.body).map_err(FilesError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Searches for messages matching a query.
+///
+/// Wraps https://api.slack.com/methods/search.messages
+pub async fn messages<R>(
+    client: &R,
+    token: &str,
+    request: &MessagesRequest<'_>,
+) -> Result<MessagesResponse, MessagesError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        Some(("query", request.query)),
+        request.sort.map(|sort| ("sort", sort)),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        request.highlight.map(|highlight| {
+            ("highlight", if highlight { "1" } else { "0" })
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.messages");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(MessagesError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<MessagesResponse>(&result.body).map_err(
+                MessagesError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
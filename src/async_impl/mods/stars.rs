@@ -0,0 +1,100 @@
+pub use crate::stars::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Adds a star to an item.
+///
+/// Wraps https://api.slack.com/methods/stars.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params();
+    let params = vec![
+        item,
+        item_ts,
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.add");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AddError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists stars for a user.
+///
+/// Wraps https://api.slack.com/methods/stars.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Removes a star from an item.
+///
+/// Wraps https://api.slack.com/methods/stars.remove
+pub async fn remove<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveRequest<'_>,
+) -> Result<RemoveResponse, RemoveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let [item, item_ts] = request.item.params();
+    let params = vec![
+        item,
+        item_ts,
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "stars.remove");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(RemoveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<RemoveResponse>(&result.body).map_err(RemoveError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
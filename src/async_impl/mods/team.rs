@@ -0,0 +1,134 @@
+pub use crate::team::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Gets the access logs for the current team.
+///
+/// Wraps https://api.slack.com/methods/team.accessLogs
+pub async fn access_logs<R>(
+    client: &R,
+    token: &str,
+    request: &AccessLogsRequest,
+) -> Result<AccessLogsResponse, AccessLogsError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let before = request.before.map(|before| before.to_string());
+    let params = vec![
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+        before.as_ref().map(|before| ("before", &before[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.accessLogs");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(AccessLogsError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<AccessLogsResponse>(&result.body)
+                .map_err(AccessLogsError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets billable users information for the current team.
+///
+/// Wraps https://api.slack.com/methods/team.billableInfo
+pub async fn billable_info<R>(
+    client: &R,
+    token: &str,
+    request: &BillableInfoRequest<'_>,
+) -> Result<BillableInfoResponse, BillableInfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.billableInfo");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(BillableInfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<BillableInfoResponse>(&result.body)
+                .map_err(BillableInfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about the current team.
+///
+/// Wraps https://api.slack.com/methods/team.info
+pub async fn info<R>(client: &R, token: &str) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets the integration logs for the current team.
+///
+/// Wraps https://api.slack.com/methods/team.integrationLogs
+pub async fn integration_logs<R>(
+    client: &R,
+    token: &str,
+    request: &IntegrationLogsRequest<'_>,
+) -> Result<IntegrationLogsResponse, IntegrationLogsError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let count = request.count.map(|count| count.to_string());
+    let page = request.page.map(|page| page.to_string());
+    let params = vec![
+        request.service_id.map(
+            |service_id| ("service_id", service_id)
+        ),
+        request.app_id.map(|app_id| ("app_id", app_id)),
+        request.user.map(|user| ("user", user.as_str())),
+        request.change_type.map(|change_type| {
+            ("change_type", change_type)
+        }),
+        count.as_ref().map(|count| ("count", &count[..])),
+        page.as_ref().map(|page| ("page", &page[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.integrationLogs");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(IntegrationLogsError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<IntegrationLogsResponse>(&result.body)
+                .map_err(IntegrationLogsError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
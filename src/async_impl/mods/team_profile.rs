@@ -0,0 +1,35 @@
+pub use crate::team_profile::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Retrieve a team's profile.
+///
+/// Wraps https://api.slack.com/methods/team.profile.get
+pub async fn get<R>(
+    client: &R,
+    token: &str,
+    request: &GetRequest<'_>,
+) -> Result<GetResponse, GetError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.visibility.map(
+            |visibility| ("visibility", visibility)
+        ),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.profile.get");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(GetError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,188 @@
+//! Get info on your team's User Groups.
+
+pub use crate::usergroups::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Create a User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.create
+pub async fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("name", request.name)),
+        request.handle.map(|handle| ("handle", handle)),
+        request.description.map(|description| {
+            ("description", description)
+        }),
+        request.channels.map(|channels| ("channels", channels)),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.create");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(CreateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<CreateResponse>(&result.body).map_err(CreateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Disable an existing User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.disable
+pub async fn disable<R>(
+    client: &R,
+    token: &str,
+    request: &DisableRequest<'_>,
+) -> Result<DisableResponse, DisableError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("usergroup", request.usergroup.as_str())),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.disable");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(DisableError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DisableResponse>(&result.body).map_err(
+                DisableError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Enable a User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.enable
+pub async fn enable<R>(
+    client: &R,
+    token: &str,
+    request: &EnableRequest<'_>,
+) -> Result<EnableResponse, EnableError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("usergroup", request.usergroup.as_str())),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.enable");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(EnableError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<EnableResponse>(&result.body).map_err(EnableError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// List all User Groups for a team
+///
+/// Wraps https://api.slack.com/methods/usergroups.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.include_disabled.map(|include_disabled| {
+            ("include_disabled", if include_disabled { "1" } else { "0" })
+        }),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+        request.include_users.map(|include_users| {
+            ("include_users", if include_users { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Update an existing User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.update
+pub async fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest<'_>,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("usergroup", request.usergroup.as_str())),
+        request.name.map(|name| ("name", name)),
+        request.handle.map(|handle| ("handle", handle)),
+        request.description.map(|description| {
+            ("description", description)
+        }),
+        request.channels.map(|channels| ("channels", channels)),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.update");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(UpdateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UpdateResponse>(&result.body).map_err(UpdateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,70 @@
+pub use crate::usergroups_users::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// List all users in a User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.users.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("usergroup", request.usergroup.as_str())),
+        request.include_disabled.map(|include_disabled| {
+            ("include_disabled", if include_disabled { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.users.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Update the list of users for a User Group
+///
+/// Wraps https://api.slack.com/methods/usergroups.users.update
+pub async fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest<'_>,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        Some(("usergroup", request.usergroup.as_str())),
+        Some(("users", request.users)),
+        request.include_count.map(|include_count| {
+            ("include_count", if include_count { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.users.update");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(UpdateError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UpdateResponse>(&result.body).map_err(UpdateError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
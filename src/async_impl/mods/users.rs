@@ -0,0 +1,242 @@
+//! Get info on members of your Slack team.
+
+pub use crate::users::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, MultipartFile, RequestBody, SlackWebRequest};
+
+/// Delete the user profile photo
+///
+/// Wraps https://api.slack.com/methods/users.deletePhoto
+pub async fn delete_photo<R>(
+    client: &R,
+    token: &str,
+) -> Result<DeletePhotoResponse, DeletePhotoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.deletePhoto");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(DeletePhotoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<DeletePhotoResponse>(&result.body)
+                .map_err(DeletePhotoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets user presence information.
+///
+/// Wraps https://api.slack.com/methods/users.getPresence
+pub async fn get_presence<R>(
+    client: &R,
+    token: &str,
+    request: &GetPresenceRequest<'_>,
+) -> Result<GetPresenceResponse, GetPresenceError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("user", request.user.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.getPresence");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(GetPresenceError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetPresenceResponse>(&result.body)
+                .map_err(GetPresenceError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Get a user's identity.
+///
+/// Wraps https://api.slack.com/methods/users.identity
+pub async fn identity<R>(client: &R, token: &str) -> Result<IdentityResponse, IdentityError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.identity");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(IdentityError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<IdentityResponse>(&result.body).map_err(
+                IdentityError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Gets information about a user.
+///
+/// Wraps https://api.slack.com/methods/users.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("user", request.user.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.info");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(InfoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Lists all users in a Slack team.
+///
+/// Wraps https://api.slack.com/methods/users.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.presence.map(|presence| {
+            ("presence", if presence { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.list");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(ListError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Marks a user as active.
+///
+/// Wraps https://api.slack.com/methods/users.setActive
+pub async fn set_active<R>(client: &R, token: &str) -> Result<SetActiveResponse, SetActiveError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setActive");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
+        .await
+        .map_err(SetActiveError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetActiveResponse>(&result.body).map_err(
+                SetActiveError::MalformedResponse,
+            )
+        })
+        .and_then(|o| o.into())
+}
+
+/// Set the user profile photo
+///
+/// Wraps https://api.slack.com/methods/users.setPhoto
+pub async fn set_photo<R>(
+    client: &R,
+    token: &str,
+    request: &SetPhotoRequest<'_>,
+) -> Result<SetPhotoResponse, SetPhotoError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let crop_x = request.crop_x.map(|crop_x| crop_x.to_string());
+    let crop_y = request.crop_y.map(|crop_y| crop_y.to_string());
+    let crop_w = request.crop_w.map(|crop_w| crop_w.to_string());
+    let params = vec![
+        crop_x.as_ref().map(|crop_x| ("crop_x", &crop_x[..])),
+        crop_y.as_ref().map(|crop_y| ("crop_y", &crop_y[..])),
+        crop_w.as_ref().map(|crop_w| ("crop_w", &crop_w[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPhoto");
+    let body = RequestBody::Multipart(
+        &params[..],
+        MultipartFile {
+            name: "image",
+            filename: "image",
+            contents: request.image,
+        },
+    );
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body,
+        })
+        .await
+        .map_err(SetPhotoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPhotoResponse>(&result.body)
+                .map_err(SetPhotoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+
+/// Manually sets user presence.
+///
+/// Wraps https://api.slack.com/methods/users.setPresence
+pub async fn set_presence<R>(
+    client: &R,
+    token: &str,
+    request: &SetPresenceRequest<'_>,
+) -> Result<SetPresenceResponse, SetPresenceError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![Some(("presence", request.presence))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPresence");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetPresenceError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPresenceResponse>(&result.body)
+                .map_err(SetPresenceError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
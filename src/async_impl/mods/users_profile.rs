@@ -0,0 +1,69 @@
+pub use crate::users_profile::*;
+
+use crate::requests::{AsyncSlackWebRequestSender, RequestBody, SlackWebRequest};
+
+/// Retrieves a user's profile information.
+///
+/// Wraps https://api.slack.com/methods/users.profile.get
+pub async fn get<R>(
+    client: &R,
+    token: &str,
+    request: &GetRequest<'_>,
+) -> Result<GetResponse, GetError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+        request.include_labels.map(|include_labels| {
+            ("include_labels", if include_labels { "1" } else { "0" })
+        }),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.get");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(GetError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+/// Set the profile information for a user.
+///
+/// Wraps https://api.slack.com/methods/users.profile.set
+pub async fn set<R>(
+    client: &R,
+    token: &str,
+    request: &SetRequest<'_>,
+) -> Result<SetResponse, SetError<R::Error>>
+where
+    R: AsyncSlackWebRequestSender,
+{
+    let params = vec![
+        request.user.map(|user| ("user", user.as_str())),
+        request.profile.map(|profile| ("profile", profile)),
+        request.name.map(|name| ("name", name)),
+        request.value.map(|value| ("value", value)),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.set");
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
+        .await
+        .map_err(SetError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetResponse>(&result.body).map_err(SetError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,338 @@
+//! Message attachments, which are sent with the `attachments` parameter of `chat.postMessage` and
+//! `chat.update` and the `unfurls` of `chat.unfurl`.
+//!
+//! ```
+//! use slack_api::attachments::{Attachment, AttachmentAction, AttachmentField};
+//!
+//! let attachments = vec![Attachment::builder()
+//!     .fallback("Deploy finished")
+//!     .color("good")
+//!     .author_name("CI")
+//!     .title("Deploy finished")
+//!     .field(AttachmentField::new("Environment", "production").short(true))
+//!     .callback_id("deploy")
+//!     .action(AttachmentAction::button("rollback", "Roll back", "v42"))
+//!     .footer("deploys")
+//!     .build()];
+//! let request = slack_api::chat::PostMessageRequest::builder()
+//!     .channel("C1234567890".parse().unwrap())
+//!     .text("Deploy finished")
+//!     .attachments(attachments);
+//! ```
+
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+/// The most attachments a message can have. Chat methods given more fail with
+/// `TooManyAttachments` without sending the request.
+pub const MAX_ATTACHMENTS: usize = 100;
+
+/// A legacy secondary block of a message, made with [`Attachment::builder`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Attachment {
+    /// A plain text summary, shown where the attachment can not be
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// `good`, `warning`, `danger` or a hex color code such as `#439FE0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// Text shown above the attachment
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<AttachmentField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_icon: Option<String>,
+    /// The time shown in the footer, which is sent as a number of seconds since the epoch
+    #[serde(serialize_with = "serialize_numeric_ts")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Identifies the attachment in the payload sent when one of its actions is used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<AttachmentAction>>,
+    /// The names of the fields formatted with Slack's markup, such as `text` and `pretext`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn_in: Option<Vec<String>>,
+    /// Layout blocks, read as leniently as those of a received message
+    #[serde(default, deserialize_with = "crate::blocks::deserialize_received")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+}
+
+impl Attachment {
+    /// Returns a builder for an attachment.
+    pub fn builder() -> AttachmentBuilder {
+        Default::default()
+    }
+}
+
+/// A builder for an [`Attachment`].
+#[derive(Clone, Default, Debug)]
+pub struct AttachmentBuilder {
+    attachment: Attachment,
+}
+
+macro_rules! string_setters {
+    ($($(#[$attr:meta])* $name:ident),+) => {
+        $(
+            $(#[$attr])*
+            pub fn $name<S: Into<String>>(mut self, $name: S) -> Self {
+                self.attachment.$name = Some($name.into());
+                self
+            }
+        )+
+    };
+}
+
+impl AttachmentBuilder {
+    string_setters!(
+        /// A plain text summary, shown where the attachment can not be.
+        fallback,
+        /// `good`, `warning`, `danger` or a hex color code such as `#439FE0`.
+        color,
+        /// Text shown above the attachment.
+        pretext,
+        author_name,
+        author_link,
+        author_icon,
+        title,
+        title_link,
+        text,
+        image_url,
+        thumb_url,
+        footer,
+        footer_icon,
+        /// Identifies the attachment in the payload sent when one of its actions is used.
+        callback_id
+    );
+
+    /// Adds a field, shown in a table below the text.
+    pub fn field(mut self, field: AttachmentField) -> Self {
+        self.attachment.fields.get_or_insert_with(Vec::new).push(field);
+        self
+    }
+
+    /// Adds a button or menu.
+    pub fn action(mut self, action: AttachmentAction) -> Self {
+        self.attachment.actions.get_or_insert_with(Vec::new).push(action);
+        self
+    }
+
+    /// The time shown in the footer.
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.attachment.ts = Some(ts);
+        self
+    }
+
+    /// The names of the fields formatted with Slack's markup, such as `text` and `pretext`.
+    pub fn mrkdwn_in<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.attachment.mrkdwn_in = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Layout blocks, shown in the attachment instead of its text and fields.
+    pub fn blocks(mut self, blocks: Vec<crate::blocks::Block>) -> Self {
+        self.attachment.blocks = Some(blocks);
+        self
+    }
+
+    /// Returns the attachment.
+    pub fn build(self) -> Attachment {
+        self.attachment
+    }
+}
+
+/// A title and value shown in a table of an attachment.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachmentField {
+    pub title: String,
+    pub value: String,
+    /// Whether the field is short enough to be shown next to another
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short: Option<bool>,
+}
+
+impl AttachmentField {
+    /// Returns a field showing `value` under the heading `title`.
+    pub fn new<T: Into<String>, V: Into<String>>(title: T, value: V) -> Self {
+        AttachmentField {
+            title: title.into(),
+            value: value.into(),
+            short: None,
+        }
+    }
+
+    /// Sets whether the field is short enough to be shown next to another.
+    pub fn short(mut self, short: bool) -> Self {
+        self.short = Some(short);
+        self
+    }
+}
+
+/// A button or menu of an attachment, made with [`AttachmentAction::button`] or
+/// [`AttachmentAction::select`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AttachmentAction {
+    pub name: String,
+    pub text: String,
+    /// `button` or `select`
+    #[serde(rename = "type")]
+    pub ty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// A link opened by a button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// `default`, `primary` or `danger`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ActionConfirmation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ActionOption>>,
+    /// `users`, `channels`, `conversations` or `external` to fill a menu from elsewhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_source: Option<String>,
+}
+
+impl AttachmentAction {
+    /// Returns a button that sends `value` when it is clicked.
+    pub fn button<N, T, V>(name: N, text: T, value: V) -> Self
+    where
+        N: Into<String>,
+        T: Into<String>,
+        V: Into<String>,
+    {
+        AttachmentAction {
+            name: name.into(),
+            text: text.into(),
+            ty: "button".into(),
+            value: Some(value.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Returns a menu of `options`.
+    pub fn select<N: Into<String>, T: Into<String>>(name: N, text: T, options: Vec<ActionOption>) -> Self {
+        AttachmentAction {
+            name: name.into(),
+            text: text.into(),
+            ty: "select".into(),
+            options: Some(options),
+            ..Default::default()
+        }
+    }
+}
+
+/// An option of a menu, showing `text` and sending `value` when it is chosen.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ActionOption {
+    pub text: String,
+    pub value: String,
+}
+
+/// A dialog asking to confirm an action before it is taken.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ActionConfirmation {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismiss_text: Option<String>,
+}
+
+/// Serializes the `ts` of an attachment as a number, which is how Slack documents it.
+fn serialize_numeric_ts<S: serde::Serializer>(ts: &Option<crate::Ts>, serializer: S) -> Result<S::Ok, S::Error> {
+    use serde::Serialize;
+
+    ts.as_ref().map(crate::Ts::to_numeric).serialize(serializer)
+}
+
+/// The attachments that links in a message unfurl into, by the URL of the link.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Unfurls(pub BTreeMap<String, Attachment>);
+
+impl Unfurls {
+    /// Returns no unfurls.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Unfurls the link to `url` into `attachment`.
+    pub fn unfurl<S: Into<String>>(mut self, url: S, attachment: Attachment) -> Self {
+        self.0.insert(url.into(), attachment);
+        self
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, Attachment)> for Unfurls {
+    fn from_iter<I: IntoIterator<Item = (S, Attachment)>>(iter: I) -> Self {
+        Unfurls(iter.into_iter().map(|(url, attachment)| (url.into(), attachment)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Attachment, AttachmentField, Unfurls};
+    use crate::mock::MockSender;
+
+    #[test]
+    fn test_attachments_serialize_what_was_set() {
+        let attachment = Attachment::builder()
+            .color("good")
+            .field(AttachmentField::new("Env", "prod").short(true))
+            .ts("1503435956".parse().unwrap())
+            .build();
+        let unfurls = Unfurls::new().unfurl("https://example.com", attachment);
+        assert_eq!(
+            serde_json::json!({"https://example.com": {
+                "color": "good",
+                "fields": [{"title": "Env", "value": "prod", "short": true}],
+                "ts": 1503435956,
+            }}),
+            serde_json::to_value(&unfurls).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_too_many_attachments_are_not_sent() {
+        let client = MockSender::new();
+        let attachments = vec![Attachment::builder().text("hi").build(); super::MAX_ATTACHMENTS + 1];
+        let request = crate::chat::PostMessageRequest::builder()
+            .channel("C1234567890".parse().unwrap())
+            .text("Hello")
+            .attachments(attachments);
+        match crate::chat::post_message(&client, "xoxb-token", &request.build().unwrap()) {
+            Err(crate::chat::PostMessageError::TooManyAttachments) => {}
+            other => panic!("{:?}", other),
+        }
+        assert!(client.calls().is_empty());
+    }
+}
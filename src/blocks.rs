@@ -0,0 +1,587 @@
+//! Block Kit layout blocks, the elements inside them and the objects they are composed of.
+//!
+//! Blocks are sent with the `blocks` parameter of `chat.postMessage` and `chat.update`, and are
+//! parsed from the `blocks` of received messages. Blocks, elements and text of a type that is not
+//! modelled here are kept as the JSON they were sent as, but one of a modelled type that does not
+//! parse fails. Received messages are read leniently instead, keeping any block that does not parse
+//! as its JSON, so that a block Slack sends in a newer shape does not fail the whole message.
+//!
+//! ```
+//! use slack_api::blocks::{ActionsBlock, Block, ButtonElement, SectionBlock, Text};
+//!
+//! let blocks = vec![
+//!     Block::from(SectionBlock::new(Text::mrkdwn("*Deploy* finished"))),
+//!     Block::from(ActionsBlock::new(vec![ButtonElement::new(Text::plain("Roll back"), "rollback").into()])),
+//! ];
+//! let request = slack_api::chat::PostMessageRequest::builder()
+//!     .channel("C1234567890".parse().unwrap())
+//!     .text("Deploy finished")
+//!     .blocks(blocks);
+//! ```
+
+/// A layout block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Actions(ActionsBlock),
+    Context(ContextBlock),
+    Divider(DividerBlock),
+    Image(ImageBlock),
+    Input(InputBlock),
+    Section(SectionBlock),
+    /// A block of another type, or a received block that does not parse, as it was sent
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// An interactive element of a block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BlockElement {
+    Button(ButtonElement),
+    ChannelsSelect(ChannelsSelectElement),
+    ConversationsSelect(ConversationsSelectElement),
+    Datepicker(DatepickerElement),
+    ExternalSelect(ExternalSelectElement),
+    Image(ImageElement),
+    Overflow(OverflowElement),
+    PlainTextInput(PlainTextInputElement),
+    StaticSelect(StaticSelectElement),
+    UsersSelect(UsersSelectElement),
+    /// An element of another type, as it was sent
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// An element of a [`ContextBlock`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextElement {
+    Image(ImageElement),
+    Mrkdwn(MrkdwnText),
+    PlainText(PlainText),
+    /// An element of another type, as it was sent
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+/// A text object.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    Mrkdwn(MrkdwnText),
+    PlainText(PlainText),
+    /// Text of another type, as it was sent
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+deserialize_by_type!(Block {
+    "actions" => Actions(_),
+    "context" => Context(_),
+    "divider" => Divider(_),
+    "image" => Image(_),
+    "input" => Input(_),
+    "section" => Section(_),
+});
+
+deserialize_by_type!(BlockElement {
+    "button" => Button(_),
+    "channels_select" => ChannelsSelect(_),
+    "conversations_select" => ConversationsSelect(_),
+    "datepicker" => Datepicker(_),
+    "external_select" => ExternalSelect(_),
+    "image" => Image(_),
+    "overflow" => Overflow(_),
+    "plain_text_input" => PlainTextInput(_),
+    "static_select" => StaticSelect(_),
+    "users_select" => UsersSelect(_),
+});
+
+deserialize_by_type!(ContextElement {
+    "image" => Image(_),
+    "mrkdwn" => Mrkdwn(_),
+    "plain_text" => PlainText(_),
+});
+
+deserialize_by_type!(Text {
+    "mrkdwn" => Mrkdwn(_),
+    "plain_text" => PlainText(_),
+});
+
+impl Text {
+    /// Returns plain text, with emoji codes shown as emoji.
+    pub fn plain<S: Into<String>>(text: S) -> Self {
+        Text::PlainText(PlainText {
+            text: text.into(),
+            emoji: Some(true),
+        })
+    }
+
+    /// Returns text formatted with Slack's markup.
+    pub fn mrkdwn<S: Into<String>>(text: S) -> Self {
+        Text::Mrkdwn(MrkdwnText {
+            text: text.into(),
+            verbatim: None,
+        })
+    }
+
+    /// Returns the text itself, or the `text` of text of another type if it has one.
+    pub fn text(&self) -> &str {
+        match *self {
+            Text::Mrkdwn(ref t) => &t.text,
+            Text::PlainText(ref t) => &t.text,
+            Text::Unknown(ref json) => json.get("text").and_then(serde_json::Value::as_str).unwrap_or(""),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlainText {
+    pub text: String,
+    /// Whether emoji codes are shown as emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct MrkdwnText {
+    pub text: String,
+    /// Whether URLs, channel names and mentions are left as they are written
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim: Option<bool>,
+}
+
+/// An option of a select menu or overflow menu.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SelectOption {
+    pub text: Text,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl SelectOption {
+    /// Returns an option showing `text`, which sends `value` when it is chosen.
+    pub fn new<S: Into<String>>(text: Text, value: S) -> Self {
+        SelectOption {
+            text,
+            value: value.into(),
+            description: None,
+            url: None,
+        }
+    }
+}
+
+/// A labelled group of options of a select menu.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<SelectOption>,
+}
+
+/// A dialog asking to confirm an action before it is taken.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConfirmationDialog {
+    pub title: Text,
+    pub text: Text,
+    pub confirm: Text,
+    pub deny: Text,
+    /// `primary` or `danger`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ActionsBlock {
+    /// Returns a block holding the interactive `elements`.
+    pub fn new(elements: Vec<BlockElement>) -> Self {
+        ActionsBlock {
+            elements,
+            block_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ContextBlock {
+    /// Returns a block showing the images and text of `elements` in small print.
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        ContextBlock {
+            elements,
+            block_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InputBlock {
+    pub label: Text,
+    pub element: BlockElement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    /// Whether the input may be left empty
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
+    /// Whether changing the input sends a `block_actions` payload
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl InputBlock {
+    /// Returns a block collecting input with `element`, shown under `label`.
+    pub fn new(label: Text, element: BlockElement) -> Self {
+        InputBlock {
+            label,
+            element,
+            hint: None,
+            optional: None,
+            dispatch_action: None,
+            block_id: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    /// Text shown in two columns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    /// An element shown next to the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl SectionBlock {
+    /// Returns a block showing `text`.
+    pub fn new(text: Text) -> Self {
+        SectionBlock {
+            text: Some(text),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ButtonElement {
+    pub text: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// `primary` or `danger`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+impl ButtonElement {
+    /// Returns a button showing `text`, whose clicks are sent with `action_id`.
+    pub fn new<S: Into<String>>(text: Text, action_id: S) -> Self {
+        ButtonElement {
+            text,
+            action_id: action_id.into(),
+            url: None,
+            value: None,
+            style: None,
+            confirm: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StaticSelectElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<SelectOption>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ExternalSelectElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+    /// How many characters must be typed before options are requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct UsersSelectElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ConversationsSelectElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<crate::ChannelId>,
+    /// Whether the conversation the message is in is selected by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_to_current_conversation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ChannelsSelectElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DatepickerElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    /// The date selected by default, as `YYYY-MM-DD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct OverflowElement {
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmationDialog>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct PlainTextInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiline: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+macro_rules! from_variant {
+    ($enum:ident, $($variant:ident($ty:ident)),+) => {
+        $(
+            impl From<$ty> for $enum {
+                fn from(inner: $ty) -> Self {
+                    $enum::$variant(inner)
+                }
+            }
+        )+
+    };
+}
+
+from_variant!(
+    Block,
+    Actions(ActionsBlock),
+    Context(ContextBlock),
+    Divider(DividerBlock),
+    Image(ImageBlock),
+    Input(InputBlock),
+    Section(SectionBlock)
+);
+
+from_variant!(
+    BlockElement,
+    Button(ButtonElement),
+    ChannelsSelect(ChannelsSelectElement),
+    ConversationsSelect(ConversationsSelectElement),
+    Datepicker(DatepickerElement),
+    ExternalSelect(ExternalSelectElement),
+    Image(ImageElement),
+    Overflow(OverflowElement),
+    PlainTextInput(PlainTextInputElement),
+    StaticSelect(StaticSelectElement),
+    UsersSelect(UsersSelectElement)
+);
+
+from_variant!(ContextElement, Image(ImageElement), Mrkdwn(MrkdwnText), PlainText(PlainText));
+
+impl From<Text> for ContextElement {
+    fn from(text: Text) -> Self {
+        match text {
+            Text::Mrkdwn(t) => ContextElement::Mrkdwn(t),
+            Text::PlainText(t) => ContextElement::PlainText(t),
+            Text::Unknown(json) => ContextElement::Unknown(json),
+        }
+    }
+}
+
+/// Deserializes the blocks of a received message, keeping any that does not parse as
+/// `Block::Unknown`.
+pub(crate) fn deserialize_received<'de, D>(deserializer: D) -> Result<Option<Vec<Block>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    let blocks = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?;
+    Ok(blocks.map(|blocks| {
+        blocks
+            .into_iter()
+            .map(|block| Block::deserialize(&block).unwrap_or(Block::Unknown(block)))
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Block, BlockElement, ButtonElement, ContextElement, SectionBlock, Text};
+
+    #[test]
+    fn test_blocks_serialize_with_their_type() {
+        let mut section = SectionBlock::new(Text::mrkdwn("*Hello*"));
+        section.accessory = Some(ButtonElement::new(Text::plain("Wave"), "wave").into());
+        let json = serde_json::to_value(Block::from(section)).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "type": "section",
+                "text": {"type": "mrkdwn", "text": "*Hello*"},
+                "accessory": {
+                    "type": "button",
+                    "text": {"type": "plain_text", "text": "Wave", "emoji": true},
+                    "action_id": "wave",
+                },
+            }),
+            json
+        );
+    }
+
+    #[test]
+    fn test_unknown_blocks_keep_their_json() {
+        let json = serde_json::json!([
+            {"type": "divider"},
+            {"type": "rich_text", "elements": []},
+            {"type": "actions", "elements": [{"type": "workflow_button", "text": "Go"}]},
+            {"type": "context", "elements": [{"type": "user", "user_id": "U1"}]},
+            {"type": "section", "text": {"type": "rich_text", "text": "Hi"}},
+        ]);
+        let blocks: Vec<Block> = serde_json::from_value(json.clone()).unwrap();
+        match &blocks[..] {
+            [Block::Divider(_), Block::Unknown(_), Block::Actions(a), Block::Context(c), Block::Section(s)] => {
+                assert!(matches!(a.elements[0], BlockElement::Unknown(_)));
+                assert!(matches!(c.elements[0], ContextElement::Unknown(_)));
+                assert_eq!("Hi", s.text.as_ref().unwrap().text());
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(json, serde_json::to_value(&blocks).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_blocks_of_a_known_type_fail() {
+        let section = serde_json::json!({"type": "section", "text": {"type": "mrkdwn"}});
+        assert!(serde_json::from_value::<Block>(section).is_err());
+        let button = serde_json::json!({"type": "button", "action_id": "wave"});
+        assert!(serde_json::from_value::<BlockElement>(button).is_err());
+    }
+
+    #[test]
+    fn test_messages_parse_their_blocks() {
+        let json = r#"{"type": "message", "ts": "1.000001", "text": "Hi", "blocks": [
+            {"type": "section", "block_id": "a", "text": {"type": "mrkdwn", "text": "*Hi*"}},
+            {"type": "divider"}
+        ]}"#;
+        match serde_json::from_str::<crate::Message>(json).unwrap() {
+            crate::Message::Standard(m) => match m.blocks.as_ref().map(|b| &b[..]) {
+                Some([Block::Section(section), Block::Divider(_)]) => {
+                    assert_eq!(Some("*Hi*"), section.text.as_ref().map(|t| t.text()));
+                }
+                other => panic!("{:?}", other),
+            },
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_malformed_blocks_of_received_messages_are_kept() {
+        let image = serde_json::json!({"type": "image", "slack_file": {"id": "F1"}, "alt_text": "Chart"});
+        let json = serde_json::json!({"type": "message", "ts": "1.000001", "text": "Hi", "blocks": [
+            {"type": "divider"},
+            image,
+        ]});
+        let message = serde_json::from_value::<crate::Message>(json.clone()).unwrap();
+        match message {
+            crate::Message::Standard(ref m) => match m.blocks.as_ref().map(|b| &b[..]) {
+                Some([Block::Divider(_), Block::Unknown(json)]) => assert_eq!(&image, json),
+                other => panic!("{:?}", other),
+            },
+            ref other => panic!("{:?}", other),
+        }
+        assert_eq!(json, serde_json::to_value(&message).unwrap());
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(feature = "reqwest")]
+use std::time::Duration;
+
+#[cfg(feature = "reqwest")]
+use crate::requests::{Client, WithBaseUrl, DEFAULT_BASE_URL};
+#[cfg(feature = "reqwest")]
+use crate::retry::RetryingSender;
+
+/// A client for the Web API that holds a sender along with the token to authenticate with.
+///
+/// The methods of each module are available through the accessor of the same name, which call
+/// the functions of that module with this client's sender and token:
+///
+/// ```no_run
+/// let slack = slack_api::Slack::builder("some_token").max_retries(3).build().unwrap();
+///
+/// let request = slack_api::chat::PostMessageRequest::builder()
+///     .channel("C1234567890".parse().unwrap())
+///     .text("Hello");
+/// let response = slack.chat().post_message(&request.build().unwrap());
+/// ```
+///
+/// Any [`SlackWebRequestSender`](requests/trait.SlackWebRequestSender.html) can be used with
+/// [`Slack::new`].
+#[derive(Clone, Debug)]
+pub struct Slack<S> {
+    sender: S,
+    token: String,
+}
+
+impl<S> Slack<S> {
+    /// Creates a client that sends requests through `sender`, authenticated with `token`.
+    pub fn new<T: Into<String>>(sender: S, token: T) -> Self {
+        Slack {
+            sender,
+            token: token.into(),
+        }
+    }
+
+    /// Returns the sender requests are sent through.
+    pub fn sender(&self) -> &S {
+        &self.sender
+    }
+
+    /// Returns the token requests are authenticated with.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+/// The sender used by clients built with [`Slack::builder`].
+#[cfg(feature = "reqwest")]
+pub type DefaultSender = RetryingSender<WithBaseUrl<Client>>;
+
+#[cfg(feature = "reqwest")]
+impl Slack<DefaultSender> {
+    /// Starts building a client that sends requests with `reqwest`, authenticated with `token`.
+    pub fn builder<T: Into<String>>(token: T) -> SlackBuilder {
+        SlackBuilder {
+            token: token.into(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            timeout: None,
+            max_retries: 0,
+            retry_failures: false,
+        }
+    }
+}
+
+/// Configures a [`Slack`] client that sends requests with `reqwest`.
+#[cfg(feature = "reqwest")]
+#[derive(Clone, Debug)]
+pub struct SlackBuilder {
+    token: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    max_retries: u32,
+    retry_failures: bool,
+}
+
+#[cfg(feature = "reqwest")]
+impl SlackBuilder {
+    /// Sets the URL the API methods are found under, which defaults to
+    /// [`DEFAULT_BASE_URL`](requests/constant.DEFAULT_BASE_URL.html).
+    pub fn base_url<U: Into<String>>(mut self, base_url: U) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets how long a single request may take. By default requests do not time out.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how many times a rate limited or failed request is retried, which defaults to none.
+    ///
+    /// See [`RetryingSender`](retry/struct.RetryingSender.html) for when requests are retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets whether requests that failed with a server error or could not be sent are retried
+    /// too, rather than only rate limited ones, which defaults to no.
+    ///
+    /// See [`RetryingSender::retry_failures`](retry/struct.RetryingSender.html#method.retry_failures)
+    /// for why this is not safe for every method.
+    pub fn retry_failures(mut self, retry_failures: bool) -> Self {
+        self.retry_failures = retry_failures;
+        self
+    }
+
+    /// Builds the client.
+    pub fn build(self) -> Result<Slack<DefaultSender>, reqwest::Error> {
+        let mut client = Client::builder();
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+        let sender = RetryingSender::new(WithBaseUrl::new(client.build()?, self.base_url))
+            .max_retries(self.max_retries)
+            .retry_failures(self.retry_failures);
+        Ok(Slack::new(sender, self.token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Slack;
+    use crate::mock::MockSender;
+
+    #[test]
+    fn test_methods_use_client_token_and_sender() {
+        let slack = Slack::new(MockSender::new(), "xoxb-token");
+        slack
+            .sender()
+            .expect("users.info")
+            .param("user", "U1234567890")
+            .respond(r#"{"ok": true, "user": {"id": "U1234567890", "name": "bobby"}}"#);
+
+        let request = crate::users::InfoRequest { user: &"U1234567890".parse().unwrap() };
+        let response = slack.users().info(&request).unwrap();
+        assert_eq!(Some("bobby".to_owned()), response.user.unwrap().name);
+        slack.sender().assert_all_called();
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_builder_configures_sender() {
+        use crate::requests::SlackWebRequestSender;
+
+        let slack = Slack::builder("xoxb-token").base_url("http://localhost:8080/api").build().unwrap();
+        assert_eq!("xoxb-token", slack.token());
+        assert_eq!("http://localhost:8080/api", slack.sender().base_url());
+    }
+}
@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt;
+
+/// Error codes that mean the token used for a request can not be used for it.
+const AUTH_FAILURES: &[&str] = &[
+    "not_authed",
+    "invalid_auth",
+    "account_inactive",
+    "token_revoked",
+    "token_expired",
+    "no_permission",
+    "missing_scope",
+    "not_allowed_token_type",
+    "not_authorized",
+];
+
+/// Error codes that mean the same request may succeed when it is made again later.
+const RETRYABLE: &[&str] = &[
+    "ratelimited",
+    "rate_limited",
+    "request_timeout",
+    "service_unavailable",
+    "fatal_error",
+    "internal_error",
+    "migration_in_progress",
+    "team_added_to_org",
+];
+
+/// An error from any Web API method.
+///
+/// The error of every method converts into this, so that errors shared by many methods, such as a
+/// revoked token or a rate limit, can be handled in one place:
+///
+/// ```
+/// use slack_api::SlackError;
+///
+/// fn post<R: slack_api::requests::SlackWebRequestSender>(
+///     client: &R,
+///     token: &str,
+/// ) -> Result<(), SlackError<R::Error>> {
+///     let request = slack_api::chat::PostMessageRequest::builder()
+///         .channel("C1234567890".parse().unwrap())
+///         .text("Hello");
+///     slack_api::chat::post_message(client, token, &request.build().unwrap())?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub enum SlackError<E: Error> {
+    /// Slack responded with this error code, such as `channel_not_found`
+    Api(String),
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> SlackError<E> {
+    /// Returns the error code Slack responded with, if any.
+    pub fn code(&self) -> Option<&str> {
+        match *self {
+            SlackError::Api(ref code) => Some(code),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the token is missing, invalid, revoked or lacks the permissions required
+    /// for the request.
+    pub fn is_auth_failure(&self) -> bool {
+        self.code().is_some_and(|code| AUTH_FAILURES.contains(&code))
+    }
+
+    /// Returns whether making the same request again later may succeed, such as when it was rate
+    /// limited or could not be sent at all.
+    pub fn is_retryable(&self) -> bool {
+        match *self {
+            SlackError::Api(ref code) => RETRYABLE.contains(&&code[..]),
+            SlackError::MalformedResponse(_) => false,
+            SlackError::Client(_) => true,
+        }
+    }
+
+    /// Returns whether something the request refers to, such as a channel, user, file or message,
+    /// does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.code().is_some_and(|code| code == "not_found" || code.ends_with("_not_found"))
+    }
+}
+
+impl<E: Error> fmt::Display for SlackError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SlackError::Api(ref code) => write!(f, "{}", code),
+            SlackError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SlackError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SlackError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SlackError::MalformedResponse(ref e) => Some(e),
+            SlackError::Client(ref inner) => Some(inner),
+            SlackError::Api(_) => None,
+        }
+    }
+}
+
+/// The error returned when building a request without setting one of its required fields.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingField(pub &'static str);
+
+impl fmt::Display for MissingField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "missing required field `{}`", self.0)
+    }
+}
+
+impl Error for MissingField {}
+
+/// The error returned when a string is not a valid ID of the expected kind of object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidId {
+    /// The kind of object the ID was expected to belong to, such as `channel`
+    pub kind: &'static str,
+    /// The string that is not a valid ID
+    pub id: String,
+}
+
+impl fmt::Display for InvalidId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a valid {} ID", self.id, self.kind)
+    }
+}
+
+impl Error for InvalidId {}
+
+/// The error returned when a string is not a valid timestamp.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidTs(pub String);
+
+impl fmt::Display for InvalidTs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "`{}` is not a valid timestamp", self.0)
+    }
+}
+
+impl Error for InvalidTs {}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::SlackError;
+    use crate::chat::PostMessageError;
+
+    #[test]
+    fn test_method_errors_convert_with_their_code() {
+        let error: SlackError<fmt::Error> = PostMessageError::ChannelNotFound.into();
+        assert_eq!(Some("channel_not_found"), error.code());
+        assert!(error.is_not_found());
+        assert!(!error.is_auth_failure());
+
+        let error: SlackError<fmt::Error> = PostMessageError::Unknown("token_revoked".to_owned()).into();
+        assert!(error.is_auth_failure());
+
+        let error: SlackError<fmt::Error> = PostMessageError::RateLimited.into();
+        assert!(error.is_retryable());
+
+        let error: SlackError<fmt::Error> = PostMessageError::Client(fmt::Error).into();
+        assert!(error.is_retryable());
+        assert_eq!(None, error.code());
+    }
+}
@@ -0,0 +1,1278 @@
+//! Events that Slack pushes to clients, such as over the Real Time Messaging API.
+//!
+//! [`Event`] has a variant for most event types of the RTM API, such as `reaction_added` and
+//! `channel_created`, and for the Events API events that apps commonly handle, such as
+//! `app_mention` and `link_shared`. Its variants list exactly which types are covered. Events of
+//! any other type, such as `sh_room_join` or `external_org_migration_started`, are kept as JSON in
+//! [`Event::Unknown`].
+//!
+//! The payload of most variants is a struct of this module named after the variant, which reuses
+//! the types of the Web API where an event carries a whole object, such as the
+//! [`Channel`](../struct.Channel.html) of `channel_created`.
+//!
+//! ```
+//! use slack_api::Event;
+//!
+//! let json = r#"{"type": "user_typing", "channel": "C1234567890", "user": "U1234567890"}"#;
+//! match serde_json::from_str(json).unwrap() {
+//!     Event::UserTyping(typing) => assert_eq!("U1234567890", typing.user),
+//!     other => panic!("{:?}", other),
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    Bot, Channel, ChannelId, File, FileComment, FileCommentId, FileId, Group, Im, Item, ItemRef,
+    Message, TeamId, Ts, User, UserId, Usergroup, UsergroupId,
+};
+
+/// An event, tagged by its `type`.
+///
+/// Events of a type that is not modelled are kept as JSON in [`Event::Unknown`], so that a new
+/// event type does not break a running client. An event of a modelled type that does not parse
+/// fails instead.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    /// The list of accounts a user is signed into changed
+    AccountsChanged,
+    /// The app was mentioned in a message
+    AppMention(AppMention),
+    /// An integration bot was added
+    BotAdded(BotAdded),
+    /// An integration bot was changed
+    BotChanged(BotChanged),
+    /// A channel was archived
+    ChannelArchive(ChannelArchive),
+    /// A channel was created
+    ChannelCreated(ChannelCreated),
+    /// A channel was deleted
+    ChannelDeleted(ChannelDeleted),
+    /// Bulk updates were made to the history of a channel
+    ChannelHistoryChanged(ChannelHistoryChanged),
+    /// A channel was given a new ID, such as when it was shared with another team
+    ChannelIdChanged(ChannelIdChanged),
+    /// The user joined a channel
+    ChannelJoined(ChannelJoined),
+    /// The user left a channel
+    ChannelLeft(ChannelLeft),
+    /// The read cursor of a channel was moved
+    ChannelMarked(ChannelMarked),
+    /// A channel was renamed
+    ChannelRename(ChannelRename),
+    /// A channel was shared with another team
+    ChannelShared(ChannelShared),
+    /// A channel was unarchived
+    ChannelUnarchive(ChannelUnarchive),
+    /// A channel stopped being shared with another team
+    ChannelUnshared(ChannelUnshared),
+    /// A slash command was added or changed
+    CommandsChanged,
+    /// The Do Not Disturb settings of the user changed
+    DndUpdated(DndUpdated),
+    /// The Do Not Disturb settings of a member of the team changed
+    DndUpdatedUser(DndUpdatedUser),
+    /// The email domain of the team changed
+    EmailDomainChanged(EmailDomainChanged),
+    /// A custom emoji was added, removed or aliased
+    EmojiChanged(EmojiChanged),
+    /// A file was changed
+    FileChange(FileChange),
+    /// A comment was added to a file
+    FileCommentAdded(FileCommentAdded),
+    /// A comment was removed from a file
+    FileCommentDeleted(FileCommentDeleted),
+    /// A comment on a file was edited
+    FileCommentEdited(FileCommentEdited),
+    /// A file was created
+    FileCreated(FileCreated),
+    /// A file was deleted
+    FileDeleted(FileDeleted),
+    /// A file was made public
+    FilePublic(FilePublic),
+    /// A file was shared
+    FileShared(FileShared),
+    /// A file was unshared
+    FileUnshared(FileUnshared),
+    /// The server is about to close the connection, so the client should reconnect
+    Goodbye,
+    /// A private channel was archived
+    GroupArchive(GroupArchive),
+    /// The user closed a private channel
+    GroupClose(GroupClose),
+    /// A private channel was deleted
+    GroupDeleted(GroupDeleted),
+    /// Bulk updates were made to the history of a private channel
+    GroupHistoryChanged(GroupHistoryChanged),
+    /// The user joined a private channel
+    GroupJoined(GroupJoined),
+    /// The user left a private channel
+    GroupLeft(GroupLeft),
+    /// The read cursor of a private channel was moved
+    GroupMarked(GroupMarked),
+    /// The user opened a private channel
+    GroupOpen(GroupOpen),
+    /// A private channel was renamed
+    GroupRename(GroupRename),
+    /// A private channel was unarchived
+    GroupUnarchive(GroupUnarchive),
+    /// The connection was opened, and events will follow
+    Hello,
+    /// The user closed a direct message channel
+    ImClose(ImClose),
+    /// A direct message channel was created
+    ImCreated(ImCreated),
+    /// Bulk updates were made to the history of a direct message channel
+    ImHistoryChanged(ImHistoryChanged),
+    /// The read cursor of a direct message channel was moved
+    ImMarked(ImMarked),
+    /// The user opened a direct message channel
+    ImOpen(ImOpen),
+    /// A message has links to a domain the app unfurls
+    LinkShared(LinkShared),
+    /// The user set their presence
+    ManualPresenceChange(ManualPresenceChange),
+    /// A user joined a channel
+    MemberJoinedChannel(MemberJoinedChannel),
+    /// A user left a channel
+    MemberLeftChannel(MemberLeftChannel),
+    /// An item was pinned to a channel
+    PinAdded(PinAdded),
+    /// An item was unpinned from a channel
+    PinRemoved(PinRemoved),
+    /// A preference of the user changed
+    PrefChange(PrefChange),
+    /// The presence of a user, or of several users at once, changed
+    PresenceChange(PresenceChange),
+    /// A reaction was added to an item
+    ReactionAdded(ReactionAdded),
+    /// A reaction was removed from an item
+    ReactionRemoved(ReactionRemoved),
+    /// A URL to reconnect to in place of the one this connection was opened with
+    ReconnectUrl(ReconnectUrl),
+    /// An item was starred by the user
+    StarAdded(StarAdded),
+    /// An item was unstarred by the user
+    StarRemoved(StarRemoved),
+    /// A user group was created
+    SubteamCreated(SubteamCreated),
+    /// The members of a user group changed
+    SubteamMembersChanged(SubteamMembersChanged),
+    /// The user was added to a user group
+    SubteamSelfAdded(SubteamSelfAdded),
+    /// The user was removed from a user group
+    SubteamSelfRemoved(SubteamSelfRemoved),
+    /// A user group was updated
+    SubteamUpdated(SubteamUpdated),
+    /// The domain of the team changed
+    TeamDomainChange(TeamDomainChange),
+    /// A user joined the team
+    TeamJoin(TeamJoin),
+    /// The team is being migrated to another server, so the client should reconnect
+    TeamMigrationStarted,
+    /// The billing plan of the team changed
+    TeamPlanChange(TeamPlanChange),
+    /// A preference of the team changed
+    TeamPrefChange(TeamPrefChange),
+    /// The profile fields of the team were updated
+    TeamProfileChange(TeamProfileChange),
+    /// Profile fields were deleted from the team
+    TeamProfileDelete(TeamProfileDelete),
+    /// The profile fields of the team were reordered
+    TeamProfileReorder(TeamProfileReorder),
+    /// The team was renamed
+    TeamRename(TeamRename),
+    /// Tokens of the app were revoked
+    TokensRevoked(TokensRevoked),
+    /// A member of the team changed their data
+    UserChange(UserChange),
+    /// A member of the team joined or left a huddle
+    UserHuddleChanged(UserHuddleChanged),
+    /// A member of the team changed their profile
+    UserProfileChanged(UserProfileChanged),
+    /// A member of the team changed their status
+    UserStatusChanged(UserStatusChanged),
+    /// A user is typing in a channel
+    UserTyping(UserTyping),
+    /// A message was sent to a channel
+    ///
+    /// It is serialized as the message itself, which already has its `type`, so it comes after
+    /// the variants that are tagged when serialized.
+    #[serde(untagged)]
+    Message(Message),
+    /// An event of a type that is not modelled, as it was sent
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+deserialize_by_type!(Event {
+    "accounts_changed" => AccountsChanged,
+    "app_mention" => AppMention(_),
+    "bot_added" => BotAdded(_),
+    "bot_changed" => BotChanged(_),
+    "channel_archive" => ChannelArchive(_),
+    "channel_created" => ChannelCreated(_),
+    "channel_deleted" => ChannelDeleted(_),
+    "channel_history_changed" => ChannelHistoryChanged(_),
+    "channel_id_changed" => ChannelIdChanged(_),
+    "channel_joined" => ChannelJoined(_),
+    "channel_left" => ChannelLeft(_),
+    "channel_marked" => ChannelMarked(_),
+    "channel_rename" => ChannelRename(_),
+    "channel_shared" => ChannelShared(_),
+    "channel_unarchive" => ChannelUnarchive(_),
+    "channel_unshared" => ChannelUnshared(_),
+    "commands_changed" => CommandsChanged,
+    "dnd_updated" => DndUpdated(_),
+    "dnd_updated_user" => DndUpdatedUser(_),
+    "email_domain_changed" => EmailDomainChanged(_),
+    "emoji_changed" => EmojiChanged(_),
+    "file_change" => FileChange(_),
+    "file_comment_added" => FileCommentAdded(_),
+    "file_comment_deleted" => FileCommentDeleted(_),
+    "file_comment_edited" => FileCommentEdited(_),
+    "file_created" => FileCreated(_),
+    "file_deleted" => FileDeleted(_),
+    "file_public" => FilePublic(_),
+    "file_shared" => FileShared(_),
+    "file_unshared" => FileUnshared(_),
+    "goodbye" => Goodbye,
+    "group_archive" => GroupArchive(_),
+    "group_close" => GroupClose(_),
+    "group_deleted" => GroupDeleted(_),
+    "group_history_changed" => GroupHistoryChanged(_),
+    "group_joined" => GroupJoined(_),
+    "group_left" => GroupLeft(_),
+    "group_marked" => GroupMarked(_),
+    "group_open" => GroupOpen(_),
+    "group_rename" => GroupRename(_),
+    "group_unarchive" => GroupUnarchive(_),
+    "hello" => Hello,
+    "im_close" => ImClose(_),
+    "im_created" => ImCreated(_),
+    "im_history_changed" => ImHistoryChanged(_),
+    "im_marked" => ImMarked(_),
+    "im_open" => ImOpen(_),
+    "link_shared" => LinkShared(_),
+    "manual_presence_change" => ManualPresenceChange(_),
+    "member_joined_channel" => MemberJoinedChannel(_),
+    "member_left_channel" => MemberLeftChannel(_),
+    "message" => Message(_),
+    "pin_added" => PinAdded(_),
+    "pin_removed" => PinRemoved(_),
+    "pref_change" => PrefChange(_),
+    "presence_change" => PresenceChange(_),
+    "reaction_added" => ReactionAdded(_),
+    "reaction_removed" => ReactionRemoved(_),
+    "reconnect_url" => ReconnectUrl(_),
+    "star_added" => StarAdded(_),
+    "star_removed" => StarRemoved(_),
+    "subteam_created" => SubteamCreated(_),
+    "subteam_members_changed" => SubteamMembersChanged(_),
+    "subteam_self_added" => SubteamSelfAdded(_),
+    "subteam_self_removed" => SubteamSelfRemoved(_),
+    "subteam_updated" => SubteamUpdated(_),
+    "team_domain_change" => TeamDomainChange(_),
+    "team_join" => TeamJoin(_),
+    "team_migration_started" => TeamMigrationStarted,
+    "team_plan_change" => TeamPlanChange(_),
+    "team_pref_change" => TeamPrefChange(_),
+    "team_profile_change" => TeamProfileChange(_),
+    "team_profile_delete" => TeamProfileDelete(_),
+    "team_profile_reorder" => TeamProfileReorder(_),
+    "team_rename" => TeamRename(_),
+    "tokens_revoked" => TokensRevoked(_),
+    "user_change" => UserChange(_),
+    "user_huddle_changed" => UserHuddleChanged(_),
+    "user_profile_changed" => UserProfileChanged(_),
+    "user_status_changed" => UserStatusChanged(_),
+    "user_typing" => UserTyping(_),
+});
+
+/// The payload of [`Event::AppMention`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AppMention {
+    pub channel: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub ts: Ts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::BotAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BotAdded {
+    pub bot: Bot,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::BotChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BotChanged {
+    pub bot: Bot,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelArchive`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelArchive {
+    pub channel: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelCreated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelCreated {
+    pub channel: Channel,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelDeleted`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelDeleted {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelHistoryChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelHistoryChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelIdChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelIdChanged {
+    pub old_channel_id: ChannelId,
+    pub new_channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelJoined`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelJoined {
+    pub channel: Channel,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelLeft`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelLeft {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelMarked`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelMarked {
+    pub channel: ChannelId,
+    pub ts: Ts,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelRename`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelRename {
+    pub channel: Channel,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelShared`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelShared {
+    pub channel: ChannelId,
+    pub connected_team_id: TeamId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelUnarchive`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelUnarchive {
+    pub channel: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ChannelUnshared`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ChannelUnshared {
+    pub channel: ChannelId,
+    pub previously_connected_team_id: TeamId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_ext_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::DndUpdated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DndUpdated {
+    pub dnd_status: DndStatus,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::DndUpdatedUser`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DndUpdatedUser {
+    pub dnd_status: DndStatus,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::EmailDomainChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmailDomainChanged {
+    pub email_domain: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::EmojiChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmojiChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileChange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileCommentAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileCommentAdded {
+    pub comment: FileComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<FileId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileCommentDeleted`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileCommentDeleted {
+    pub comment: FileCommentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<FileId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileCommentEdited`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileCommentEdited {
+    pub comment: FileComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<FileId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileCreated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileCreated {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileDeleted`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileDeleted {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FilePublic`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FilePublic {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileShared`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileShared {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::FileUnshared`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileUnshared {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+    pub file_id: FileId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupArchive`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupArchive {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupClose`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupClose {
+    pub channel: ChannelId,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupDeleted`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupDeleted {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupHistoryChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupHistoryChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupJoined`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupJoined {
+    pub channel: Group,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupLeft`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupLeft {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupMarked`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupMarked {
+    pub channel: ChannelId,
+    pub ts: Ts,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupOpen`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupOpen {
+    pub channel: ChannelId,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupRename`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupRename {
+    pub channel: Group,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::GroupUnarchive`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GroupUnarchive {
+    pub channel: ChannelId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ImClose`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImClose {
+    pub channel: ChannelId,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ImCreated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImCreated {
+    pub channel: Im,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ImHistoryChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImHistoryChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ImMarked`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImMarked {
+    pub channel: ChannelId,
+    pub ts: Ts,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ImOpen`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImOpen {
+    pub channel: ChannelId,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::LinkShared`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LinkShared {
+    pub channel: ChannelId,
+    pub user: UserId,
+    pub message_ts: Ts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Ts>,
+    pub links: Vec<SharedLink>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ManualPresenceChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ManualPresenceChange {
+    pub presence: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::MemberJoinedChannel`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemberJoinedChannel {
+    pub channel: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamId>,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::MemberLeftChannel`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemberLeftChannel {
+    pub channel: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamId>,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::PinAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PinAdded {
+    pub channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::PinRemoved`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PinRemoved {
+    pub channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_pins: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::PrefChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PrefChange {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::PresenceChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PresenceChange {
+    pub presence: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<UserId>>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ReactionAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReactionAdded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    pub item: ItemRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_user: Option<UserId>,
+    pub reaction: String,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ReactionRemoved`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReactionRemoved {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    pub item: ItemRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_user: Option<UserId>,
+    pub reaction: String,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::ReconnectUrl`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReconnectUrl {
+    pub url: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::StarAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StarAdded {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    pub item: Item,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::StarRemoved`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StarRemoved {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    pub item: Item,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::SubteamCreated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubteamCreated {
+    pub subteam: Usergroup,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::SubteamMembersChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubteamMembersChanged {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_users: Option<Vec<UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_previous_update: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_update: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed_users: Option<Vec<UserId>>,
+    pub subteam_id: UsergroupId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<TeamId>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::SubteamSelfAdded`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubteamSelfAdded {
+    pub subteam_id: UsergroupId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::SubteamSelfRemoved`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubteamSelfRemoved {
+    pub subteam_id: UsergroupId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::SubteamUpdated`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SubteamUpdated {
+    pub subteam: Usergroup,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamDomainChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamDomainChange {
+    pub domain: String,
+    pub url: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamJoin`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamJoin {
+    pub user: User,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamPlanChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamPlanChange {
+    pub plan: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamPrefChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamPrefChange {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamProfileChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamProfileChange {
+    pub profile: serde_json::Value,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamProfileDelete`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamProfileDelete {
+    pub profile: serde_json::Value,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamProfileReorder`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamProfileReorder {
+    pub profile: serde_json::Value,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TeamRename`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TeamRename {
+    pub name: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::TokensRevoked`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TokensRevoked {
+    pub tokens: RevokedTokens,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::UserChange`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserChange {
+    pub user: User,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::UserHuddleChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserHuddleChanged {
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_ts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::UserProfileChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserProfileChanged {
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_ts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::UserStatusChanged`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserStatusChanged {
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_ts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The payload of [`Event::UserTyping`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UserTyping {
+    pub channel: ChannelId,
+    pub user: UserId,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The Do Not Disturb settings of a user, as given by `dnd_updated` and `dnd_updated_user`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DndStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_dnd_end_ts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_dnd_start_ts: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snooze_endtime: Option<i32>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// A link of a [`LinkShared`] event.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SharedLink {
+    pub domain: String,
+    pub url: String,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The tokens of a [`TokensRevoked`] event, by the users they belonged to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RevokedTokens {
+    /// The users whose user tokens were revoked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<Vec<UserId>>,
+    /// The bot users whose bot tokens were revoked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot: Option<Vec<UserId>>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Event;
+    use crate::ItemRef;
+
+    #[test]
+    fn test_events_parse_by_their_type() {
+        let json = serde_json::json!([
+            {"type": "hello"},
+            {"type": "reaction_added", "user": "U1234567890", "reaction": "thumbsup", "event_ts": "1.000002",
+             "item": {"type": "message", "channel": "C1234567890", "ts": "1.000001"}},
+            {"type": "channel_created", "channel": {"id": "C1234567890", "name": "general", "created": 1360782804}},
+            {"type": "presence_change", "users": ["U1234567890", "U0987654321"], "presence": "away"},
+        ]);
+        let events: Vec<Event> = serde_json::from_value(json.clone()).unwrap();
+        match &events[..] {
+            [
+                Event::Hello,
+                Event::ReactionAdded(reaction),
+                Event::ChannelCreated(created),
+                Event::PresenceChange(presence),
+            ] => {
+                match reaction.item {
+                    ItemRef::Message { ref channel, .. } => assert_eq!("C1234567890", channel.as_str()),
+                    ref other => panic!("{:?}", other),
+                }
+                assert_eq!(Some("general"), created.channel.name.as_deref());
+                assert_eq!(2, presence.users.as_ref().unwrap().len());
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(json, serde_json::to_value(&events).unwrap());
+    }
+
+    #[test]
+    fn test_unknown_events_keep_their_json() {
+        let json = serde_json::json!({"type": "huddle_changed", "huddle": {"id": "H1"}});
+        match serde_json::from_value::<Event>(json.clone()).unwrap() {
+            Event::Unknown(ref value) => assert_eq!(&json, value),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_shared_channel_and_app_events_parse() {
+        let json = serde_json::json!([
+            {"type": "channel_shared", "connected_team_id": "E0000000001", "channel": "C1234567890",
+             "event_ts": "1.000001"},
+            {"type": "channel_id_changed", "old_channel_id": "G1234567890", "new_channel_id": "C1234567890"},
+            {"type": "link_shared", "channel": "C1234567890", "user": "U1234567890", "message_ts": "1.000002",
+             "links": [{"domain": "example.com", "url": "https://example.com/12345"}]},
+            {"type": "tokens_revoked", "tokens": {"oauth": ["U1234567890"], "bot": []}},
+            {"type": "user_status_changed", "user": {"id": "U1234567890", "profile": {"status_text": "Away"}},
+             "cache_ts": 1503435956},
+        ]);
+        let events: Vec<Event> = serde_json::from_value(json.clone()).unwrap();
+        match &events[..] {
+            [
+                Event::ChannelShared(shared),
+                Event::ChannelIdChanged(changed),
+                Event::LinkShared(link),
+                Event::TokensRevoked(revoked),
+                Event::UserStatusChanged(status),
+            ] => {
+                assert_eq!("E0000000001", shared.connected_team_id.as_str());
+                assert_eq!("C1234567890", changed.new_channel_id.as_str());
+                assert_eq!("example.com", link.links[0].domain);
+                assert_eq!(1, revoked.tokens.oauth.as_ref().unwrap().len());
+                assert_eq!(Some(1503435956), status.cache_ts);
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(json, serde_json::to_value(&events).unwrap());
+    }
+
+    #[test]
+    fn test_message_events_round_trip() {
+        let json = serde_json::json!([
+            {"type": "message", "channel": "C1234567890", "user": "U1234567890", "text": "Hi", "ts": "1.000001"},
+            {"type": "message", "subtype": "channel_join", "channel": "C1234567890", "user": "U1234567890",
+             "text": "<@U1234567890> has joined the channel", "ts": "1.000002"},
+        ]);
+        let events: Vec<Event> = serde_json::from_value(json.clone()).unwrap();
+        match &events[..] {
+            [Event::Message(message), Event::Message(join)] => {
+                assert_eq!(Some("Hi"), message.text());
+                assert_eq!(Some("channel_join"), join.subtype());
+            }
+            other => panic!("{:?}", other),
+        }
+        let serialized = serde_json::to_string(&events).unwrap();
+        assert_eq!(2, serialized.matches(r#""type":"message""#).count(), "{}", serialized);
+        assert_eq!(json, serde_json::from_str::<serde_json::Value>(&serialized).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_events_of_a_known_type_fail() {
+        let json = serde_json::json!({"type": "user_typing", "channel": "C1234567890", "user": 42});
+        assert!(serde_json::from_value::<Event>(json).is_err());
+    }
+}
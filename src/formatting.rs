@@ -0,0 +1,309 @@
+//! Slack's message formatting: escaping, mentions, links and emoji in message `text`.
+//!
+//! [`Token`]s display as the markup Slack expects, with any text they hold escaped, and [`parse`]
+//! turns the `text` of a received message back into tokens.
+//!
+//! ```
+//! use slack_api::formatting::{self, escape, Token};
+//!
+//! let user = "U1234567890".parse().unwrap();
+//! let text = format!(
+//!     "{} {} shipped {} to {}",
+//!     Token::here(),
+//!     Token::user(&user),
+//!     escape("<v2>"),
+//!     Token::link("https://example.com")
+//! );
+//! assert_eq!("<!here> <@U1234567890> shipped &lt;v2&gt; to <https://example.com>", text);
+//!
+//! let tokens = formatting::parse(&text);
+//! assert_eq!(Token::user(&user), tokens[2]);
+//! assert_eq!(Token::text(" shipped <v2> to "), tokens[3]);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{ChannelId, UserId, UsergroupId};
+
+/// A piece of message text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// Plain text, unescaped
+    Text(String),
+    User {
+        id: UserId,
+        label: Option<String>,
+    },
+    Channel {
+        id: ChannelId,
+        label: Option<String>,
+    },
+    Usergroup {
+        id: UsergroupId,
+        label: Option<String>,
+    },
+    /// A special mention such as `here`, `channel` or `everyone`, or a command such as `date^..`
+    Special {
+        name: String,
+        label: Option<String>,
+    },
+    Link {
+        url: String,
+        label: Option<String>,
+    },
+    /// An emoji by its name, such as `tada` or `+1::skin-tone-2`
+    Emoji(String),
+}
+
+impl Token {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Token::Text(text.into())
+    }
+
+    pub fn user(id: &UserId) -> Self {
+        Token::User {
+            id: id.clone(),
+            label: None,
+        }
+    }
+
+    pub fn channel(id: &ChannelId) -> Self {
+        Token::Channel {
+            id: id.clone(),
+            label: None,
+        }
+    }
+
+    pub fn usergroup(id: &UsergroupId) -> Self {
+        Token::Usergroup {
+            id: id.clone(),
+            label: None,
+        }
+    }
+
+    /// Returns `@here`, which notifies the active members of a channel.
+    pub fn here() -> Self {
+        Token::special("here")
+    }
+
+    /// Returns `@channel`, which notifies every member of a channel.
+    pub fn everyone_in_channel() -> Self {
+        Token::special("channel")
+    }
+
+    /// Returns `@everyone`, which notifies every member of the workspace.
+    pub fn everyone() -> Self {
+        Token::special("everyone")
+    }
+
+    fn special(name: &str) -> Self {
+        Token::Special {
+            name: name.to_owned(),
+            label: None,
+        }
+    }
+
+    pub fn link<S: Into<String>>(url: S) -> Self {
+        Token::Link {
+            url: url.into(),
+            label: None,
+        }
+    }
+
+    /// Returns a link shown as `label` instead of its URL.
+    pub fn link_with_label<S: Into<String>, L: Into<String>>(url: S, label: L) -> Self {
+        Token::Link {
+            url: url.into(),
+            label: Some(label.into()),
+        }
+    }
+
+    pub fn emoji<S: Into<String>>(name: S) -> Self {
+        Token::Emoji(name.into())
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (prefix, target, label) = match *self {
+            Token::Text(ref text) => return f.write_str(&escape(text)),
+            Token::Emoji(ref name) => return write!(f, ":{}:", name),
+            Token::User { ref id, ref label } => ("@", id.as_str(), label),
+            Token::Channel { ref id, ref label } => ("#", id.as_str(), label),
+            Token::Usergroup { ref id, ref label } => ("!subteam^", id.as_str(), label),
+            Token::Special { ref name, ref label } => ("!", &name[..], label),
+            Token::Link { ref url, ref label } => ("", &url[..], label),
+        };
+        write!(f, "<{}{}", prefix, escape(target).replace('|', "%7C"))?;
+        if let Some(ref label) = *label {
+            write!(f, "|{}", escape(label))?;
+        }
+        f.write_str(">")
+    }
+}
+
+/// Escapes `&`, `<` and `>`, which Slack reads as the start of an entity or of a mention or link.
+pub fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len() + 8);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Reverses [`escape`].
+pub fn unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(text.replace("&lt;", "<").replace("&gt;", ">").replace("&amp;", "&"))
+}
+
+/// Splits the `text` of a received message into tokens.
+///
+/// Mentions of IDs that are not valid, and markup that is not closed, are kept as text.
+pub fn parse(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => break,
+        };
+        parse_text(&rest[..start], &mut tokens);
+        match parse_markup(&rest[start + 1..end]) {
+            Some(token) => tokens.push(token),
+            None => push_text(&unescape(&rest[start..=end]), &mut tokens),
+        }
+        rest = &rest[end + 1..];
+    }
+    parse_text(rest, &mut tokens);
+    tokens
+}
+
+/// Parses what is between `<` and `>`.
+fn parse_markup(markup: &str) -> Option<Token> {
+    let (target, label) = match markup.find('|') {
+        Some(i) => (&markup[..i], Some(unescape(&markup[i + 1..]).into_owned())),
+        None => (markup, None),
+    };
+    let token = if let Some(id) = target.strip_prefix('@') {
+        Token::User {
+            id: id.parse().ok()?,
+            label,
+        }
+    } else if let Some(id) = target.strip_prefix('#') {
+        Token::Channel {
+            id: id.parse().ok()?,
+            label,
+        }
+    } else if let Some(id) = target.strip_prefix("!subteam^") {
+        Token::Usergroup {
+            id: id.parse().ok()?,
+            label,
+        }
+    } else if let Some(name) = target.strip_prefix('!') {
+        Token::Special {
+            name: unescape(name).into_owned(),
+            label,
+        }
+    } else if !target.is_empty() {
+        Token::Link {
+            url: unescape(target).into_owned(),
+            label,
+        }
+    } else {
+        return None;
+    };
+    Some(token)
+}
+
+/// Splits text outside of markup into emoji and unescaped text.
+fn parse_text(text: &str, tokens: &mut Vec<Token>) {
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        match emoji_len(&rest[start + 1..]) {
+            Some(len) => {
+                push_text(&unescape(&rest[..start]), tokens);
+                tokens.push(Token::Emoji(rest[start + 1..start + 1 + len].to_owned()));
+                rest = &rest[start + len + 2..];
+            }
+            None => {
+                push_text(&unescape(&rest[..=start]), tokens);
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    push_text(&unescape(rest), tokens);
+}
+
+/// Returns the length of the emoji name at the start of `text`, which ends with a `:` that is not
+/// followed by a skin tone.
+fn emoji_len(text: &str) -> Option<usize> {
+    let is_name = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || "_+-'".contains(c);
+    let mut len = text.find(|c: char| !is_name(c))?;
+    if len == 0 || !text[len..].starts_with(':') {
+        return None;
+    }
+    if text[len..].starts_with("::skin-tone-") {
+        len += 2 + emoji_len(&text[len + 2..])?;
+    }
+    Some(len)
+}
+
+fn push_text(text: &str, tokens: &mut Vec<Token>) {
+    if text.is_empty() {
+        return;
+    }
+    match tokens.last_mut() {
+        Some(Token::Text(last)) => last.push_str(text),
+        _ => tokens.push(Token::Text(text.to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Token};
+
+    #[test]
+    fn test_tokens_escape_what_they_hold() {
+        let link = Token::link_with_label("https://example.com/?a=1&b=|", "<docs>");
+        assert_eq!("<https://example.com/?a=1&amp;b=%7C|&lt;docs&gt;>", link.to_string());
+        let group = Token::usergroup(&"S1234567890".parse().unwrap());
+        assert_eq!("<!subteam^S1234567890>", group.to_string());
+        assert_eq!("a &amp; b", Token::text("a & b").to_string());
+    }
+
+    #[test]
+    fn test_parse_splits_mentions_links_and_emoji() {
+        let text = "<!channel> <#C1234567890|general> &lt;3 :tada: :+1::skin-tone-2: at 10:30 \
+                    <https://example.com?a=1&amp;b=2|site> <@nobody> <unclosed";
+        assert_eq!(
+            vec![
+                Token::everyone_in_channel(),
+                Token::text(" "),
+                Token::Channel {
+                    id: "C1234567890".parse().unwrap(),
+                    label: Some("general".into()),
+                },
+                Token::text(" <3 "),
+                Token::emoji("tada"),
+                Token::text(" "),
+                Token::emoji("+1::skin-tone-2"),
+                Token::text(" at 10:30 "),
+                Token::link_with_label("https://example.com?a=1&b=2", "site"),
+                Token::text(" <@nobody> <unclosed"),
+            ],
+            parse(text)
+        );
+    }
+}
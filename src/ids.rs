@@ -0,0 +1,361 @@
+//! Identifiers of Slack objects, typed so that the ID of one kind of object can not be passed
+//! where another is expected.
+//!
+//! Each ID is checked for the prefix Slack gives that kind of object when it is parsed, and is
+//! serialized as the plain string. IDs received from Slack are only checked not to be empty, so
+//! that an ID with a prefix Slack introduces later does not fail the whole response.
+//!
+//! ```
+//! use slack_api::{ChannelId, UserId};
+//!
+//! let channel: ChannelId = "C1234567890".parse().unwrap();
+//! assert_eq!("C1234567890", channel.as_str());
+//! assert!("C1234567890".parse::<UserId>().is_err());
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{InvalidId, Ts};
+
+/// Returns whether `id` is one of `prefixes` followed by at least one letter or digit, and does
+/// not start with any of `excluded`.
+fn is_valid(id: &str, prefixes: &[&str], excluded: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| {
+        id.len() > prefix.len()
+            && id.starts_with(prefix)
+            && id[prefix.len()..].bytes().all(|b| b.is_ascii_alphanumeric())
+    }) && !excluded.iter().any(|prefix| id.starts_with(prefix))
+}
+
+macro_rules! id {
+    ($(#[$attr:meta])* $name:ident, $kind:expr, [$($prefix:expr),+]) => {
+        id!($(#[$attr])* $name, $kind, [$($prefix),+], except []);
+    };
+    ($(#[$attr:meta])* $name:ident, $kind:expr, [$($prefix:expr),+], except [$($excluded:expr),*]) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            const PREFIXES: &'static [&'static str] = &[$($prefix),+];
+
+            /// Prefixes of other kinds of IDs that start with one of `PREFIXES`
+            const EXCLUDED: &'static [&'static str] = &[$($excluded),*];
+
+            /// Returns the ID as a string.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = InvalidId;
+
+            fn try_from(id: String) -> Result<Self, InvalidId> {
+                if is_valid(&id, $name::PREFIXES, $name::EXCLUDED) {
+                    Ok($name(id))
+                } else {
+                    Err(InvalidId { kind: $kind, id })
+                }
+            }
+        }
+
+        impl<'a> TryFrom<&'a str> for $name {
+            type Error = InvalidId;
+
+            fn try_from(id: &'a str) -> Result<Self, InvalidId> {
+                $name::try_from(id.to_owned())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = InvalidId;
+
+            fn from_str(id: &str) -> Result<Self, InvalidId> {
+                $name::try_from(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl<'a> PartialEq<&'a str> for $name {
+            fn eq(&self, other: &&'a str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for str {
+            fn eq(&self, other: &$name) -> bool {
+                self == other.0
+            }
+        }
+
+        impl<'a> PartialEq<$name> for &'a str {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                use serde::de::Error as SerdeError;
+
+                // Any prefix is accepted, in case Slack starts giving out new ones
+                let id = String::deserialize(deserializer)?;
+                if id.is_empty() {
+                    Err(D::Error::custom(InvalidId { kind: $kind, id }))
+                } else {
+                    Ok($name(id))
+                }
+            }
+        }
+    };
+}
+
+id!(
+    /// The ID of a public or private channel, or of a direct message channel.
+    ChannelId,
+    "channel",
+    ["C", "G", "D"]
+);
+
+id!(
+    /// The ID of a user.
+    UserId,
+    "user",
+    ["U", "W"]
+);
+
+id!(
+    /// The ID of a team, or of an enterprise organization.
+    TeamId,
+    "team",
+    ["T", "E"]
+);
+
+id!(
+    /// The ID of a file.
+    FileId,
+    "file",
+    ["F"],
+    except ["Fc"]
+);
+
+id!(
+    /// The ID of a comment on a file.
+    FileCommentId,
+    "file comment",
+    ["Fc"]
+);
+
+id!(
+    /// The ID of a bot.
+    BotId,
+    "bot",
+    ["B"]
+);
+
+id!(
+    /// The ID of a user group.
+    UsergroupId,
+    "user group",
+    ["S"]
+);
+
+/// A channel given to a method that takes either its ID or its name, such as `chat.postMessage`.
+///
+/// Parsing a string gives an ID when it is a valid channel ID, and a name such as `#general`
+/// otherwise.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChannelRef {
+    Id(ChannelId),
+    Name(String),
+}
+
+impl ChannelRef {
+    /// Returns the ID or name as it is sent to Slack.
+    pub fn as_str(&self) -> &str {
+        match *self {
+            ChannelRef::Id(ref id) => id.as_str(),
+            ChannelRef::Name(ref name) => name,
+        }
+    }
+}
+
+impl From<ChannelId> for ChannelRef {
+    fn from(id: ChannelId) -> Self {
+        ChannelRef::Id(id)
+    }
+}
+
+impl FromStr for ChannelRef {
+    type Err = InvalidId;
+
+    fn from_str(channel: &str) -> Result<Self, InvalidId> {
+        if channel.is_empty() {
+            Err(InvalidId { kind: "channel", id: String::new() })
+        } else {
+            Ok(channel.parse().map(ChannelRef::Id).unwrap_or_else(|_| ChannelRef::Name(channel.to_owned())))
+        }
+    }
+}
+
+impl fmt::Display for ChannelRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An item that can be starred, pinned or reacted to, as it is given to the `add`, `remove` and
+/// `get` methods of `stars`, `pins` and `reactions`.
+///
+/// It is serialized the way events such as `reaction_added` refer to their item.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "ItemRefRepr", into = "ItemRefRepr")]
+pub enum ItemRef {
+    /// A message, by its channel and timestamp
+    Message { channel: ChannelId, ts: Ts },
+    File(FileId),
+    FileComment(FileCommentId),
+    /// A channel, which can only be starred
+    Channel(ChannelId),
+}
+
+impl ItemRef {
+    /// Returns the form parameters that identify the item.
+    pub(crate) fn params(&self) -> [Option<(&'static str, &str)>; 2] {
+        match *self {
+            ItemRef::Message { ref channel, ref ts } => {
+                [Some(("channel", channel.as_str())), Some(("timestamp", ts.as_str()))]
+            }
+            ItemRef::File(ref file) => [Some(("file", file.as_str())), None],
+            ItemRef::FileComment(ref comment) => [Some(("file_comment", comment.as_str())), None],
+            ItemRef::Channel(ref channel) => [Some(("channel", channel.as_str())), None],
+        }
+    }
+
+    /// Returns the form parameters that identify the item in `channel`, which a message can leave
+    /// out as it is in its own channel.
+    ///
+    /// Fails with the error Slack gives for the item: `channel_not_found` when it is not in
+    /// `channel` or no channel is given for it, and `no_item_specified` when it is a channel.
+    pub(crate) fn params_in<'a>(
+        &'a self,
+        channel: Option<&'a ChannelId>,
+    ) -> Result<[Option<(&'static str, &'a str)>; 2], &'static str> {
+        match (self, channel) {
+            (ItemRef::Channel(_), _) => Err("no_item_specified"),
+            (ItemRef::Message { channel: own, .. }, Some(channel)) if own != channel => Err("channel_not_found"),
+            (ItemRef::Message { .. }, _) => Ok(self.params()),
+            (_, None) => Err("channel_not_found"),
+            (_, Some(channel)) => Ok([Some(("channel", channel.as_str())), self.params()[0]]),
+        }
+    }
+}
+
+/// The tagged form of an [`ItemRef`] in JSON.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ItemRefRepr {
+    Message { channel: ChannelId, ts: Ts },
+    File { file: FileId },
+    FileComment { file_comment: FileCommentId },
+    Channel { channel: ChannelId },
+}
+
+impl From<ItemRefRepr> for ItemRef {
+    fn from(repr: ItemRefRepr) -> Self {
+        match repr {
+            ItemRefRepr::Message { channel, ts } => ItemRef::Message { channel, ts },
+            ItemRefRepr::File { file } => ItemRef::File(file),
+            ItemRefRepr::FileComment { file_comment } => ItemRef::FileComment(file_comment),
+            ItemRefRepr::Channel { channel } => ItemRef::Channel(channel),
+        }
+    }
+}
+
+impl From<ItemRef> for ItemRefRepr {
+    fn from(item: ItemRef) -> Self {
+        match item {
+            ItemRef::Message { channel, ts } => ItemRefRepr::Message { channel, ts },
+            ItemRef::File(file) => ItemRefRepr::File { file },
+            ItemRef::FileComment(file_comment) => ItemRefRepr::FileComment { file_comment },
+            ItemRef::Channel(channel) => ItemRefRepr::Channel { channel },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelId, ChannelRef, FileCommentId, FileId, UserId};
+
+    #[test]
+    fn test_ids_check_their_prefix() {
+        assert!("D1234567890".parse::<ChannelId>().is_ok());
+        assert!("W1234567890".parse::<UserId>().is_ok());
+        assert!("U".parse::<UserId>().is_err());
+        assert!("U12345 67890".parse::<UserId>().is_err());
+
+        let error = "U1234567890".parse::<ChannelId>().unwrap_err();
+        assert_eq!("`U1234567890` is not a valid channel ID", error.to_string());
+
+        assert!("Fc1234567890".parse::<FileId>().is_err());
+        assert!("Fc1234567890".parse::<FileCommentId>().is_ok());
+    }
+
+    #[test]
+    fn test_ids_serialize_as_strings() {
+        let id: UserId = serde_json::from_str(r#""U1234567890""#).unwrap();
+        assert_eq!("U1234567890", id);
+        assert_eq!(r#""U1234567890""#, serde_json::to_string(&id).unwrap());
+        assert_eq!("Z1", serde_json::from_str::<ChannelId>(r#""Z1""#).unwrap());
+        assert!(serde_json::from_str::<UserId>(r#""""#).is_err());
+    }
+
+    #[test]
+    fn test_channel_refs_parse_ids_or_names() {
+        assert_eq!(ChannelRef::Id("C1234567890".parse().unwrap()), "C1234567890".parse().unwrap());
+        assert_eq!(ChannelRef::Name("#general".into()), "#general".parse().unwrap());
+        assert!("".parse::<ChannelRef>().is_err());
+    }
+
+    #[test]
+    fn test_messages_can_be_posted_to_channel_names() {
+        let client = crate::mock::MockSender::new();
+        client
+            .expect("chat.postMessage")
+            .param("channel", "#general")
+            .respond(r#"{"ok": true, "channel": "C1234567890", "ts": "1355517523.000005"}"#);
+        let request = crate::chat::PostMessageRequest::builder().channel("#general".parse().unwrap()).text("Hello");
+        crate::chat::post_message(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+        client.assert_all_called();
+    }
+}
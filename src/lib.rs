@@ -26,13 +26,85 @@ pub use mods::*;
 mod types;
 pub use types::*;
 
+mod ids;
+pub use ids::*;
+
+mod message;
+
+mod ts;
+pub use ts::Ts;
+
+mod client;
+#[cfg(feature = "reqwest")]
+pub use client::{DefaultSender, SlackBuilder};
+pub use client::Slack;
+
+mod error;
+pub use error::{InvalidId, InvalidTs, MissingField, SlackError};
+
+pub use event::Event;
+pub use workspace::Workspace;
+
+/// Implements `Deserialize` for an enum tagged by its `type`, from the type of each variant.
+///
+/// A value whose `type` is not listed is kept as JSON in `Unknown`, but a value of a listed type
+/// that does not parse as its variant fails. Variants listed as `Variant(_)` parse the whole value,
+/// and unit variants ignore it.
+macro_rules! deserialize_by_type {
+    ($enum:ident { $($ty:literal => $variant:ident $(($inner:tt))?,)+ }) => {
+        impl<'de> ::serde::Deserialize<'de> for $enum {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let value = <serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+                let ty = match value.get("type").and_then(serde_json::Value::as_str) {
+                    Some(ty) => ty.to_owned(),
+                    None => return Ok($enum::Unknown(value)),
+                };
+                match ty.as_str() {
+                    $($ty => deserialize_by_type!(@variant $enum::$variant $(($inner))?, value),)+
+                    _ => Ok($enum::Unknown(value)),
+                }
+            }
+        }
+    };
+    (@variant $enum:ident::$variant:ident, $value:ident) => {
+        Ok($enum::$variant)
+    };
+    (@variant $enum:ident::$variant:ident(_), $value:ident) => {
+        serde_json::from_value($value).map($enum::$variant).map_err(::serde::de::Error::custom)
+    };
+}
+
+pub mod attachments;
+pub mod blocks;
+pub mod event;
+pub mod formatting;
+pub mod mock;
+pub mod pagination;
 pub mod requests;
+pub mod retry;
+pub mod workspace;
+
+#[cfg(feature = "async")]
+pub mod async_impl;
+
+#[cfg(feature = "rtm-client")]
+pub mod rtm_client;
 
 #[cfg(feature = "reqwest")]
 pub use requests::default_client;
+#[cfg(all(feature = "reqwest", feature = "async"))]
+pub use requests::default_async_client;
+
+fn get_slack_url_for_method(base_url: &str, method: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), method)
+}
 
-fn get_slack_url_for_method(method: &str) -> String {
-    format!("https://slack.com/api/{}", method)
+/// Embeds a typed parameter (such as `attachments` or `blocks`) into a JSON request body.
+fn json_value<T: serde::Serialize + ?Sized>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).expect("request parameters serialize to JSON")
 }
 
 fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
@@ -80,7 +152,6 @@ fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T
 
 #[cfg(test)]
 mod tests {
-    use serde_json;
     use super::UserProfile;
 
     #[test]
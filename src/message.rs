@@ -0,0 +1,142 @@
+use crate::{BotId, ChannelId, Message, Ts, UserId};
+
+/// Matches the special cases given first, then borrows `$field` of each variant listed after them.
+/// Variants that are in neither list have no such field.
+macro_rules! field {
+    ($message:expr, $field:ident, except [$($special:pat => $value:expr),*], [$($variant:ident),+]) => {
+        match *$message {
+            $($special => $value,)*
+            $(Message::$variant(ref m) => m.$field.as_ref(),)+
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    };
+}
+
+/// Accessors for the fields most messages have, whatever their subtype.
+///
+/// For `message_changed`, `message_replied` and `message_deleted` messages, the author, text,
+/// thread and whether it was sent by a bot are those of the message that was changed, replied to
+/// or deleted. `ts` is always the timestamp of the message itself.
+///
+/// ```
+/// let message: slack_api::Message = serde_json::from_str(r#"{
+///     "type": "message",
+///     "subtype": "message_changed",
+///     "channel": "C1234567890",
+///     "ts": "1503435957.000300",
+///     "message": {"type": "message", "user": "U1234567890", "text": "edited", "ts": "1503435956.000247"}
+/// }"#).unwrap();
+/// assert_eq!("1503435957.000300", message.ts().unwrap().as_str());
+/// assert_eq!(Some("edited"), message.text());
+/// assert_eq!("U1234567890", *message.user().unwrap());
+/// assert_eq!(Some("message_changed"), message.subtype());
+/// ```
+impl Message {
+    /// Returns the timestamp of the message, which identifies it within its channel.
+    pub fn ts(&self) -> Option<&Ts> {
+        field!(self, ts, except [], [
+            Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin,
+            ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic,
+            ChannelUnarchive, EkmAccessDenied, FileComment, FileMention, FileShare, GroupArchive, GroupJoin,
+            GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage, MessageChanged,
+            MessageDeleted, MessageReplied, PinnedItem, ReminderAdd, ReplyBroadcast, SlackbotResponse,
+            ThreadBroadcast, Tombstone, UnpinnedItem, Unknown
+        ])
+    }
+
+    /// Returns the user who sent the message, if it was sent by a user.
+    pub fn user(&self) -> Option<&UserId> {
+        field!(self, user, except [
+            Message::FileComment(ref m) => m.comment.as_ref().and_then(|c| c.user.as_ref()),
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.user.as_ref()),
+            Message::MessageDeleted(ref m) => m.previous_message.as_ref().and_then(|m| m.user.as_ref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.user.as_ref())
+        ], [
+            Standard, BotAdd, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin, ChannelLeave,
+            ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic, ChannelUnarchive,
+            EkmAccessDenied, FileMention, FileShare, GroupArchive, GroupJoin, GroupLeave, GroupName,
+            GroupPurpose, GroupTopic, GroupUnarchive, MeMessage, PinnedItem, ReminderAdd, ReplyBroadcast,
+            SlackbotResponse, ThreadBroadcast, Tombstone, UnpinnedItem, Unknown
+        ])
+    }
+
+    /// Returns the channel the message was sent in, which Slack leaves out of most history responses.
+    pub fn channel(&self) -> Option<&ChannelId> {
+        field!(self, channel, except [], [
+            Standard, MeMessage, MessageChanged, MessageDeleted, MessageReplied, PinnedItem, ReplyBroadcast,
+            SlackbotResponse, UnpinnedItem, Unknown
+        ])
+    }
+
+    /// Returns the text of the message.
+    pub fn text(&self) -> Option<&str> {
+        let text: Option<&String> = field!(self, text, except [
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.text.as_ref()),
+            Message::MessageDeleted(ref m) => m.previous_message.as_ref().and_then(|m| m.text.as_ref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.text.as_ref())
+        ], [
+            Standard, BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin,
+            ChannelLeave, ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic,
+            ChannelUnarchive, EkmAccessDenied, FileComment, FileMention, FileShare, GroupArchive, GroupJoin,
+            GroupLeave, GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage, PinnedItem,
+            ReminderAdd, SlackbotResponse, ThreadBroadcast, Tombstone, UnpinnedItem, Unknown
+        ]);
+        text.map(String::as_str)
+    }
+
+    /// Returns the timestamp of the parent of the thread the message is in, if it is in one.
+    pub fn thread_ts(&self) -> Option<&Ts> {
+        field!(self, thread_ts, except [
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.thread_ts.as_ref()),
+            Message::MessageDeleted(ref m) => m.previous_message.as_ref().and_then(|m| m.thread_ts.as_ref()),
+            Message::MessageReplied(ref m) => {
+                m.message.as_ref().and_then(|m| m.thread_ts.as_ref()).or(m.thread_ts.as_ref())
+            }
+        ], [Standard, ThreadBroadcast, Tombstone, Unknown])
+    }
+
+    /// Returns the subtype of the message, or `None` for a plain message.
+    pub fn subtype(&self) -> Option<&str> {
+        let subtype: Option<&String> = field!(self, subtype, except [], [
+            BotAdd, BotMessage, BotRemove, ChannelArchive, ChannelConvertToPrivate, ChannelJoin, ChannelLeave,
+            ChannelName, ChannelPostingPermissions, ChannelPurpose, ChannelTopic, ChannelUnarchive,
+            EkmAccessDenied, FileComment, FileMention, FileShare, GroupArchive, GroupJoin, GroupLeave,
+            GroupName, GroupPurpose, GroupTopic, GroupUnarchive, MeMessage, MessageChanged, MessageDeleted,
+            MessageReplied, PinnedItem, ReminderAdd, ReplyBroadcast, SlackbotResponse, ThreadBroadcast,
+            Tombstone, UnpinnedItem, Unknown
+        ]);
+        subtype.map(String::as_str)
+    }
+
+    /// Returns whether the message was sent by a bot or an integration rather than a user.
+    pub fn is_bot(&self) -> bool {
+        let bot_id: Option<&BotId> = field!(self, bot_id, except [
+            Message::BotMessage(_) => return true,
+            Message::MessageChanged(ref m) => m.message.as_ref().and_then(|m| m.bot_id.as_ref()),
+            Message::MessageDeleted(ref m) => m.previous_message.as_ref().and_then(|m| m.bot_id.as_ref()),
+            Message::MessageReplied(ref m) => m.message.as_ref().and_then(|m| m.bot_id.as_ref())
+        ], [Standard, Unknown]);
+        bot_id.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Message;
+
+    #[test]
+    fn test_accessors_work_across_subtypes() {
+        let messages: Vec<Message> = serde_json::from_value(serde_json::json!([
+            {"type": "message", "user": "U1", "text": "hi", "ts": "1.000001", "thread_ts": "1.000000"},
+            {"type": "message", "subtype": "bot_message", "bot_id": "B1", "text": "beep", "ts": "2.000002"},
+            {"type": "message", "subtype": "file_comment", "ts": "3.000003", "comment": {"user": "U3"}},
+        ]))
+        .unwrap();
+        assert_eq!(Some("1.000000"), messages[0].thread_ts().map(|ts| ts.as_str()));
+        assert_eq!((None, false), (messages[0].subtype(), messages[0].is_bot()));
+        assert_eq!((None, true), (messages[1].user(), messages[1].is_bot()));
+        assert_eq!(Some("beep"), messages[1].text());
+        assert_eq!("U3", *messages[2].user().unwrap());
+    }
+}
@@ -0,0 +1,440 @@
+//! Senders for testing code that uses the API without talking to Slack.
+//!
+//! [`MockSender`] answers requests with canned responses and keeps track of the calls it was
+//! sent. [`Recorder`] wraps a real sender and records every exchange into a [`Cassette`], which
+//! can be saved to a file and replayed offline with [`MockSender::replay`].
+//!
+//! Requests are matched on the name of the method and on their parameters, as they are sent.
+//! Form parameters are strings, so booleans are matched as `"1"` and `"0"`. Parameters of methods
+//! sent as JSON are matched against the JSON value, with strings taken as is. Tokens are never
+//! matched or recorded.
+
+use std::collections::BTreeMap;
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::sync::Mutex;
+
+#[cfg(feature = "async")]
+use crate::requests::{AsyncSlackWebRequestSender, BoxFuture};
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender, SlackWebResponse};
+
+/// A request that was sent to a [`MockSender`] or [`Recorder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockCall {
+    /// The name of the method that was called, such as `chat.postMessage`.
+    pub method: String,
+    /// The parameters the method was called with.
+    pub params: BTreeMap<String, String>,
+}
+
+impl<'a> From<SlackWebRequest<'a>> for MockCall {
+    fn from(request: SlackWebRequest<'a>) -> Self {
+        let params = match request.body {
+            RequestBody::Form(params) | RequestBody::Multipart(params, _) => params
+                .iter()
+                .map(|&(name, value)| (name.to_owned(), value.to_owned()))
+                .collect(),
+            RequestBody::Json(json) => json
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, value)| {
+                    let value = match *value {
+                        serde_json::Value::String(ref s) => s.clone(),
+                        ref value => value.to_string(),
+                    };
+                    (name.clone(), value)
+                })
+                .collect(),
+        };
+        MockCall {
+            method: request.url.rsplit('/').next().unwrap_or_default().to_owned(),
+            params,
+        }
+    }
+}
+
+/// The error returned by a [`MockSender`] for a request it has no response for.
+#[derive(Debug)]
+pub struct MockError {
+    /// The request that no response was found for.
+    pub call: MockCall,
+}
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected call to {} with {:?}", self.call.method, self.call.params)
+    }
+}
+
+impl error::Error for MockError {}
+
+#[derive(Clone, Debug)]
+struct Expectation {
+    method: String,
+    params: BTreeMap<String, String>,
+    /// Whether the parameters must match exactly, rather than just be included in the request.
+    exact: bool,
+    response: SlackWebResponse,
+}
+
+impl Expectation {
+    fn matches(&self, call: &MockCall) -> bool {
+        self.method == call.method
+            && if self.exact {
+                self.params == call.params
+            } else {
+                self.params.iter().all(|(name, value)| call.params.get(name) == Some(value))
+            }
+    }
+}
+
+/// A sender that answers requests with canned responses.
+///
+/// Every expected call is answered once, by the first expectation that matches it. Calls that
+/// no expectation matches fail with a [`MockError`].
+///
+/// # Examples
+///
+/// ```
+/// use slack_api::mock::MockSender;
+///
+/// let client = MockSender::new();
+/// client
+///     .expect("chat.postMessage")
+///     .param("channel", "C1234567890")
+///     .respond(r#"{"ok": true, "channel": "C1234567890", "ts": "1503435956.000247"}"#);
+///
+/// let request = slack_api::chat::PostMessageRequest::builder()
+///     .channel("C1234567890".parse().unwrap())
+///     .text("Hello");
+/// let response = slack_api::chat::post_message(&client, "some_token", &request.build().unwrap()).unwrap();
+/// assert_eq!("1503435956.000247", response.ts.unwrap().as_str());
+/// client.assert_all_called();
+/// ```
+#[derive(Debug, Default)]
+pub struct MockSender {
+    expectations: Mutex<Vec<Expectation>>,
+    calls: Mutex<Vec<MockCall>>,
+}
+
+impl MockSender {
+    /// Creates a sender without any expectations.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a sender that replays the exchanges recorded in `cassette`.
+    ///
+    /// Each recorded response is returned once, for a call with exactly the recorded parameters.
+    pub fn replay(cassette: Cassette) -> Self {
+        let mock = MockSender::new();
+        {
+            let mut expectations = mock.expectations.lock().unwrap();
+            expectations.extend(cassette.interactions.into_iter().map(|interaction| Expectation {
+                method: interaction.method,
+                params: interaction.params,
+                exact: true,
+                response: SlackWebResponse {
+                    status: interaction.status,
+                    headers: interaction.headers,
+                    body: interaction.body,
+                },
+            }));
+        }
+        mock
+    }
+
+    /// Expects a call to `method`, such as `chat.postMessage`.
+    ///
+    /// The expectation is added once its response is set with
+    /// [`respond`](struct.ExpectationBuilder.html#method.respond).
+    pub fn expect(&self, method: &str) -> ExpectationBuilder<'_> {
+        ExpectationBuilder {
+            mock: self,
+            expectation: Expectation {
+                method: method.to_owned(),
+                params: BTreeMap::new(),
+                exact: false,
+                response: SlackWebResponse {
+                    status: 200,
+                    headers: vec![],
+                    body: String::new(),
+                },
+            },
+        }
+    }
+
+    /// Returns the calls that were sent to this sender so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Panics if any expected call has not been made yet.
+    pub fn assert_all_called(&self) {
+        let expectations = self.expectations.lock().unwrap();
+        if !expectations.is_empty() {
+            let missing = expectations
+                .iter()
+                .map(|e| format!("{} with {:?}", e.method, e.params))
+                .collect::<Vec<_>>();
+            panic!("expected calls were not made: {}", missing.join(", "));
+        }
+    }
+
+    fn respond(&self, request: SlackWebRequest) -> Result<SlackWebResponse, MockError> {
+        let call = MockCall::from(request);
+        self.calls.lock().unwrap().push(call.clone());
+
+        let mut expectations = self.expectations.lock().unwrap();
+        match expectations.iter().position(|e| e.matches(&call)) {
+            Some(i) => Ok(expectations.remove(i).response),
+            None => Err(MockError { call }),
+        }
+    }
+}
+
+impl SlackWebRequestSender for MockSender {
+    type Error = MockError;
+
+    fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error> {
+        self.respond(request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncSlackWebRequestSender for MockSender {
+    type Error = MockError;
+
+    fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>> {
+        let result = self.respond(request);
+        Box::pin(async move { result })
+    }
+}
+
+/// An expected call being set up on a [`MockSender`].
+#[must_use = "the expectation is only added once its response is set"]
+pub struct ExpectationBuilder<'a> {
+    mock: &'a MockSender,
+    expectation: Expectation,
+}
+
+impl<'a> ExpectationBuilder<'a> {
+    /// Only matches calls that have the parameter `name` set to `value`.
+    pub fn param(mut self, name: &str, value: &str) -> Self {
+        self.expectation.params.insert(name.to_owned(), value.to_owned());
+        self
+    }
+
+    /// Sets the HTTP status of the response, which defaults to 200.
+    pub fn status(mut self, status: u16) -> Self {
+        self.expectation.response.status = status;
+        self
+    }
+
+    /// Adds a header to the response.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.expectation.response.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets the body of the response and adds the expectation to the sender.
+    pub fn respond(mut self, body: &str) {
+        self.expectation.response.body = body.to_owned();
+        self.mock.expectations.lock().unwrap().push(self.expectation);
+    }
+}
+
+/// A request and the response it got, as recorded by a [`Recorder`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The name of the method that was called.
+    pub method: String,
+    /// The parameters the method was called with.
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    /// The HTTP status of the response.
+    pub status: u16,
+    /// The headers of the response.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: String,
+}
+
+/// A list of recorded interactions, which can be saved to and loaded from a JSON file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Loads a cassette from the JSON file at `path`.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Cassette> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(io::BufReader::new(file))?)
+    }
+
+    /// Saves this cassette as a JSON file at `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        Ok(serde_json::to_writer_pretty(io::BufWriter::new(file), self)?)
+    }
+}
+
+/// A sender that records the requests sent through another sender, along with their responses.
+///
+/// # Examples
+///
+/// ```no_run
+/// # let token = "some_token";
+/// use slack_api::mock::Recorder;
+///
+/// let client = Recorder::new(slack_api::default_client().unwrap());
+/// slack_api::channels::list(&client, &token, &Default::default()).unwrap();
+/// client.cassette().save("tests/cassettes/channels_list.json").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Recorder<S> {
+    inner: S,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<S> Recorder<S> {
+    /// Wraps `inner`, recording every exchange made through it.
+    pub fn new(inner: S) -> Self {
+        Recorder {
+            inner,
+            interactions: Mutex::new(vec![]),
+        }
+    }
+
+    /// Returns the exchanges recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        Cassette {
+            interactions: self.interactions.lock().unwrap().clone(),
+        }
+    }
+
+    /// Unwraps this sender, returning the wrapped sender.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    fn record(&self, call: MockCall, response: &SlackWebResponse) {
+        self.interactions.lock().unwrap().push(Interaction {
+            method: call.method,
+            params: call.params,
+            status: response.status,
+            headers: response.headers.clone(),
+            body: response.body.clone(),
+        });
+    }
+}
+
+impl<S: SlackWebRequestSender> SlackWebRequestSender for Recorder<S> {
+    type Error = S::Error;
+
+    fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error> {
+        let response = self.inner.send(request)?;
+        self.record(MockCall::from(request), &response);
+        Ok(response)
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: AsyncSlackWebRequestSender + Sync> AsyncSlackWebRequestSender for Recorder<S> {
+    type Error = S::Error;
+
+    fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>> {
+        Box::pin(async move {
+            let response = self.inner.send(request).await?;
+            self.record(MockCall::from(request), &response);
+            Ok(response)
+        })
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cassette, MockSender, Recorder};
+
+    #[test]
+    fn test_mock_matches_method_and_params() {
+        let client = MockSender::new();
+        client
+            .expect("channels.history")
+            .param("channel", "C2")
+            .respond(r#"{"ok": true, "messages": [], "has_more": false}"#);
+        client
+            .expect("channels.history")
+            .param("channel", "C1")
+            .respond(r#"{"ok": false, "error": "channel_not_found"}"#);
+
+        let request = crate::channels::HistoryRequest::builder()
+            .channel("C1".parse().unwrap())
+            .count(10);
+        match crate::channels::history(&client, "xoxb-token", &request.build().unwrap()) {
+            Err(crate::channels::HistoryError::ChannelNotFound) => {}
+            other => panic!("{:?}", other),
+        }
+        assert_eq!("10", client.calls()[0].params["count"]);
+
+        let request = crate::channels::HistoryRequest::builder().channel("C2".parse().unwrap());
+        crate::channels::history(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_mock_fails_unexpected_calls() {
+        let client = MockSender::new();
+        match crate::auth::test(&client, "xoxb-token") {
+            Err(crate::auth::TestError::Client(e)) => assert_eq!("auth.test", e.call.method),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected calls were not made: auth.test")]
+    fn test_mock_asserts_all_called() {
+        let client = MockSender::new();
+        client.expect("auth.test").respond(r#"{"ok": true}"#);
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let live = MockSender::new();
+        live.expect("chat.postMessage")
+            .respond(r#"{"ok": true, "channel": "C1", "ts": "1503435956.000247"}"#);
+        let recorder = Recorder::new(live);
+        let request = crate::chat::PostMessageRequest::builder()
+            .channel("C1".parse().unwrap())
+            .text("Hello")
+            .as_user(true);
+        crate::chat::post_message(&recorder, "xoxb-token", &request.build().unwrap()).unwrap();
+
+        let path = std::env::temp_dir().join(format!("slack_api_cassette_{}.json", std::process::id()));
+        recorder.cassette().save(&path).unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(recorder.cassette(), cassette);
+        assert_eq!("true", cassette.interactions[0].params["as_user"]);
+
+        let replay = MockSender::replay(cassette);
+        let response = crate::chat::post_message(&replay, "xoxb-token", &request.build().unwrap()).unwrap();
+        assert_eq!("1503435956.000247", response.ts.unwrap().as_str());
+        replay.assert_all_called();
+    }
+}
@@ -6,30 +6,31 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Checks API calling code.
 ///
 /// Wraps https://api.slack.com/methods/api.test
-
 pub fn test<R>(client: &R, request: &TestRequest) -> Result<TestResponse, TestError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
         request.error.map(|error| ("error", error)),
         request.foo.map(|foo| ("foo", foo)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("api.test");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "api.test");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: None,
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(TestError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result).map_err(TestError::MalformedResponse)
+            serde_json::from_str::<TestResponse>(&result.body).map_err(TestError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -42,21 +43,62 @@ pub struct TestRequest<'a> {
     pub foo: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> TestRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> TestRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`TestRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct TestRequestBuilder {
+    error: Option<String>,
+    foo: Option<String>,
+}
+
+impl TestRequestBuilder {
+    /// Error response to return
+    pub fn error<S: Into<String>>(mut self, error: S) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    /// example property to return
+    pub fn foo<S: Into<String>>(mut self, foo: S) -> Self {
+        self.foo = Some(foo.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<TestRequest<'_>, crate::MissingField> {
+        Ok(TestRequest {
+            error: self.error.as_deref(),
+            foo: self.foo.as_deref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub args: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
-    fn into(self) -> Result<TestResponse, TestError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TestResponse> for Result<TestResponse, TestError<E>> {
+    fn from(response: TestResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -104,44 +146,24 @@ impl<'a, E: Error> From<&'a str> for TestError<E> {
 
 impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for TestError<E> {
-    fn description(&self) -> &str {
         match *self {
-            TestError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            TestError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            TestError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            TestError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            TestError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            TestError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            TestError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            TestError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            TestError::MalformedResponse(ref e) => e.description(),
-            TestError::Unknown(ref s) => s,
-            TestError::Client(ref inner) => inner.description(),
+            TestError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            TestError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            TestError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            TestError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            TestError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            TestError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            TestError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            TestError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            TestError::MalformedResponse(ref e) => write!(f, "{}", e),
+            TestError::Unknown(ref s) => write!(f, "{}", s),
+            TestError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for TestError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             TestError::MalformedResponse(ref e) => Some(e),
             TestError::Client(ref inner) => Some(inner),
@@ -149,3 +171,47 @@ impl<E: Error> Error for TestError<E> {
         }
     }
 }
+
+impl<E: Error> From<TestError<E>> for crate::SlackError<E> {
+    fn from(err: TestError<E>) -> Self {
+        match err {
+            TestError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            TestError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            TestError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            TestError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            TestError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            TestError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            TestError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            TestError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            TestError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            TestError::Unknown(s) => crate::SlackError::Api(s),
+            TestError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`api`](api/index.html) module.
+    pub fn api(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Checks API calling code.
+    ///
+    /// Wraps https://api.slack.com/methods/api.test
+    pub fn test(&self, request: &TestRequest) -> Result<TestResponse, TestError<S::Error>> {
+        test(self.slack.sender(), request)
+    }
+}
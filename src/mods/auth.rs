@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Revokes a token.
 ///
 /// Wraps https://api.slack.com/methods/auth.revoke
-
 pub fn revoke<R>(
     client: &R,
     token: &str,
@@ -22,20 +20,22 @@ pub fn revoke<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.test.map(
             |test| ("test", if test { "1" } else { "0" })
         ),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("auth.revoke");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.revoke");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RevokeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RevokeResponse>(&result).map_err(RevokeError::MalformedResponse)
+            serde_json::from_str::<RevokeResponse>(&result.body).map_err(RevokeError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -46,21 +46,54 @@ pub struct RevokeRequest {
     pub test: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl RevokeRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RevokeRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RevokeRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RevokeRequestBuilder {
+    test: Option<bool>,
+}
+
+impl RevokeRequestBuilder {
+    /// Setting this parameter to 1 triggers a testing mode where the specified token will not actually be revoked.
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RevokeRequest, crate::MissingField> {
+        Ok(RevokeRequest {
+            test: self.test,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub revoked: Option<bool>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RevokeResponse, RevokeError<E>>> for RevokeResponse {
-    fn into(self) -> Result<RevokeResponse, RevokeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RevokeResponse> for Result<RevokeResponse, RevokeError<E>> {
+    fn from(response: RevokeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -117,49 +150,27 @@ impl<'a, E: Error> From<&'a str> for RevokeError<E> {
 
 impl<E: Error> fmt::Display for RevokeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for RevokeError<E> {
-    fn description(&self) -> &str {
         match *self {
-            RevokeError::NotAuthed => "not_authed: No authentication token provided.",
-            RevokeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RevokeError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RevokeError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RevokeError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RevokeError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RevokeError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RevokeError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RevokeError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RevokeError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RevokeError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RevokeError::MalformedResponse(ref e) => e.description(),
-            RevokeError::Unknown(ref s) => s,
-            RevokeError::Client(ref inner) => inner.description(),
+            RevokeError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RevokeError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RevokeError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RevokeError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RevokeError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RevokeError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RevokeError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RevokeError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RevokeError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RevokeError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RevokeError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RevokeError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RevokeError::Unknown(ref s) => write!(f, "{}", s),
+            RevokeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RevokeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RevokeError::MalformedResponse(ref e) => Some(e),
             RevokeError::Client(ref inner) => Some(inner),
@@ -168,44 +179,76 @@ impl<E: Error> Error for RevokeError<E> {
     }
 }
 
+impl<E: Error> From<RevokeError<E>> for crate::SlackError<E> {
+    fn from(err: RevokeError<E>) -> Self {
+        match err {
+            RevokeError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RevokeError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RevokeError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RevokeError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RevokeError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RevokeError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RevokeError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RevokeError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RevokeError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RevokeError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RevokeError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RevokeError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RevokeError::Unknown(s) => crate::SlackError::Api(s),
+            RevokeError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Checks authentication & identity.
 ///
 /// Wraps https://api.slack.com/methods/auth.test
-
 pub fn test<R>(client: &R, token: &str) -> Result<TestResponse, TestError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("auth.test");
+    let url = crate::get_slack_url_for_method(client.base_url(), "auth.test");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(TestError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result).map_err(TestError::MalformedResponse)
+            serde_json::from_str::<TestResponse>(&result.body).map_err(TestError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TestResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
-    pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user: Option<String>,
-    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
-    fn into(self) -> Result<TestResponse, TestError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TestResponse> for Result<TestResponse, TestError<E>> {
+    fn from(response: TestResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -262,49 +305,27 @@ impl<'a, E: Error> From<&'a str> for TestError<E> {
 
 impl<E: Error> fmt::Display for TestError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for TestError<E> {
-    fn description(&self) -> &str {
         match *self {
-            TestError::NotAuthed => "not_authed: No authentication token provided.",
-            TestError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            TestError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            TestError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            TestError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            TestError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            TestError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            TestError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            TestError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            TestError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            TestError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            TestError::MalformedResponse(ref e) => e.description(),
-            TestError::Unknown(ref s) => s,
-            TestError::Client(ref inner) => inner.description(),
+            TestError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            TestError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            TestError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            TestError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            TestError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            TestError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            TestError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            TestError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            TestError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            TestError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            TestError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            TestError::MalformedResponse(ref e) => write!(f, "{}", e),
+            TestError::Unknown(ref s) => write!(f, "{}", s),
+            TestError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for TestError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             TestError::MalformedResponse(ref e) => Some(e),
             TestError::Client(ref inner) => Some(inner),
@@ -312,3 +333,57 @@ impl<E: Error> Error for TestError<E> {
         }
     }
 }
+
+impl<E: Error> From<TestError<E>> for crate::SlackError<E> {
+    fn from(err: TestError<E>) -> Self {
+        match err {
+            TestError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            TestError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            TestError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            TestError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            TestError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            TestError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            TestError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            TestError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            TestError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            TestError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            TestError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            TestError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            TestError::Unknown(s) => crate::SlackError::Api(s),
+            TestError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`auth`](auth/index.html) module.
+    pub fn auth(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Revokes a token.
+    ///
+    /// Wraps https://api.slack.com/methods/auth.revoke
+    pub fn revoke(&self, request: &RevokeRequest) -> Result<RevokeResponse, RevokeError<S::Error>> {
+        revoke(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Checks authentication & identity.
+    ///
+    /// Wraps https://api.slack.com/methods/auth.test
+    pub fn test(&self) -> Result<TestResponse, TestError<S::Error>> {
+        test(self.slack.sender(), self.slack.token())
+    }
+}
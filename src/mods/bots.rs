@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Gets information about a bot user.
 ///
 /// Wraps https://api.slack.com/methods/bots.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -22,15 +20,18 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("bots.info");
+    let params = vec![request.bot.map(|bot| ("bot", bot.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "bots.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -38,40 +39,87 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// Bot user to get info on
-    pub bot: Option<&'a str>,
+    pub bot: Option<&'a crate::BotId>,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    bot: Option<crate::BotId>,
+}
+
+impl InfoRequestBuilder {
+    /// Bot user to get info on
+    pub fn bot(mut self, bot: crate::BotId) -> Self {
+        self.bot = Some(bot);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            bot: self.bot.as_ref(),
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub bot: Option<InfoResponseBot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponseBot {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<InfoResponseBotIcons>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponseBotIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -134,51 +182,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::BotNotFound => "bot_not_found: Value passed for bot was invalid.",
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::BotNotFound => write!(f, "bot_not_found: Value passed for bot was invalid."),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -186,3 +212,52 @@ impl<E: Error> Error for InfoError<E> {
         }
     }
 }
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::BotNotFound => crate::SlackError::Api("bot_not_found".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`bots`](bots/index.html) module.
+    pub fn bots(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Gets information about a bot user.
+    ///
+    /// Wraps https://api.slack.com/methods/bots.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Archives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.archive
-
 pub fn archive<R>(
     client: &R,
     token: &str,
@@ -23,41 +21,76 @@ pub fn archive<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.archive");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.archive");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ArchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result).map_err(
+            serde_json::from_str::<ArchiveResponse>(&result.body).map_err(
                 ArchiveError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct ArchiveRequest<'a> {
     /// Channel to archive
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+}
+
+impl<'a> ArchiveRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ArchiveRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ArchiveRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ArchiveRequestBuilder {
+    channel: Option<crate::ChannelId>,
+}
+
+impl ArchiveRequestBuilder {
+    /// Channel to archive
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ArchiveRequest<'_>, crate::MissingField> {
+        Ok(ArchiveRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ArchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
-    fn into(self) -> Result<ArchiveResponse, ArchiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ArchiveResponse> for Result<ArchiveResponse, ArchiveError<E>> {
+    fn from(response: ArchiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -132,63 +165,33 @@ impl<'a, E: Error> From<&'a str> for ArchiveError<E> {
 
 impl<E: Error> fmt::Display for ArchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            ArchiveError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            ArchiveError::AlreadyArchived => write!(f, "already_archived: Channel has already been archived."),
+            ArchiveError::CantArchiveGeneral => write!(f, "cant_archive_general: You cannot archive the general channel"),
+            ArchiveError::RestrictedAction => write!(f, "restricted_action: A team preference prevents the authenticated user from archiving."),
+            ArchiveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ArchiveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ArchiveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ArchiveError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            ArchiveError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            ArchiveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ArchiveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ArchiveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ArchiveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ArchiveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ArchiveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ArchiveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ArchiveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ArchiveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ArchiveError::Unknown(ref s) => write!(f, "{}", s),
+            ArchiveError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for ArchiveError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            ArchiveError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            ArchiveError::AlreadyArchived => "already_archived: Channel has already been archived.",
-            ArchiveError::CantArchiveGeneral => {
-                "cant_archive_general: You cannot archive the general channel"
-            }
-            ArchiveError::RestrictedAction => {
-                "restricted_action: A team preference prevents the authenticated user from archiving."
-            }
-            ArchiveError::NotAuthed => "not_authed: No authentication token provided.",
-            ArchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ArchiveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ArchiveError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            ArchiveError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            ArchiveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ArchiveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ArchiveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ArchiveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ArchiveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ArchiveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ArchiveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ArchiveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ArchiveError::MalformedResponse(ref e) => e.description(),
-            ArchiveError::Unknown(ref s) => s,
-            ArchiveError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ArchiveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ArchiveError::MalformedResponse(ref e) => Some(e),
             ArchiveError::Client(ref inner) => Some(inner),
@@ -197,10 +200,36 @@ impl<E: Error> Error for ArchiveError<E> {
     }
 }
 
+impl<E: Error> From<ArchiveError<E>> for crate::SlackError<E> {
+    fn from(err: ArchiveError<E>) -> Self {
+        match err {
+            ArchiveError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            ArchiveError::AlreadyArchived => crate::SlackError::Api("already_archived".to_owned()),
+            ArchiveError::CantArchiveGeneral => crate::SlackError::Api("cant_archive_general".to_owned()),
+            ArchiveError::RestrictedAction => crate::SlackError::Api("restricted_action".to_owned()),
+            ArchiveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ArchiveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ArchiveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ArchiveError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            ArchiveError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            ArchiveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ArchiveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ArchiveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ArchiveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ArchiveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ArchiveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ArchiveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ArchiveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ArchiveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ArchiveError::Unknown(s) => crate::SlackError::Api(s),
+            ArchiveError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Creates a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.create
-
 pub fn create<R>(
     client: &R,
     token: &str,
@@ -209,21 +238,23 @@ pub fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         Some(("name", request.name)),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.create");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.create");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result).map_err(CreateError::MalformedResponse)
+            serde_json::from_str::<CreateResponse>(&result.body).map_err(CreateError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -236,21 +267,62 @@ pub struct CreateRequest<'a> {
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CreateRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> CreateRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`CreateRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequestBuilder {
+    name: Option<String>,
+    validate: Option<bool>,
+}
+
+impl CreateRequestBuilder {
+    /// Name of channel to create
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<CreateRequest<'_>, crate::MissingField> {
+        Ok(CreateRequest {
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            validate: self.validate,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
-    pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
-    fn into(self) -> Result<CreateResponse, CreateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -337,73 +409,37 @@ impl<'a, E: Error> From<&'a str> for CreateError<E> {
 
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            CreateError::NameTaken => write!(f, "name_taken: A channel cannot be created with the given name."),
+            CreateError::RestrictedAction => write!(f, "restricted_action: A team preference prevents the authenticated user from creating channels."),
+            CreateError::NoChannel => write!(f, "no_channel: Value passed for name was empty."),
+            CreateError::InvalidNameRequired => write!(f, "invalid_name_required: Value passed for name was empty."),
+            CreateError::InvalidNamePunctuation => write!(f, "invalid_name_punctuation: Value passed for name contained only punctuation."),
+            CreateError::InvalidNameMaxlength => write!(f, "invalid_name_maxlength: Value passed for name exceeded max length."),
+            CreateError::InvalidNameSpecials => write!(f, "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."),
+            CreateError::InvalidName => write!(f, "invalid_name: Value passed for name was invalid."),
+            CreateError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            CreateError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            CreateError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            CreateError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            CreateError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            CreateError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            CreateError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            CreateError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            CreateError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            CreateError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            CreateError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            CreateError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            CreateError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            CreateError::MalformedResponse(ref e) => write!(f, "{}", e),
+            CreateError::Unknown(ref s) => write!(f, "{}", s),
+            CreateError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for CreateError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            CreateError::NameTaken => {
-                "name_taken: A channel cannot be created with the given name."
-            }
-            CreateError::RestrictedAction => {
-                "restricted_action: A team preference prevents the authenticated user from creating channels."
-            }
-            CreateError::NoChannel => "no_channel: Value passed for name was empty.",
-            CreateError::InvalidNameRequired => {
-                "invalid_name_required: Value passed for name was empty."
-            }
-            CreateError::InvalidNamePunctuation => {
-                "invalid_name_punctuation: Value passed for name contained only punctuation."
-            }
-            CreateError::InvalidNameMaxlength => {
-                "invalid_name_maxlength: Value passed for name exceeded max length."
-            }
-            CreateError::InvalidNameSpecials => {
-                "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."
-            }
-            CreateError::InvalidName => "invalid_name: Value passed for name was invalid.",
-            CreateError::NotAuthed => "not_authed: No authentication token provided.",
-            CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            CreateError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            CreateError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            CreateError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            CreateError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            CreateError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            CreateError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            CreateError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            CreateError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            CreateError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            CreateError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            CreateError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            CreateError::MalformedResponse(ref e) => e.description(),
-            CreateError::Unknown(ref s) => s,
-            CreateError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for CreateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             CreateError::MalformedResponse(ref e) => Some(e),
             CreateError::Client(ref inner) => Some(inner),
@@ -412,10 +448,40 @@ impl<E: Error> Error for CreateError<E> {
     }
 }
 
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(err: CreateError<E>) -> Self {
+        match err {
+            CreateError::NameTaken => crate::SlackError::Api("name_taken".to_owned()),
+            CreateError::RestrictedAction => crate::SlackError::Api("restricted_action".to_owned()),
+            CreateError::NoChannel => crate::SlackError::Api("no_channel".to_owned()),
+            CreateError::InvalidNameRequired => crate::SlackError::Api("invalid_name_required".to_owned()),
+            CreateError::InvalidNamePunctuation => crate::SlackError::Api("invalid_name_punctuation".to_owned()),
+            CreateError::InvalidNameMaxlength => crate::SlackError::Api("invalid_name_maxlength".to_owned()),
+            CreateError::InvalidNameSpecials => crate::SlackError::Api("invalid_name_specials".to_owned()),
+            CreateError::InvalidName => crate::SlackError::Api("invalid_name".to_owned()),
+            CreateError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            CreateError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            CreateError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            CreateError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            CreateError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            CreateError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            CreateError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            CreateError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            CreateError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            CreateError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            CreateError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            CreateError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            CreateError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            CreateError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            CreateError::Unknown(s) => crate::SlackError::Api(s),
+            CreateError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Fetches history of messages and events from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.history
-
 pub fn history<R>(
     client: &R,
     token: &str,
@@ -426,10 +492,9 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
+        Some(("channel", request.channel.as_str())),
+        request.latest.map(|latest| ("latest", latest.as_str())),
+        request.oldest.map(|oldest| ("oldest", oldest.as_str())),
         request.inclusive.map(|inclusive| {
             ("inclusive", if inclusive { "1" } else { "0" })
         }),
@@ -438,27 +503,31 @@ where
             ("unreads", if unreads { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.history");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.history");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(HistoryError::Client)
         .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result).map_err(
+            serde_json::from_str::<HistoryResponse>(&result.body).map_err(
                 HistoryError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct HistoryRequest<'a> {
     /// Channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<&'a crate::Ts>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<&'a crate::Ts>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -467,23 +536,98 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> HistoryRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> HistoryRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`HistoryRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct HistoryRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    latest: Option<crate::Ts>,
+    oldest: Option<crate::Ts>,
+    inclusive: Option<bool>,
+    count: Option<u32>,
+    unreads: Option<bool>,
+}
+
+impl HistoryRequestBuilder {
+    /// Channel to fetch history for.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: crate::Ts) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: crate::Ts) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<HistoryRequest<'_>, crate::MissingField> {
+        Ok(HistoryRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            latest: self.latest.as_ref(),
+            oldest: self.oldest.as_ref(),
+            inclusive: self.inclusive,
+            count: self.count,
+            unreads: self.unreads,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
-    pub latest: Option<String>,
-    pub messages: Option<Vec<::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<crate::Message>>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
-    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<HistoryResponse> for Result<HistoryResponse, HistoryError<E>> {
+    fn from(response: HistoryResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -549,58 +693,30 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
 
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            HistoryError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            HistoryError::InvalidTsLatest => write!(f, "invalid_ts_latest: Value passed for latest was invalid"),
+            HistoryError::InvalidTsOldest => write!(f, "invalid_ts_oldest: Value passed for oldest was invalid"),
+            HistoryError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            HistoryError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            HistoryError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            HistoryError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            HistoryError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            HistoryError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            HistoryError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            HistoryError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            HistoryError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            HistoryError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            HistoryError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            HistoryError::MalformedResponse(ref e) => write!(f, "{}", e),
+            HistoryError::Unknown(ref s) => write!(f, "{}", s),
+            HistoryError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for HistoryError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            HistoryError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            HistoryError::InvalidTsLatest => {
-                "invalid_ts_latest: Value passed for latest was invalid"
-            }
-            HistoryError::InvalidTsOldest => {
-                "invalid_ts_oldest: Value passed for oldest was invalid"
-            }
-            HistoryError::NotAuthed => "not_authed: No authentication token provided.",
-            HistoryError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            HistoryError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            HistoryError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            HistoryError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            HistoryError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            HistoryError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            HistoryError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            HistoryError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            HistoryError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            HistoryError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            HistoryError::MalformedResponse(ref e) => e.description(),
-            HistoryError::Unknown(ref s) => s,
-            HistoryError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for HistoryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             HistoryError::MalformedResponse(ref e) => Some(e),
             HistoryError::Client(ref inner) => Some(inner),
@@ -609,10 +725,33 @@ impl<E: Error> Error for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for crate::SlackError<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        match err {
+            HistoryError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            HistoryError::InvalidTsLatest => crate::SlackError::Api("invalid_ts_latest".to_owned()),
+            HistoryError::InvalidTsOldest => crate::SlackError::Api("invalid_ts_oldest".to_owned()),
+            HistoryError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            HistoryError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            HistoryError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            HistoryError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            HistoryError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            HistoryError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            HistoryError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            HistoryError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            HistoryError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            HistoryError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            HistoryError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            HistoryError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            HistoryError::Unknown(s) => crate::SlackError::Api(s),
+            HistoryError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets information about a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -621,40 +760,76 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.info");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct InfoRequest<'a> {
     /// Channel to get info on
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    channel: Option<crate::ChannelId>,
+}
+
+impl InfoRequestBuilder {
+    /// Channel to get info on
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
-    pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -714,52 +889,28 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            InfoError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            InfoError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -768,10 +919,31 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Invites a user to a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.invite
-
 pub fn invite<R>(
     client: &R,
     token: &str,
@@ -780,46 +952,89 @@ pub fn invite<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.invite");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.invite");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InviteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result).map_err(InviteError::MalformedResponse)
+            serde_json::from_str::<InviteResponse>(&result.body).map_err(InviteError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct InviteRequest<'a> {
     /// Channel to invite user to.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+    /// User to invite to channel.
+    pub user: &'a crate::UserId,
+}
+
+impl<'a> InviteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InviteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InviteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    user: Option<crate::UserId>,
+}
+
+impl InviteRequestBuilder {
+    /// Channel to invite user to.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
     /// User to invite to channel.
-    pub user: &'a str,
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InviteRequest<'_>, crate::MissingField> {
+        Ok(InviteRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            user: self.user.as_ref().ok_or(crate::MissingField("user"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InviteResponse {
-    pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
-    fn into(self) -> Result<InviteResponse, InviteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InviteResponse> for Result<InviteResponse, InviteError<E>> {
+    fn from(response: InviteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -906,71 +1121,37 @@ impl<'a, E: Error> From<&'a str> for InviteError<E> {
 
 impl<E: Error> fmt::Display for InviteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            InviteError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            InviteError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            InviteError::CantInviteSelf => write!(f, "cant_invite_self: Authenticated user cannot invite themselves to a channel."),
+            InviteError::NotInChannel => write!(f, "not_in_channel: Authenticated user is not in the channel."),
+            InviteError::AlreadyInChannel => write!(f, "already_in_channel: Invited user is already in the channel."),
+            InviteError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            InviteError::CantInvite => write!(f, "cant_invite: User cannot be invited to this channel."),
+            InviteError::UraMaxChannels => write!(f, "ura_max_channels: URA is already in the maximum number of channels."),
+            InviteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InviteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InviteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InviteError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            InviteError::UserIsUltraRestricted => write!(f, "user_is_ultra_restricted: This method cannot be called by a single channel guest."),
+            InviteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InviteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InviteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InviteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InviteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InviteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InviteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InviteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InviteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InviteError::Unknown(ref s) => write!(f, "{}", s),
+            InviteError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for InviteError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            InviteError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            InviteError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            InviteError::CantInviteSelf => {
-                "cant_invite_self: Authenticated user cannot invite themselves to a channel."
-            }
-            InviteError::NotInChannel => {
-                "not_in_channel: Authenticated user is not in the channel."
-            }
-            InviteError::AlreadyInChannel => {
-                "already_in_channel: Invited user is already in the channel."
-            }
-            InviteError::IsArchived => "is_archived: Channel has been archived.",
-            InviteError::CantInvite => "cant_invite: User cannot be invited to this channel.",
-            InviteError::UraMaxChannels => {
-                "ura_max_channels: URA is already in the maximum number of channels."
-            }
-            InviteError::NotAuthed => "not_authed: No authentication token provided.",
-            InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InviteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InviteError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            InviteError::UserIsUltraRestricted => {
-                "user_is_ultra_restricted: This method cannot be called by a single channel guest."
-            }
-            InviteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InviteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InviteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InviteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InviteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InviteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InviteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InviteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InviteError::MalformedResponse(ref e) => e.description(),
-            InviteError::Unknown(ref s) => s,
-            InviteError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InviteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InviteError::MalformedResponse(ref e) => Some(e),
             InviteError::Client(ref inner) => Some(inner),
@@ -979,10 +1160,40 @@ impl<E: Error> Error for InviteError<E> {
     }
 }
 
+impl<E: Error> From<InviteError<E>> for crate::SlackError<E> {
+    fn from(err: InviteError<E>) -> Self {
+        match err {
+            InviteError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            InviteError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            InviteError::CantInviteSelf => crate::SlackError::Api("cant_invite_self".to_owned()),
+            InviteError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            InviteError::AlreadyInChannel => crate::SlackError::Api("already_in_channel".to_owned()),
+            InviteError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            InviteError::CantInvite => crate::SlackError::Api("cant_invite".to_owned()),
+            InviteError::UraMaxChannels => crate::SlackError::Api("ura_max_channels".to_owned()),
+            InviteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InviteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InviteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InviteError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            InviteError::UserIsUltraRestricted => crate::SlackError::Api("user_is_ultra_restricted".to_owned()),
+            InviteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InviteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InviteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InviteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InviteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InviteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InviteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InviteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InviteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InviteError::Unknown(s) => crate::SlackError::Api(s),
+            InviteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Joins a channel, creating it if needed.
 ///
 /// Wraps https://api.slack.com/methods/channels.join
-
 pub fn join<R>(
     client: &R,
     token: &str,
@@ -991,21 +1202,23 @@ pub fn join<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         Some(("name", request.name)),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.join");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.join");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(JoinError::Client)
         .and_then(|result| {
-            serde_json::from_str::<JoinResponse>(&result).map_err(JoinError::MalformedResponse)
+            serde_json::from_str::<JoinResponse>(&result.body).map_err(JoinError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -1018,21 +1231,62 @@ pub struct JoinRequest<'a> {
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> JoinRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> JoinRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`JoinRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct JoinRequestBuilder {
+    name: Option<String>,
+    validate: Option<bool>,
+}
+
+impl JoinRequestBuilder {
+    /// Name of channel to join
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<JoinRequest<'_>, crate::MissingField> {
+        Ok(JoinRequest {
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            validate: self.validate,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct JoinResponse {
-    pub channel: Option<::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<JoinResponse, JoinError<E>>> for JoinResponse {
-    fn into(self) -> Result<JoinResponse, JoinError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<JoinResponse> for Result<JoinResponse, JoinError<E>> {
+    fn from(response: JoinResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1125,75 +1379,39 @@ impl<'a, E: Error> From<&'a str> for JoinError<E> {
 
 impl<E: Error> fmt::Display for JoinError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            JoinError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            JoinError::NameTaken => write!(f, "name_taken: A channel cannot be created with the given name."),
+            JoinError::RestrictedAction => write!(f, "restricted_action: A team preference prevents the authenticated user from creating channels."),
+            JoinError::NoChannel => write!(f, "no_channel: Value passed for name was empty."),
+            JoinError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            JoinError::InvalidNameRequired => write!(f, "invalid_name_required: Value passed for name was empty."),
+            JoinError::InvalidNamePunctuation => write!(f, "invalid_name_punctuation: Value passed for name contained only punctuation."),
+            JoinError::InvalidNameMaxlength => write!(f, "invalid_name_maxlength: Value passed for name exceeded max length."),
+            JoinError::InvalidNameSpecials => write!(f, "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."),
+            JoinError::InvalidName => write!(f, "invalid_name: Value passed for name was invalid."),
+            JoinError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            JoinError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            JoinError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            JoinError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            JoinError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            JoinError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            JoinError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            JoinError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            JoinError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            JoinError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            JoinError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            JoinError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            JoinError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            JoinError::MalformedResponse(ref e) => write!(f, "{}", e),
+            JoinError::Unknown(ref s) => write!(f, "{}", s),
+            JoinError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for JoinError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            JoinError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            JoinError::NameTaken => "name_taken: A channel cannot be created with the given name.",
-            JoinError::RestrictedAction => {
-                "restricted_action: A team preference prevents the authenticated user from creating channels."
-            }
-            JoinError::NoChannel => "no_channel: Value passed for name was empty.",
-            JoinError::IsArchived => "is_archived: Channel has been archived.",
-            JoinError::InvalidNameRequired => {
-                "invalid_name_required: Value passed for name was empty."
-            }
-            JoinError::InvalidNamePunctuation => {
-                "invalid_name_punctuation: Value passed for name contained only punctuation."
-            }
-            JoinError::InvalidNameMaxlength => {
-                "invalid_name_maxlength: Value passed for name exceeded max length."
-            }
-            JoinError::InvalidNameSpecials => {
-                "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."
-            }
-            JoinError::InvalidName => "invalid_name: Value passed for name was invalid.",
-            JoinError::NotAuthed => "not_authed: No authentication token provided.",
-            JoinError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            JoinError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            JoinError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            JoinError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            JoinError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            JoinError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            JoinError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            JoinError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            JoinError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            JoinError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            JoinError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            JoinError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            JoinError::MalformedResponse(ref e) => e.description(),
-            JoinError::Unknown(ref s) => s,
-            JoinError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for JoinError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             JoinError::MalformedResponse(ref e) => Some(e),
             JoinError::Client(ref inner) => Some(inner),
@@ -1202,10 +1420,42 @@ impl<E: Error> Error for JoinError<E> {
     }
 }
 
+impl<E: Error> From<JoinError<E>> for crate::SlackError<E> {
+    fn from(err: JoinError<E>) -> Self {
+        match err {
+            JoinError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            JoinError::NameTaken => crate::SlackError::Api("name_taken".to_owned()),
+            JoinError::RestrictedAction => crate::SlackError::Api("restricted_action".to_owned()),
+            JoinError::NoChannel => crate::SlackError::Api("no_channel".to_owned()),
+            JoinError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            JoinError::InvalidNameRequired => crate::SlackError::Api("invalid_name_required".to_owned()),
+            JoinError::InvalidNamePunctuation => crate::SlackError::Api("invalid_name_punctuation".to_owned()),
+            JoinError::InvalidNameMaxlength => crate::SlackError::Api("invalid_name_maxlength".to_owned()),
+            JoinError::InvalidNameSpecials => crate::SlackError::Api("invalid_name_specials".to_owned()),
+            JoinError::InvalidName => crate::SlackError::Api("invalid_name".to_owned()),
+            JoinError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            JoinError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            JoinError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            JoinError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            JoinError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            JoinError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            JoinError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            JoinError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            JoinError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            JoinError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            JoinError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            JoinError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            JoinError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            JoinError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            JoinError::Unknown(s) => crate::SlackError::Api(s),
+            JoinError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Removes a user from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.kick
-
 pub fn kick<R>(
     client: &R,
     token: &str,
@@ -1214,45 +1464,87 @@ pub fn kick<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("user", request.user)),
+        Some(("channel", request.channel.as_str())),
+        Some(("user", request.user.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.kick");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.kick");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(KickError::Client)
         .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result).map_err(KickError::MalformedResponse)
+            serde_json::from_str::<KickResponse>(&result.body).map_err(KickError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct KickRequest<'a> {
     /// Channel to remove user from.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// User to remove from channel.
-    pub user: &'a str,
+    pub user: &'a crate::UserId,
+}
+
+impl<'a> KickRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> KickRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`KickRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct KickRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    user: Option<crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl KickRequestBuilder {
+    /// Channel to remove user from.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// User to remove from channel.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<KickRequest<'_>, crate::MissingField> {
+        Ok(KickRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            user: self.user.as_ref().ok_or(crate::MissingField("user"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct KickResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
-    fn into(self) -> Result<KickResponse, KickError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<KickResponse> for Result<KickResponse, KickError<E>> {
+    fn from(response: KickResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1333,67 +1625,35 @@ impl<'a, E: Error> From<&'a str> for KickError<E> {
 
 impl<E: Error> fmt::Display for KickError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            KickError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            KickError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            KickError::CantKickSelf => write!(f, "cant_kick_self: Authenticated user can't kick themselves from a channel."),
+            KickError::NotInChannel => write!(f, "not_in_channel: User was not in the channel."),
+            KickError::CantKickFromGeneral => write!(f, "cant_kick_from_general: User cannot be removed from #general."),
+            KickError::RestrictedAction => write!(f, "restricted_action: A team preference prevents the authenticated user from kicking."),
+            KickError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            KickError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            KickError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            KickError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            KickError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            KickError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            KickError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            KickError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            KickError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            KickError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            KickError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            KickError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            KickError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            KickError::MalformedResponse(ref e) => write!(f, "{}", e),
+            KickError::Unknown(ref s) => write!(f, "{}", s),
+            KickError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for KickError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            KickError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            KickError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            KickError::CantKickSelf => {
-                "cant_kick_self: Authenticated user can't kick themselves from a channel."
-            }
-            KickError::NotInChannel => "not_in_channel: User was not in the channel.",
-            KickError::CantKickFromGeneral => {
-                "cant_kick_from_general: User cannot be removed from #general."
-            }
-            KickError::RestrictedAction => {
-                "restricted_action: A team preference prevents the authenticated user from kicking."
-            }
-            KickError::NotAuthed => "not_authed: No authentication token provided.",
-            KickError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            KickError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            KickError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            KickError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            KickError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            KickError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            KickError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            KickError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            KickError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            KickError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            KickError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            KickError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            KickError::MalformedResponse(ref e) => e.description(),
-            KickError::Unknown(ref s) => s,
-            KickError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for KickError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             KickError::MalformedResponse(ref e) => Some(e),
             KickError::Client(ref inner) => Some(inner),
@@ -1402,10 +1662,38 @@ impl<E: Error> Error for KickError<E> {
     }
 }
 
+impl<E: Error> From<KickError<E>> for crate::SlackError<E> {
+    fn from(err: KickError<E>) -> Self {
+        match err {
+            KickError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            KickError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            KickError::CantKickSelf => crate::SlackError::Api("cant_kick_self".to_owned()),
+            KickError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            KickError::CantKickFromGeneral => crate::SlackError::Api("cant_kick_from_general".to_owned()),
+            KickError::RestrictedAction => crate::SlackError::Api("restricted_action".to_owned()),
+            KickError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            KickError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            KickError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            KickError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            KickError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            KickError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            KickError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            KickError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            KickError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            KickError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            KickError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            KickError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            KickError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            KickError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            KickError::Unknown(s) => crate::SlackError::Api(s),
+            KickError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Leaves a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.leave
-
 pub fn leave<R>(
     client: &R,
     token: &str,
@@ -1414,39 +1702,74 @@ pub fn leave<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.leave");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.leave");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(LeaveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result).map_err(LeaveError::MalformedResponse)
+            serde_json::from_str::<LeaveResponse>(&result.body).map_err(LeaveError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct LeaveRequest<'a> {
     /// Channel to leave
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+}
+
+impl<'a> LeaveRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> LeaveRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`LeaveRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct LeaveRequestBuilder {
+    channel: Option<crate::ChannelId>,
+}
+
+impl LeaveRequestBuilder {
+    /// Channel to leave
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<LeaveRequest<'_>, crate::MissingField> {
+        Ok(LeaveRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeaveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
-    fn into(self) -> Result<LeaveResponse, LeaveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<LeaveResponse> for Result<LeaveResponse, LeaveError<E>> {
+    fn from(response: LeaveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1518,60 +1841,32 @@ impl<'a, E: Error> From<&'a str> for LeaveError<E> {
 
 impl<E: Error> fmt::Display for LeaveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            LeaveError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            LeaveError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            LeaveError::CantLeaveGeneral => write!(f, "cant_leave_general: Authenticated user cannot leave the general channel"),
+            LeaveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            LeaveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            LeaveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            LeaveError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            LeaveError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            LeaveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            LeaveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            LeaveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            LeaveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            LeaveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            LeaveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            LeaveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            LeaveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            LeaveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            LeaveError::Unknown(ref s) => write!(f, "{}", s),
+            LeaveError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for LeaveError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            LeaveError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            LeaveError::IsArchived => "is_archived: Channel has been archived.",
-            LeaveError::CantLeaveGeneral => {
-                "cant_leave_general: Authenticated user cannot leave the general channel"
-            }
-            LeaveError::NotAuthed => "not_authed: No authentication token provided.",
-            LeaveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            LeaveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            LeaveError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            LeaveError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            LeaveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            LeaveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            LeaveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            LeaveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            LeaveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            LeaveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            LeaveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            LeaveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            LeaveError::MalformedResponse(ref e) => e.description(),
-            LeaveError::Unknown(ref s) => s,
-            LeaveError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for LeaveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             LeaveError::MalformedResponse(ref e) => Some(e),
             LeaveError::Client(ref inner) => Some(inner),
@@ -1580,10 +1875,35 @@ impl<E: Error> Error for LeaveError<E> {
     }
 }
 
+impl<E: Error> From<LeaveError<E>> for crate::SlackError<E> {
+    fn from(err: LeaveError<E>) -> Self {
+        match err {
+            LeaveError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            LeaveError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            LeaveError::CantLeaveGeneral => crate::SlackError::Api("cant_leave_general".to_owned()),
+            LeaveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            LeaveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            LeaveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            LeaveError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            LeaveError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            LeaveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            LeaveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            LeaveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            LeaveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            LeaveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            LeaveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            LeaveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            LeaveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            LeaveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            LeaveError::Unknown(s) => crate::SlackError::Api(s),
+            LeaveError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists all channels in a Slack team.
 ///
 /// Wraps https://api.slack.com/methods/channels.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -1592,9 +1912,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.exclude_archived.map(|exclude_archived| {
             ("exclude_archived", if exclude_archived { "1" } else { "0" })
         }),
@@ -1602,13 +1920,17 @@ where
             ("exclude_members", if exclude_members { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -1621,21 +1943,62 @@ pub struct ListRequest {
     pub exclude_members: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    exclude_archived: Option<bool>,
+    exclude_members: Option<bool>,
+}
+
+impl ListRequestBuilder {
+    /// Exclude archived channels from the list
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    /// Exclude the members collection from each channel
+    pub fn exclude_members(mut self, exclude_members: bool) -> Self {
+        self.exclude_members = Some(exclude_members);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest, crate::MissingField> {
+        Ok(ListRequest {
+            exclude_archived: self.exclude_archived,
+            exclude_members: self.exclude_members,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
-    pub channels: Option<Vec<::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<crate::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1692,49 +2055,27 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -1743,10 +2084,30 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Sets the read cursor in a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.mark
-
 pub fn mark<R>(
     client: &R,
     token: &str,
@@ -1755,45 +2116,87 @@ pub fn mark<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", request.channel.as_str())),
+        Some(("ts", request.ts.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.mark");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.mark");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(MarkError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result).map_err(MarkError::MalformedResponse)
+            serde_json::from_str::<MarkResponse>(&result.body).map_err(MarkError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct MarkRequest<'a> {
     /// Channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: &'a crate::Ts,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> MarkRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`MarkRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct MarkRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    ts: Option<crate::Ts>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl MarkRequestBuilder {
+    /// Channel to set reading cursor in.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Timestamp of the most recently seen message.
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<MarkRequest<'_>, crate::MissingField> {
+        Ok(MarkRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            ts: self.ts.as_ref().ok_or(crate::MissingField("ts"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
-    fn into(self) -> Result<MarkResponse, MarkError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MarkResponse> for Result<MarkResponse, MarkError<E>> {
+    fn from(response: MarkResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1859,56 +2262,30 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
 
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            MarkError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            MarkError::InvalidTimestamp => write!(f, "invalid_timestamp: Value passed for timestamp was invalid."),
+            MarkError::NotInChannel => write!(f, "not_in_channel: Caller is not a member of the channel."),
+            MarkError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            MarkError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            MarkError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            MarkError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            MarkError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            MarkError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            MarkError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            MarkError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            MarkError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            MarkError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            MarkError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            MarkError::MalformedResponse(ref e) => write!(f, "{}", e),
+            MarkError::Unknown(ref s) => write!(f, "{}", s),
+            MarkError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for MarkError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            MarkError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            MarkError::InvalidTimestamp => {
-                "invalid_timestamp: Value passed for timestamp was invalid."
-            }
-            MarkError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
-            MarkError::NotAuthed => "not_authed: No authentication token provided.",
-            MarkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            MarkError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            MarkError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            MarkError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            MarkError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            MarkError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            MarkError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            MarkError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            MarkError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            MarkError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            MarkError::MalformedResponse(ref e) => e.description(),
-            MarkError::Unknown(ref s) => s,
-            MarkError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for MarkError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             MarkError::MalformedResponse(ref e) => Some(e),
             MarkError::Client(ref inner) => Some(inner),
@@ -1917,10 +2294,33 @@ impl<E: Error> Error for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for crate::SlackError<E> {
+    fn from(err: MarkError<E>) -> Self {
+        match err {
+            MarkError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            MarkError::InvalidTimestamp => crate::SlackError::Api("invalid_timestamp".to_owned()),
+            MarkError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            MarkError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            MarkError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            MarkError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            MarkError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            MarkError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            MarkError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            MarkError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            MarkError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            MarkError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            MarkError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            MarkError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            MarkError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            MarkError::Unknown(s) => crate::SlackError::Api(s),
+            MarkError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Renames a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.rename
-
 pub fn rename<R>(
     client: &R,
     token: &str,
@@ -1929,59 +2329,117 @@ pub fn rename<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
+        Some(("channel", request.channel.as_str())),
         Some(("name", request.name)),
         request.validate.map(|validate| {
             ("validate", if validate { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.rename");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.rename");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RenameError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result).map_err(RenameError::MalformedResponse)
+            serde_json::from_str::<RenameResponse>(&result.body).map_err(RenameError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RenameRequest<'a> {
     /// Channel to rename
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// New name for channel.
     pub name: &'a str,
     /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RenameRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RenameRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RenameRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RenameRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    name: Option<String>,
+    validate: Option<bool>,
+}
+
+impl RenameRequestBuilder {
+    /// Channel to rename
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// New name for channel.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RenameRequest<'_>, crate::MissingField> {
+        Ok(RenameRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            validate: self.validate,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<RenameResponseChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RenameResponseChannel {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<f32>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
-    fn into(self) -> Result<RenameResponse, RenameError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RenameResponse> for Result<RenameResponse, RenameError<E>> {
+    fn from(response: RenameResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -2071,72 +2529,38 @@ impl<'a, E: Error> From<&'a str> for RenameError<E> {
 
 impl<E: Error> fmt::Display for RenameError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            RenameError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            RenameError::NotInChannel => write!(f, "not_in_channel: Caller is not a member of the channel."),
+            RenameError::NotAuthorized => write!(f, "not_authorized: Caller cannot rename this channel"),
+            RenameError::InvalidName => write!(f, "invalid_name: Value passed for name was invalid."),
+            RenameError::NameTaken => write!(f, "name_taken: New channel name is taken"),
+            RenameError::InvalidNameRequired => write!(f, "invalid_name_required: Value passed for name was empty."),
+            RenameError::InvalidNamePunctuation => write!(f, "invalid_name_punctuation: Value passed for name contained only punctuation."),
+            RenameError::InvalidNameMaxlength => write!(f, "invalid_name_maxlength: Value passed for name exceeded max length."),
+            RenameError::InvalidNameSpecials => write!(f, "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."),
+            RenameError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RenameError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RenameError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RenameError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            RenameError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            RenameError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RenameError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RenameError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RenameError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RenameError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RenameError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RenameError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RenameError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RenameError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RenameError::Unknown(ref s) => write!(f, "{}", s),
+            RenameError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for RenameError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            RenameError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            RenameError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
-            RenameError::NotAuthorized => "not_authorized: Caller cannot rename this channel",
-            RenameError::InvalidName => "invalid_name: Value passed for name was invalid.",
-            RenameError::NameTaken => "name_taken: New channel name is taken",
-            RenameError::InvalidNameRequired => {
-                "invalid_name_required: Value passed for name was empty."
-            }
-            RenameError::InvalidNamePunctuation => {
-                "invalid_name_punctuation: Value passed for name contained only punctuation."
-            }
-            RenameError::InvalidNameMaxlength => {
-                "invalid_name_maxlength: Value passed for name exceeded max length."
-            }
-            RenameError::InvalidNameSpecials => {
-                "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters."
-            }
-            RenameError::NotAuthed => "not_authed: No authentication token provided.",
-            RenameError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RenameError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RenameError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            RenameError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            RenameError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RenameError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RenameError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RenameError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RenameError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RenameError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RenameError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RenameError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RenameError::MalformedResponse(ref e) => e.description(),
-            RenameError::Unknown(ref s) => s,
-            RenameError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RenameError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RenameError::MalformedResponse(ref e) => Some(e),
             RenameError::Client(ref inner) => Some(inner),
@@ -2145,10 +2569,41 @@ impl<E: Error> Error for RenameError<E> {
     }
 }
 
+impl<E: Error> From<RenameError<E>> for crate::SlackError<E> {
+    fn from(err: RenameError<E>) -> Self {
+        match err {
+            RenameError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            RenameError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            RenameError::NotAuthorized => crate::SlackError::Api("not_authorized".to_owned()),
+            RenameError::InvalidName => crate::SlackError::Api("invalid_name".to_owned()),
+            RenameError::NameTaken => crate::SlackError::Api("name_taken".to_owned()),
+            RenameError::InvalidNameRequired => crate::SlackError::Api("invalid_name_required".to_owned()),
+            RenameError::InvalidNamePunctuation => crate::SlackError::Api("invalid_name_punctuation".to_owned()),
+            RenameError::InvalidNameMaxlength => crate::SlackError::Api("invalid_name_maxlength".to_owned()),
+            RenameError::InvalidNameSpecials => crate::SlackError::Api("invalid_name_specials".to_owned()),
+            RenameError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RenameError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RenameError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RenameError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            RenameError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            RenameError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RenameError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RenameError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RenameError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RenameError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RenameError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RenameError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RenameError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RenameError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RenameError::Unknown(s) => crate::SlackError::Api(s),
+            RenameError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Retrieve a thread of messages posted to a channel
 ///
 /// Wraps https://api.slack.com/methods/channels.replies
-
 pub fn replies<R>(
     client: &R,
     token: &str,
@@ -2157,49 +2612,93 @@ pub fn replies<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", request.channel.as_str())),
+        Some(("thread_ts", request.thread_ts.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.replies");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.replies");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RepliesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result).map_err(
+            serde_json::from_str::<RepliesResponse>(&result.body).map_err(
                 RepliesError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RepliesRequest<'a> {
     /// Channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+    /// Unique identifier of a thread's parent message
+    pub thread_ts: &'a crate::Ts,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RepliesRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RepliesRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RepliesRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    thread_ts: Option<crate::Ts>,
+}
+
+impl RepliesRequestBuilder {
+    /// Channel to fetch thread from
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub fn thread_ts(mut self, thread_ts: crate::Ts) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RepliesRequest<'_>, crate::MissingField> {
+        Ok(RepliesRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            thread_ts: self.thread_ts.as_ref().ok_or(crate::MissingField("thread_ts"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub messages: Option<Vec<::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<crate::Message>>,
     #[serde(default)]
     ok: bool,
-    pub thread_info: Option<::ThreadInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_info: Option<crate::ThreadInfo>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
-    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RepliesResponse> for Result<RepliesResponse, RepliesError<E>> {
+    fn from(response: RepliesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -2262,55 +2761,29 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
 
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            RepliesError::ChannelNotFound => write!(f, "channel_not_found: Value for channel was missing or invalid."),
+            RepliesError::ThreadNotFound => write!(f, "thread_not_found: Value for thread_ts was missing or invalid."),
+            RepliesError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RepliesError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RepliesError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RepliesError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RepliesError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RepliesError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RepliesError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RepliesError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RepliesError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RepliesError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RepliesError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RepliesError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RepliesError::Unknown(ref s) => write!(f, "{}", s),
+            RepliesError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for RepliesError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            RepliesError::ChannelNotFound => {
-                "channel_not_found: Value for channel was missing or invalid."
-            }
-            RepliesError::ThreadNotFound => {
-                "thread_not_found: Value for thread_ts was missing or invalid."
-            }
-            RepliesError::NotAuthed => "not_authed: No authentication token provided.",
-            RepliesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RepliesError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RepliesError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RepliesError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RepliesError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RepliesError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RepliesError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RepliesError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RepliesError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RepliesError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RepliesError::MalformedResponse(ref e) => e.description(),
-            RepliesError::Unknown(ref s) => s,
-            RepliesError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RepliesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RepliesError::MalformedResponse(ref e) => Some(e),
             RepliesError::Client(ref inner) => Some(inner),
@@ -2319,10 +2792,32 @@ impl<E: Error> Error for RepliesError<E> {
     }
 }
 
+impl<E: Error> From<RepliesError<E>> for crate::SlackError<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        match err {
+            RepliesError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            RepliesError::ThreadNotFound => crate::SlackError::Api("thread_not_found".to_owned()),
+            RepliesError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RepliesError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RepliesError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RepliesError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RepliesError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RepliesError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RepliesError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RepliesError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RepliesError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RepliesError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RepliesError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RepliesError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RepliesError::Unknown(s) => crate::SlackError::Api(s),
+            RepliesError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Sets the purpose for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setPurpose
-
 pub fn set_purpose<R>(
     client: &R,
     token: &str,
@@ -2331,47 +2826,90 @@ pub fn set_purpose<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
+        Some(("channel", request.channel.as_str())),
         Some(("purpose", request.purpose)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.setPurpose");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.setPurpose");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SetPurposeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
+            serde_json::from_str::<SetPurposeResponse>(&result.body)
                 .map_err(SetPurposeError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct SetPurposeRequest<'a> {
     /// Channel to set the purpose of
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// The new purpose
     pub purpose: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> SetPurposeRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetPurposeRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetPurposeRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetPurposeRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    purpose: Option<String>,
+}
+
+impl SetPurposeRequestBuilder {
+    /// Channel to set the purpose of
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// The new purpose
+    pub fn purpose<S: Into<String>>(mut self, purpose: S) -> Self {
+        self.purpose = Some(purpose.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetPurposeRequest<'_>, crate::MissingField> {
+        Ok(SetPurposeRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            purpose: self.purpose.as_deref().ok_or(crate::MissingField("purpose"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPurposeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurposeResponse {
-    fn into(self) -> Result<SetPurposeResponse, SetPurposeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetPurposeResponse> for Result<SetPurposeResponse, SetPurposeError<E>> {
+    fn from(response: SetPurposeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -2443,60 +2981,32 @@ impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
 
 impl<E: Error> fmt::Display for SetPurposeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            SetPurposeError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            SetPurposeError::NotInChannel => write!(f, "not_in_channel: Authenticated user is not in the channel."),
+            SetPurposeError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            SetPurposeError::TooLong => write!(f, "too_long: Purpose was longer than 250 characters."),
+            SetPurposeError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            SetPurposeError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetPurposeError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetPurposeError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetPurposeError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetPurposeError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetPurposeError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetPurposeError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetPurposeError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetPurposeError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetPurposeError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetPurposeError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetPurposeError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetPurposeError::Unknown(ref s) => write!(f, "{}", s),
+            SetPurposeError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for SetPurposeError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            SetPurposeError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            SetPurposeError::NotInChannel => {
-                "not_in_channel: Authenticated user is not in the channel."
-            }
-            SetPurposeError::IsArchived => "is_archived: Channel has been archived.",
-            SetPurposeError::TooLong => "too_long: Purpose was longer than 250 characters.",
-            SetPurposeError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            SetPurposeError::NotAuthed => "not_authed: No authentication token provided.",
-            SetPurposeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetPurposeError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetPurposeError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetPurposeError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetPurposeError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetPurposeError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetPurposeError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetPurposeError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetPurposeError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetPurposeError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetPurposeError::MalformedResponse(ref e) => e.description(),
-            SetPurposeError::Unknown(ref s) => s,
-            SetPurposeError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for SetPurposeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SetPurposeError::MalformedResponse(ref e) => Some(e),
             SetPurposeError::Client(ref inner) => Some(inner),
@@ -2505,10 +3015,35 @@ impl<E: Error> Error for SetPurposeError<E> {
     }
 }
 
+impl<E: Error> From<SetPurposeError<E>> for crate::SlackError<E> {
+    fn from(err: SetPurposeError<E>) -> Self {
+        match err {
+            SetPurposeError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            SetPurposeError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            SetPurposeError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            SetPurposeError::TooLong => crate::SlackError::Api("too_long".to_owned()),
+            SetPurposeError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            SetPurposeError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetPurposeError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetPurposeError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetPurposeError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetPurposeError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetPurposeError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetPurposeError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetPurposeError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetPurposeError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetPurposeError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetPurposeError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetPurposeError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetPurposeError::Unknown(s) => crate::SlackError::Api(s),
+            SetPurposeError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Sets the topic for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setTopic
-
 pub fn set_topic<R>(
     client: &R,
     token: &str,
@@ -2517,48 +3052,91 @@ pub fn set_topic<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
+        Some(("channel", request.channel.as_str())),
         Some(("topic", request.topic)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.setTopic");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.setTopic");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SetTopicError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result).map_err(
+            serde_json::from_str::<SetTopicResponse>(&result.body).map_err(
                 SetTopicError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct SetTopicRequest<'a> {
     /// Channel to set the topic of
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// The new topic
     pub topic: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> SetTopicRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetTopicRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetTopicRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetTopicRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    topic: Option<String>,
+}
+
+impl SetTopicRequestBuilder {
+    /// Channel to set the topic of
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// The new topic
+    pub fn topic<S: Into<String>>(mut self, topic: S) -> Self {
+        self.topic = Some(topic.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetTopicRequest<'_>, crate::MissingField> {
+        Ok(SetTopicRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            topic: self.topic.as_deref().ok_or(crate::MissingField("topic"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetTopicResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResponse {
-    fn into(self) -> Result<SetTopicResponse, SetTopicError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetTopicResponse> for Result<SetTopicResponse, SetTopicError<E>> {
+    fn from(response: SetTopicResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -2630,60 +3208,32 @@ impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
 
 impl<E: Error> fmt::Display for SetTopicError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            SetTopicError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            SetTopicError::NotInChannel => write!(f, "not_in_channel: Authenticated user is not in the channel."),
+            SetTopicError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            SetTopicError::TooLong => write!(f, "too_long: Topic was longer than 250 characters."),
+            SetTopicError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            SetTopicError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetTopicError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetTopicError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetTopicError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetTopicError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetTopicError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetTopicError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetTopicError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetTopicError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetTopicError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetTopicError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetTopicError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetTopicError::Unknown(ref s) => write!(f, "{}", s),
+            SetTopicError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for SetTopicError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            SetTopicError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            SetTopicError::NotInChannel => {
-                "not_in_channel: Authenticated user is not in the channel."
-            }
-            SetTopicError::IsArchived => "is_archived: Channel has been archived.",
-            SetTopicError::TooLong => "too_long: Topic was longer than 250 characters.",
-            SetTopicError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            SetTopicError::NotAuthed => "not_authed: No authentication token provided.",
-            SetTopicError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetTopicError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetTopicError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetTopicError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetTopicError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetTopicError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetTopicError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetTopicError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetTopicError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetTopicError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetTopicError::MalformedResponse(ref e) => e.description(),
-            SetTopicError::Unknown(ref s) => s,
-            SetTopicError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for SetTopicError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SetTopicError::MalformedResponse(ref e) => Some(e),
             SetTopicError::Client(ref inner) => Some(inner),
@@ -2692,10 +3242,35 @@ impl<E: Error> Error for SetTopicError<E> {
     }
 }
 
+impl<E: Error> From<SetTopicError<E>> for crate::SlackError<E> {
+    fn from(err: SetTopicError<E>) -> Self {
+        match err {
+            SetTopicError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            SetTopicError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            SetTopicError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            SetTopicError::TooLong => crate::SlackError::Api("too_long".to_owned()),
+            SetTopicError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            SetTopicError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetTopicError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetTopicError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetTopicError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetTopicError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetTopicError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetTopicError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetTopicError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetTopicError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetTopicError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetTopicError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetTopicError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetTopicError::Unknown(s) => crate::SlackError::Api(s),
+            SetTopicError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Unarchives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.unarchive
-
 pub fn unarchive<R>(
     client: &R,
     token: &str,
@@ -2704,41 +3279,76 @@ pub fn unarchive<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("channels.unarchive");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "channels.unarchive");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(UnarchiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result).map_err(
+            serde_json::from_str::<UnarchiveResponse>(&result.body).map_err(
                 UnarchiveError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct UnarchiveRequest<'a> {
     /// Channel to unarchive
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+}
+
+impl<'a> UnarchiveRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> UnarchiveRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`UnarchiveRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct UnarchiveRequestBuilder {
+    channel: Option<crate::ChannelId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl UnarchiveRequestBuilder {
+    /// Channel to unarchive
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<UnarchiveRequest<'_>, crate::MissingField> {
+        Ok(UnarchiveRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnarchiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
-    fn into(self) -> Result<UnarchiveResponse, UnarchiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UnarchiveResponse> for Result<UnarchiveResponse, UnarchiveError<E>> {
+    fn from(response: UnarchiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -2807,57 +3417,31 @@ impl<'a, E: Error> From<&'a str> for UnarchiveError<E> {
 
 impl<E: Error> fmt::Display for UnarchiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            UnarchiveError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            UnarchiveError::NotArchived => write!(f, "not_archived: Channel is not archived."),
+            UnarchiveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            UnarchiveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            UnarchiveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            UnarchiveError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            UnarchiveError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            UnarchiveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            UnarchiveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            UnarchiveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            UnarchiveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            UnarchiveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            UnarchiveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            UnarchiveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            UnarchiveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            UnarchiveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            UnarchiveError::Unknown(ref s) => write!(f, "{}", s),
+            UnarchiveError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for UnarchiveError<E> {
-    fn description(&self) -> &str {
-        match *self {
-            UnarchiveError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            UnarchiveError::NotArchived => "not_archived: Channel is not archived.",
-            UnarchiveError::NotAuthed => "not_authed: No authentication token provided.",
-            UnarchiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            UnarchiveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            UnarchiveError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            UnarchiveError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            UnarchiveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            UnarchiveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            UnarchiveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            UnarchiveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            UnarchiveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            UnarchiveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            UnarchiveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            UnarchiveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            UnarchiveError::MalformedResponse(ref e) => e.description(),
-            UnarchiveError::Unknown(ref s) => s,
-            UnarchiveError::Client(ref inner) => inner.description(),
-        }
-    }
-
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for UnarchiveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             UnarchiveError::MalformedResponse(ref e) => Some(e),
             UnarchiveError::Client(ref inner) => Some(inner),
@@ -2865,3 +3449,170 @@ impl<E: Error> Error for UnarchiveError<E> {
         }
     }
 }
+
+impl<E: Error> From<UnarchiveError<E>> for crate::SlackError<E> {
+    fn from(err: UnarchiveError<E>) -> Self {
+        match err {
+            UnarchiveError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            UnarchiveError::NotArchived => crate::SlackError::Api("not_archived".to_owned()),
+            UnarchiveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            UnarchiveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            UnarchiveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            UnarchiveError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            UnarchiveError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            UnarchiveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            UnarchiveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            UnarchiveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            UnarchiveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            UnarchiveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            UnarchiveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            UnarchiveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            UnarchiveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            UnarchiveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            UnarchiveError::Unknown(s) => crate::SlackError::Api(s),
+            UnarchiveError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`channels`](channels/index.html) module.
+    pub fn channels(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Archives a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.archive
+    pub fn archive(
+        &self,
+        request: &ArchiveRequest,
+    ) -> Result<ArchiveResponse, ArchiveError<S::Error>> {
+        archive(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Creates a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.create
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<S::Error>> {
+        create(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Fetches history of messages and events from a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.history
+    pub fn history(
+        &self,
+        request: &HistoryRequest,
+    ) -> Result<HistoryResponse, HistoryError<S::Error>> {
+        history(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets information about a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Invites a user to a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.invite
+    pub fn invite(&self, request: &InviteRequest) -> Result<InviteResponse, InviteError<S::Error>> {
+        invite(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Joins a channel, creating it if needed.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.join
+    pub fn join(&self, request: &JoinRequest) -> Result<JoinResponse, JoinError<S::Error>> {
+        join(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Removes a user from a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.kick
+    pub fn kick(&self, request: &KickRequest) -> Result<KickResponse, KickError<S::Error>> {
+        kick(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Leaves a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.leave
+    pub fn leave(&self, request: &LeaveRequest) -> Result<LeaveResponse, LeaveError<S::Error>> {
+        leave(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists all channels in a Slack team.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Sets the read cursor in a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.mark
+    pub fn mark(&self, request: &MarkRequest) -> Result<MarkResponse, MarkError<S::Error>> {
+        mark(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Renames a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.rename
+    pub fn rename(&self, request: &RenameRequest) -> Result<RenameResponse, RenameError<S::Error>> {
+        rename(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Retrieve a thread of messages posted to a channel
+    ///
+    /// Wraps https://api.slack.com/methods/channels.replies
+    pub fn replies(
+        &self,
+        request: &RepliesRequest,
+    ) -> Result<RepliesResponse, RepliesError<S::Error>> {
+        replies(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Sets the purpose for a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.setPurpose
+    pub fn set_purpose(
+        &self,
+        request: &SetPurposeRequest,
+    ) -> Result<SetPurposeResponse, SetPurposeError<S::Error>> {
+        set_purpose(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Sets the topic for a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.setTopic
+    pub fn set_topic(
+        &self,
+        request: &SetTopicRequest,
+    ) -> Result<SetTopicResponse, SetTopicError<S::Error>> {
+        set_topic(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Unarchives a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/channels.unarchive
+    pub fn unarchive(
+        &self,
+        request: &UnarchiveRequest,
+    ) -> Result<UnarchiveResponse, UnarchiveError<S::Error>> {
+        unarchive(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Deletes a message.
 ///
 /// Wraps https://api.slack.com/methods/chat.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -23,52 +21,104 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
+        Some(("ts", request.ts.as_str())),
+        Some(("channel", request.channel.as_str())),
         request.as_user.map(|as_user| {
             ("as_user", if as_user { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.delete");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.delete");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result).map_err(DeleteError::MalformedResponse)
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct DeleteRequest<'a> {
     /// Timestamp of the message to be deleted.
-    pub ts: &'a str,
+    pub ts: &'a crate::Ts,
     /// Channel containing the message to be deleted.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DeleteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> DeleteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`DeleteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequestBuilder {
+    ts: Option<crate::Ts>,
+    channel: Option<crate::ChannelId>,
+    as_user: Option<bool>,
+}
+
+impl DeleteRequestBuilder {
+    /// Timestamp of the message to be deleted.
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Channel containing the message to be deleted.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Pass true to delete the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<DeleteRequest<'_>, crate::MissingField> {
+        Ok(DeleteRequest {
+            ts: self.ts.as_ref().ok_or(crate::MissingField("ts"))?,
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            as_user: self.as_user,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -137,61 +187,31 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DeleteError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DeleteError::MessageNotFound => {
-                "message_not_found: No message exists with the requested timestamp."
-            }
-            DeleteError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            DeleteError::CantDeleteMessage => {
-                "cant_delete_message: Authenticated user does not have permission to delete this message."
-            }
-            DeleteError::ComplianceExportsPreventDeletion => {
-                "compliance_exports_prevent_deletion: Compliance exports are on, messages can not be deleted"
-            }
-            DeleteError::NotAuthed => "not_authed: No authentication token provided.",
-            DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DeleteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DeleteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DeleteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DeleteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DeleteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DeleteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DeleteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DeleteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DeleteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DeleteError::MalformedResponse(ref e) => e.description(),
-            DeleteError::Unknown(ref s) => s,
-            DeleteError::Client(ref inner) => inner.description(),
+            DeleteError::MessageNotFound => write!(f, "message_not_found: No message exists with the requested timestamp."),
+            DeleteError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            DeleteError::CantDeleteMessage => write!(f, "cant_delete_message: Authenticated user does not have permission to delete this message."),
+            DeleteError::ComplianceExportsPreventDeletion => write!(f, "compliance_exports_prevent_deletion: Compliance exports are on, messages can not be deleted"),
+            DeleteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DeleteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DeleteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DeleteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DeleteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DeleteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DeleteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DeleteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DeleteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DeleteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DeleteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DeleteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DeleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DeleteError::MalformedResponse(ref e) => Some(e),
             DeleteError::Client(ref inner) => Some(inner),
@@ -200,10 +220,34 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        match err {
+            DeleteError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            DeleteError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            DeleteError::CantDeleteMessage => crate::SlackError::Api("cant_delete_message".to_owned()),
+            DeleteError::ComplianceExportsPreventDeletion => crate::SlackError::Api("compliance_exports_prevent_deletion".to_owned()),
+            DeleteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DeleteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DeleteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DeleteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DeleteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DeleteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DeleteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DeleteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DeleteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DeleteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DeleteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DeleteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DeleteError::Unknown(s) => crate::SlackError::Api(s),
+            DeleteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Share a me message into a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.meMessage
-
 pub fn me_message<R>(
     client: &R,
     token: &str,
@@ -212,49 +256,93 @@ pub fn me_message<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
+        Some(("channel", request.channel.as_str())),
         Some(("text", request.text)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.meMessage");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.meMessage");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(MeMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MeMessageResponse>(&result).map_err(
+            serde_json::from_str::<MeMessageResponse>(&result.body).map_err(
                 MeMessageError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct MeMessageRequest<'a> {
-    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
-    pub channel: &'a str,
+    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an ID, or a name such as `#general`.
+    pub channel: &'a crate::ChannelRef,
     /// Text of the message to send.
     pub text: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> MeMessageRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> MeMessageRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`MeMessageRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct MeMessageRequestBuilder {
+    channel: Option<crate::ChannelRef>,
+    text: Option<String>,
+}
+
+impl MeMessageRequestBuilder {
+    /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an ID, or a name such as `#general`.
+    pub fn channel(mut self, channel: crate::ChannelRef) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Text of the message to send.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<MeMessageRequest<'_>, crate::MissingField> {
+        Ok(MeMessageRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            text: self.text.as_deref().ok_or(crate::MissingField("text"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MeMessageResponse {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageResponse {
-    fn into(self) -> Result<MeMessageResponse, MeMessageError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MeMessageResponse> for Result<MeMessageResponse, MeMessageError<E>> {
+    fn from(response: MeMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -329,61 +417,33 @@ impl<'a, E: Error> From<&'a str> for MeMessageError<E> {
 
 impl<E: Error> fmt::Display for MeMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for MeMessageError<E> {
-    fn description(&self) -> &str {
         match *self {
-            MeMessageError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            MeMessageError::NotInChannel => {
-                "not_in_channel: Cannot post user messages to a channel they are not in."
-            }
-            MeMessageError::IsArchived => "is_archived: Channel has been archived.",
-            MeMessageError::MsgTooLong => "msg_too_long: Message text is too long",
-            MeMessageError::NoText => "no_text: No message text provided",
-            MeMessageError::RateLimited => {
-                "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"
-            }
-            MeMessageError::NotAuthed => "not_authed: No authentication token provided.",
-            MeMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            MeMessageError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            MeMessageError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            MeMessageError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            MeMessageError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            MeMessageError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            MeMessageError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            MeMessageError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            MeMessageError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            MeMessageError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            MeMessageError::MalformedResponse(ref e) => e.description(),
-            MeMessageError::Unknown(ref s) => s,
-            MeMessageError::Client(ref inner) => inner.description(),
+            MeMessageError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            MeMessageError::NotInChannel => write!(f, "not_in_channel: Cannot post user messages to a channel they are not in."),
+            MeMessageError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            MeMessageError::MsgTooLong => write!(f, "msg_too_long: Message text is too long"),
+            MeMessageError::NoText => write!(f, "no_text: No message text provided"),
+            MeMessageError::RateLimited => write!(f, "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"),
+            MeMessageError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            MeMessageError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            MeMessageError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            MeMessageError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            MeMessageError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            MeMessageError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            MeMessageError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            MeMessageError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            MeMessageError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            MeMessageError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            MeMessageError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            MeMessageError::MalformedResponse(ref e) => write!(f, "{}", e),
+            MeMessageError::Unknown(ref s) => write!(f, "{}", s),
+            MeMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for MeMessageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             MeMessageError::MalformedResponse(ref e) => Some(e),
             MeMessageError::Client(ref inner) => Some(inner),
@@ -392,10 +452,36 @@ impl<E: Error> Error for MeMessageError<E> {
     }
 }
 
+impl<E: Error> From<MeMessageError<E>> for crate::SlackError<E> {
+    fn from(err: MeMessageError<E>) -> Self {
+        match err {
+            MeMessageError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            MeMessageError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            MeMessageError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            MeMessageError::MsgTooLong => crate::SlackError::Api("msg_too_long".to_owned()),
+            MeMessageError::NoText => crate::SlackError::Api("no_text".to_owned()),
+            MeMessageError::RateLimited => crate::SlackError::Api("rate_limited".to_owned()),
+            MeMessageError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            MeMessageError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            MeMessageError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            MeMessageError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            MeMessageError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            MeMessageError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            MeMessageError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            MeMessageError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            MeMessageError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            MeMessageError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            MeMessageError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            MeMessageError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            MeMessageError::Unknown(s) => crate::SlackError::Api(s),
+            MeMessageError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Sends a message to a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.postMessage
-
 pub fn post_message<R>(
     client: &R,
     token: &str,
@@ -404,61 +490,58 @@ pub fn post_message<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.parse.map(|parse| ("parse", parse)),
-        request.link_names.map(|link_names| {
-            ("link_names", if link_names { "1" } else { "0" })
-        }),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
-        }),
-        request.unfurl_links.map(|unfurl_links| {
-            ("unfurl_links", if unfurl_links { "1" } else { "0" })
-        }),
-        request.unfurl_media.map(|unfurl_media| {
-            ("unfurl_media", if unfurl_media { "1" } else { "0" })
-        }),
-        request.username.map(|username| ("username", username)),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
-        request.icon_url.map(|icon_url| ("icon_url", icon_url)),
-        request.icon_emoji.map(
-            |icon_emoji| ("icon_emoji", icon_emoji)
-        ),
-        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts)),
-        request.reply_broadcast.map(|reply_broadcast| {
-            ("reply_broadcast", if reply_broadcast { "1" } else { "0" })
-        }),
+    if request
+        .attachments
+        .is_some_and(|attachments| attachments.len() > crate::attachments::MAX_ATTACHMENTS)
+    {
+        return Err(PostMessageError::TooManyAttachments);
+    }
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("channel", request.channel.as_str().into())),
+        request.text.map(|text| ("text", text.into())),
+        request.parse.map(|parse| ("parse", parse.into())),
+        request.link_names.map(|link_names| ("link_names", link_names.into())),
+        request.attachments.map(|attachments| ("attachments", crate::json_value(attachments))),
+        request.blocks.map(|blocks| ("blocks", crate::json_value(blocks))),
+        request.unfurl_links.map(|unfurl_links| ("unfurl_links", unfurl_links.into())),
+        request.unfurl_media.map(|unfurl_media| ("unfurl_media", unfurl_media.into())),
+        request.username.map(|username| ("username", username.into())),
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
+        request.icon_url.map(|icon_url| ("icon_url", icon_url.into())),
+        request.icon_emoji.map(|icon_emoji| ("icon_emoji", icon_emoji.into())),
+        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts.as_str().into())),
+        request.reply_broadcast.map(|reply_broadcast| ("reply_broadcast", reply_broadcast.into())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.postMessage");
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.postMessage");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
         .map_err(PostMessageError::Client)
         .and_then(|result| {
-            serde_json::from_str::<PostMessageResponse>(&result)
+            serde_json::from_str::<PostMessageResponse>(&result.body)
                 .map_err(PostMessageError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct PostMessageRequest<'a> {
-    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
-    pub channel: &'a str,
-    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.
-    pub text: &'a str,
+    /// Channel, private group, or IM channel to send message to. Can be an ID, or a name such as `#general`. See below for more details.
+    pub channel: &'a crate::ChannelRef,
+    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments or blocks instead.
+    pub text: Option<&'a str>,
     /// Change how messages are treated. Defaults to none. See below.
     pub parse: Option<&'a str>,
     /// Find and link channel names and usernames.
     pub link_names: Option<bool>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<&'a [crate::attachments::Attachment]>,
+    /// Layout blocks of the message.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
@@ -472,28 +555,167 @@ pub struct PostMessageRequest<'a> {
     /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
     pub icon_emoji: Option<&'a str>,
     /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
-    pub thread_ts: Option<&'a str>,
+    pub thread_ts: Option<&'a crate::Ts>,
     /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
     pub reply_broadcast: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> PostMessageRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> PostMessageRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`PostMessageRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct PostMessageRequestBuilder {
+    channel: Option<crate::ChannelRef>,
+    text: Option<String>,
+    parse: Option<String>,
+    link_names: Option<bool>,
+    attachments: Option<Vec<crate::attachments::Attachment>>,
+    blocks: Option<Vec<crate::blocks::Block>>,
+    unfurl_links: Option<bool>,
+    unfurl_media: Option<bool>,
+    username: Option<String>,
+    as_user: Option<bool>,
+    icon_url: Option<String>,
+    icon_emoji: Option<String>,
+    thread_ts: Option<crate::Ts>,
+    reply_broadcast: Option<bool>,
+}
+
+impl PostMessageRequestBuilder {
+    /// Channel, private group, or IM channel to send message to. Can be an ID, or a name such as `#general`. See below for more details.
+    pub fn channel(mut self, channel: crate::ChannelRef) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments or blocks instead.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Change how messages are treated. Defaults to none. See below.
+    pub fn parse<S: Into<String>>(mut self, parse: S) -> Self {
+        self.parse = Some(parse.into());
+        self
+    }
+
+    /// Find and link channel names and usernames.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(mut self, attachments: Vec<crate::attachments::Attachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Layout blocks of the message.
+    pub fn blocks(mut self, blocks: Vec<crate::blocks::Block>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub fn unfurl_links(mut self, unfurl_links: bool) -> Self {
+        self.unfurl_links = Some(unfurl_links);
+        self
+    }
+
+    /// Pass false to disable unfurling of media content.
+    pub fn unfurl_media(mut self, unfurl_media: bool) -> Self {
+        self.unfurl_media = Some(unfurl_media);
+        self
+    }
+
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn username<S: Into<String>>(mut self, username: S) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_url<S: Into<String>>(mut self, icon_url: S) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self
+    }
+
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.
+    pub fn icon_emoji<S: Into<String>>(mut self, icon_emoji: S) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self
+    }
+
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub fn thread_ts(mut self, thread_ts: crate::Ts) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub fn reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.reply_broadcast = Some(reply_broadcast);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<PostMessageRequest<'_>, crate::MissingField> {
+        Ok(PostMessageRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            text: self.text.as_deref(),
+            parse: self.parse.as_deref(),
+            link_names: self.link_names,
+            attachments: self.attachments.as_deref(),
+            blocks: self.blocks.as_deref(),
+            unfurl_links: self.unfurl_links,
+            unfurl_media: self.unfurl_media,
+            username: self.username.as_deref(),
+            as_user: self.as_user,
+            icon_url: self.icon_url.as_deref(),
+            icon_emoji: self.icon_emoji.as_deref(),
+            thread_ts: self.thread_ts.as_ref(),
+            reply_broadcast: self.reply_broadcast,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PostMessageResponse {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub message: Option<::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<crate::Message>,
     #[serde(default)]
     ok: bool,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMessageResponse {
-    fn into(self) -> Result<PostMessageResponse, PostMessageError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<PostMessageResponse> for Result<PostMessageResponse, PostMessageError<E>> {
+    fn from(response: PostMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -571,64 +793,34 @@ impl<'a, E: Error> From<&'a str> for PostMessageError<E> {
 
 impl<E: Error> fmt::Display for PostMessageError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for PostMessageError<E> {
-    fn description(&self) -> &str {
         match *self {
-            PostMessageError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            PostMessageError::NotInChannel => {
-                "not_in_channel: Cannot post user messages to a channel they are not in."
-            }
-            PostMessageError::IsArchived => "is_archived: Channel has been archived.",
-            PostMessageError::MsgTooLong => "msg_too_long: Message text is too long",
-            PostMessageError::NoText => "no_text: No message text provided",
-            PostMessageError::TooManyAttachments => {
-                "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
-            }
-            PostMessageError::RateLimited => {
-                "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"
-            }
-            PostMessageError::NotAuthed => "not_authed: No authentication token provided.",
-            PostMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            PostMessageError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            PostMessageError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            PostMessageError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            PostMessageError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            PostMessageError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            PostMessageError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            PostMessageError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            PostMessageError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            PostMessageError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            PostMessageError::MalformedResponse(ref e) => e.description(),
-            PostMessageError::Unknown(ref s) => s,
-            PostMessageError::Client(ref inner) => inner.description(),
+            PostMessageError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            PostMessageError::NotInChannel => write!(f, "not_in_channel: Cannot post user messages to a channel they are not in."),
+            PostMessageError::IsArchived => write!(f, "is_archived: Channel has been archived."),
+            PostMessageError::MsgTooLong => write!(f, "msg_too_long: Message text is too long"),
+            PostMessageError::NoText => write!(f, "no_text: No message text provided"),
+            PostMessageError::TooManyAttachments => write!(f, "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."),
+            PostMessageError::RateLimited => write!(f, "rate_limited: Application has posted too many messages, read the Rate Limit documentation for more information"),
+            PostMessageError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            PostMessageError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            PostMessageError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            PostMessageError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            PostMessageError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            PostMessageError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            PostMessageError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            PostMessageError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            PostMessageError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            PostMessageError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            PostMessageError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            PostMessageError::MalformedResponse(ref e) => write!(f, "{}", e),
+            PostMessageError::Unknown(ref s) => write!(f, "{}", s),
+            PostMessageError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for PostMessageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             PostMessageError::MalformedResponse(ref e) => Some(e),
             PostMessageError::Client(ref inner) => Some(inner),
@@ -637,10 +829,37 @@ impl<E: Error> Error for PostMessageError<E> {
     }
 }
 
+impl<E: Error> From<PostMessageError<E>> for crate::SlackError<E> {
+    fn from(err: PostMessageError<E>) -> Self {
+        match err {
+            PostMessageError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            PostMessageError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            PostMessageError::IsArchived => crate::SlackError::Api("is_archived".to_owned()),
+            PostMessageError::MsgTooLong => crate::SlackError::Api("msg_too_long".to_owned()),
+            PostMessageError::NoText => crate::SlackError::Api("no_text".to_owned()),
+            PostMessageError::TooManyAttachments => crate::SlackError::Api("too_many_attachments".to_owned()),
+            PostMessageError::RateLimited => crate::SlackError::Api("rate_limited".to_owned()),
+            PostMessageError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            PostMessageError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            PostMessageError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            PostMessageError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            PostMessageError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            PostMessageError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            PostMessageError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            PostMessageError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            PostMessageError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            PostMessageError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            PostMessageError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            PostMessageError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            PostMessageError::Unknown(s) => crate::SlackError::Api(s),
+            PostMessageError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Unfurl a URL that a user posted
 ///
 /// Wraps https://api.slack.com/methods/chat.unfurl
-
 pub fn unfurl<R>(
     client: &R,
     token: &str,
@@ -649,56 +868,109 @@ pub fn unfurl<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
-        Some(("unfurls", request.unfurls)),
-        request.user_auth_required.map(|user_auth_required| {
-            (
-                "user_auth_required",
-                if user_auth_required { "1" } else { "0" },
-            )
-        }),
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("channel", request.channel.as_str().into())),
+        Some(("ts", request.ts.as_str().into())),
+        Some(("unfurls", crate::json_value(request.unfurls))),
+        request.user_auth_required.map(|user_auth_required| ("user_auth_required", user_auth_required.into())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.unfurl");
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.unfurl");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
         .map_err(UnfurlError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UnfurlResponse>(&result).map_err(UnfurlError::MalformedResponse)
+            serde_json::from_str::<UnfurlResponse>(&result.body).map_err(UnfurlError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct UnfurlRequest<'a> {
     /// Channel ID of the message
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// Timestamp of the message to add unfurl behavior to
-    pub ts: &'a str,
+    pub ts: &'a crate::Ts,
     /// JSON mapping a set of URLs from the message to their unfurl attachments
-    pub unfurls: &'a str,
+    pub unfurls: &'a crate::attachments::Unfurls,
     /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
     pub user_auth_required: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UnfurlRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> UnfurlRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`UnfurlRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct UnfurlRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    ts: Option<crate::Ts>,
+    unfurls: Option<crate::attachments::Unfurls>,
+    user_auth_required: Option<bool>,
+}
+
+impl UnfurlRequestBuilder {
+    /// Channel ID of the message
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Timestamp of the message to add unfurl behavior to
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// JSON mapping a set of URLs from the message to their unfurl attachments
+    pub fn unfurls(mut self, unfurls: crate::attachments::Unfurls) -> Self {
+        self.unfurls = Some(unfurls);
+        self
+    }
+
+    /// Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain
+    pub fn user_auth_required(mut self, user_auth_required: bool) -> Self {
+        self.user_auth_required = Some(user_auth_required);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<UnfurlRequest<'_>, crate::MissingField> {
+        Ok(UnfurlRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            ts: self.ts.as_ref().ok_or(crate::MissingField("ts"))?,
+            unfurls: self.unfurls.as_ref().ok_or(crate::MissingField("unfurls"))?,
+            user_auth_required: self.user_auth_required,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UnfurlResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<UnfurlResponse, UnfurlError<E>>> for UnfurlResponse {
-    fn into(self) -> Result<UnfurlResponse, UnfurlError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UnfurlResponse> for Result<UnfurlResponse, UnfurlError<E>> {
+    fn from(response: UnfurlResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -758,50 +1030,28 @@ impl<'a, E: Error> From<&'a str> for UnfurlError<E> {
 
 impl<E: Error> fmt::Display for UnfurlError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for UnfurlError<E> {
-    fn description(&self) -> &str {
         match *self {
-            UnfurlError::NotAuthed => "not_authed: No authentication token provided.",
-            UnfurlError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            UnfurlError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            UnfurlError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            UnfurlError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            UnfurlError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            UnfurlError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            UnfurlError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            UnfurlError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            UnfurlError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            UnfurlError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            UnfurlError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            UnfurlError::MalformedResponse(ref e) => e.description(),
-            UnfurlError::Unknown(ref s) => s,
-            UnfurlError::Client(ref inner) => inner.description(),
+            UnfurlError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            UnfurlError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            UnfurlError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            UnfurlError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            UnfurlError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            UnfurlError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            UnfurlError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            UnfurlError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            UnfurlError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            UnfurlError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            UnfurlError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            UnfurlError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            UnfurlError::MalformedResponse(ref e) => write!(f, "{}", e),
+            UnfurlError::Unknown(ref s) => write!(f, "{}", s),
+            UnfurlError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for UnfurlError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             UnfurlError::MalformedResponse(ref e) => Some(e),
             UnfurlError::Client(ref inner) => Some(inner),
@@ -810,10 +1060,31 @@ impl<E: Error> Error for UnfurlError<E> {
     }
 }
 
+impl<E: Error> From<UnfurlError<E>> for crate::SlackError<E> {
+    fn from(err: UnfurlError<E>) -> Self {
+        match err {
+            UnfurlError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            UnfurlError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            UnfurlError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            UnfurlError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            UnfurlError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            UnfurlError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            UnfurlError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            UnfurlError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            UnfurlError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            UnfurlError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            UnfurlError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            UnfurlError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            UnfurlError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            UnfurlError::Unknown(s) => crate::SlackError::Api(s),
+            UnfurlError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Updates a message.
 ///
 /// Wraps https://api.slack.com/methods/chat.update
-
 pub fn update<R>(
     client: &R,
     token: &str,
@@ -822,44 +1093,49 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![
-        Some(("token", token)),
-        Some(("ts", request.ts)),
-        Some(("channel", request.channel)),
-        Some(("text", request.text)),
-        request.attachments.map(|attachments| {
-            ("attachments", attachments)
-        }),
-        request.parse.map(|parse| ("parse", parse)),
-        request.link_names.map(|link_names| {
-            ("link_names", if link_names { "1" } else { "0" })
-        }),
-        request.as_user.map(|as_user| {
-            ("as_user", if as_user { "1" } else { "0" })
-        }),
+    if request
+        .attachments
+        .is_some_and(|attachments| attachments.len() > crate::attachments::MAX_ATTACHMENTS)
+    {
+        return Err(UpdateError::TooManyAttachments);
+    }
+    let params: Vec<Option<(&str, serde_json::Value)>> = vec![
+        Some(("ts", request.ts.as_str().into())),
+        Some(("channel", request.channel.as_str().into())),
+        request.text.map(|text| ("text", text.into())),
+        request.attachments.map(|attachments| ("attachments", crate::json_value(attachments))),
+        request.blocks.map(|blocks| ("blocks", crate::json_value(blocks))),
+        request.parse.map(|parse| ("parse", parse.into())),
+        request.link_names.map(|link_names| ("link_names", link_names.into())),
+        request.as_user.map(|as_user| ("as_user", as_user.into())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("chat.update");
+    let body = params.into_iter().flatten().collect::<serde_json::Value>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "chat.update");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Json(&body),
+        })
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result).map_err(UpdateError::MalformedResponse)
+            serde_json::from_str::<UpdateResponse>(&result.body).map_err(UpdateError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct UpdateRequest<'a> {
     /// Timestamp of the message to be updated.
-    pub ts: &'a str,
+    pub ts: &'a crate::Ts,
     /// Channel containing the message to be updated.
-    pub channel: &'a str,
-    /// New text for the message, using the default formatting rules.
-    pub text: &'a str,
+    pub channel: &'a crate::ChannelId,
+    /// New text for the message, using the default formatting rules. Can be left out when the message has attachments or blocks.
+    pub text: Option<&'a str>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<&'a [crate::attachments::Attachment]>,
+    /// Layout blocks of the message.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
     /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
     pub parse: Option<&'a str>,
     /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
@@ -868,23 +1144,114 @@ pub struct UpdateRequest<'a> {
     pub as_user: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UpdateRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> UpdateRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`UpdateRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequestBuilder {
+    ts: Option<crate::Ts>,
+    channel: Option<crate::ChannelId>,
+    text: Option<String>,
+    attachments: Option<Vec<crate::attachments::Attachment>>,
+    blocks: Option<Vec<crate::blocks::Block>>,
+    parse: Option<String>,
+    link_names: Option<bool>,
+    as_user: Option<bool>,
+}
+
+impl UpdateRequestBuilder {
+    /// Timestamp of the message to be updated.
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Channel containing the message to be updated.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// New text for the message, using the default formatting rules. Can be left out when the message has attachments or blocks.
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Structured message attachments.
+    pub fn attachments(mut self, attachments: Vec<crate::attachments::Attachment>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Layout blocks of the message.
+    pub fn blocks(mut self, blocks: Vec<crate::blocks::Block>) -> Self {
+        self.blocks = Some(blocks);
+        self
+    }
+
+    /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
+    pub fn parse<S: Into<String>>(mut self, parse: S) -> Self {
+        self.parse = Some(parse.into());
+        self
+    }
+
+    /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
+    pub fn link_names(mut self, link_names: bool) -> Self {
+        self.link_names = Some(link_names);
+        self
+    }
+
+    /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
+    pub fn as_user(mut self, as_user: bool) -> Self {
+        self.as_user = Some(as_user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<UpdateRequest<'_>, crate::MissingField> {
+        Ok(UpdateRequest {
+            ts: self.ts.as_ref().ok_or(crate::MissingField("ts"))?,
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            text: self.text.as_deref(),
+            attachments: self.attachments.as_deref(),
+            blocks: self.blocks.as_deref(),
+            parse: self.parse.as_deref(),
+            link_names: self.link_names,
+            as_user: self.as_user,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
-    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -962,66 +1329,34 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
 
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for UpdateError<E> {
-    fn description(&self) -> &str {
         match *self {
-            UpdateError::MessageNotFound => {
-                "message_not_found: No message exists with the requested timestamp."
-            }
-            UpdateError::CantUpdateMessage => {
-                "cant_update_message: Authenticated user does not have permission to update this message."
-            }
-            UpdateError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            UpdateError::EditWindowClosed => {
-                "edit_window_closed: The message cannot be edited due to the team message edit settings"
-            }
-            UpdateError::MsgTooLong => "msg_too_long: Message text is too long",
-            UpdateError::TooManyAttachments => {
-                "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."
-            }
-            UpdateError::NoText => "no_text: No message text provided",
-            UpdateError::NotAuthed => "not_authed: No authentication token provided.",
-            UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            UpdateError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            UpdateError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            UpdateError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            UpdateError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            UpdateError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            UpdateError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            UpdateError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            UpdateError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            UpdateError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            UpdateError::MalformedResponse(ref e) => e.description(),
-            UpdateError::Unknown(ref s) => s,
-            UpdateError::Client(ref inner) => inner.description(),
+            UpdateError::MessageNotFound => write!(f, "message_not_found: No message exists with the requested timestamp."),
+            UpdateError::CantUpdateMessage => write!(f, "cant_update_message: Authenticated user does not have permission to update this message."),
+            UpdateError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            UpdateError::EditWindowClosed => write!(f, "edit_window_closed: The message cannot be edited due to the team message edit settings"),
+            UpdateError::MsgTooLong => write!(f, "msg_too_long: Message text is too long"),
+            UpdateError::TooManyAttachments => write!(f, "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message."),
+            UpdateError::NoText => write!(f, "no_text: No message text provided"),
+            UpdateError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            UpdateError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            UpdateError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            UpdateError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            UpdateError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            UpdateError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            UpdateError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            UpdateError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            UpdateError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            UpdateError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            UpdateError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            UpdateError::MalformedResponse(ref e) => write!(f, "{}", e),
+            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for UpdateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             UpdateError::MalformedResponse(ref e) => Some(e),
             UpdateError::Client(ref inner) => Some(inner),
@@ -1029,3 +1364,91 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        match err {
+            UpdateError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            UpdateError::CantUpdateMessage => crate::SlackError::Api("cant_update_message".to_owned()),
+            UpdateError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            UpdateError::EditWindowClosed => crate::SlackError::Api("edit_window_closed".to_owned()),
+            UpdateError::MsgTooLong => crate::SlackError::Api("msg_too_long".to_owned()),
+            UpdateError::TooManyAttachments => crate::SlackError::Api("too_many_attachments".to_owned()),
+            UpdateError::NoText => crate::SlackError::Api("no_text".to_owned()),
+            UpdateError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            UpdateError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            UpdateError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            UpdateError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            UpdateError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            UpdateError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            UpdateError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            UpdateError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            UpdateError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            UpdateError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            UpdateError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            UpdateError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            UpdateError::Unknown(s) => crate::SlackError::Api(s),
+            UpdateError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`chat`](chat/index.html) module.
+    pub fn chat(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Deletes a message.
+    ///
+    /// Wraps https://api.slack.com/methods/chat.delete
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<S::Error>> {
+        delete(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Share a me message into a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/chat.meMessage
+    pub fn me_message(
+        &self,
+        request: &MeMessageRequest,
+    ) -> Result<MeMessageResponse, MeMessageError<S::Error>> {
+        me_message(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Sends a message to a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/chat.postMessage
+    pub fn post_message(
+        &self,
+        request: &PostMessageRequest,
+    ) -> Result<PostMessageResponse, PostMessageError<S::Error>> {
+        post_message(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Unfurl a URL that a user posted
+    ///
+    /// Wraps https://api.slack.com/methods/chat.unfurl
+    pub fn unfurl(&self, request: &UnfurlRequest) -> Result<UnfurlResponse, UnfurlError<S::Error>> {
+        unfurl(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Updates a message.
+    ///
+    /// Wraps https://api.slack.com/methods/chat.update
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<S::Error>> {
+        update(self.slack.sender(), self.slack.token(), request)
+    }
+}
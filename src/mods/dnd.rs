@@ -7,43 +7,48 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Ends the current user's Do Not Disturb session immediately.
 ///
 /// Wraps https://api.slack.com/methods/dnd.endDnd
-
 pub fn end_dnd<R>(client: &R, token: &str) -> Result<EndDndResponse, EndDndError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("dnd.endDnd");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endDnd");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(EndDndError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EndDndResponse>(&result).map_err(EndDndError::MalformedResponse)
+            serde_json::from_str::<EndDndResponse>(&result.body).map_err(EndDndError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndDndResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<EndDndResponse, EndDndError<E>>> for EndDndResponse {
-    fn into(self) -> Result<EndDndResponse, EndDndError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EndDndResponse> for Result<EndDndResponse, EndDndError<E>> {
+    fn from(response: EndDndResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -106,53 +111,29 @@ impl<'a, E: Error> From<&'a str> for EndDndError<E> {
 
 impl<E: Error> fmt::Display for EndDndError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for EndDndError<E> {
-    fn description(&self) -> &str {
         match *self {
-            EndDndError::UnknownError => {
-                "unknown_error: There was a mysterious problem ending the user's Do Not Disturb session"
-            }
-            EndDndError::NotAuthed => "not_authed: No authentication token provided.",
-            EndDndError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            EndDndError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            EndDndError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            EndDndError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            EndDndError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            EndDndError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            EndDndError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            EndDndError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            EndDndError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            EndDndError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            EndDndError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            EndDndError::MalformedResponse(ref e) => e.description(),
-            EndDndError::Unknown(ref s) => s,
-            EndDndError::Client(ref inner) => inner.description(),
+            EndDndError::UnknownError => write!(f, "unknown_error: There was a mysterious problem ending the user's Do Not Disturb session"),
+            EndDndError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            EndDndError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            EndDndError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            EndDndError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            EndDndError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            EndDndError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            EndDndError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            EndDndError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            EndDndError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            EndDndError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            EndDndError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            EndDndError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            EndDndError::MalformedResponse(ref e) => write!(f, "{}", e),
+            EndDndError::Unknown(ref s) => write!(f, "{}", s),
+            EndDndError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for EndDndError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             EndDndError::MalformedResponse(ref e) => Some(e),
             EndDndError::Client(ref inner) => Some(inner),
@@ -161,45 +142,78 @@ impl<E: Error> Error for EndDndError<E> {
     }
 }
 
+impl<E: Error> From<EndDndError<E>> for crate::SlackError<E> {
+    fn from(err: EndDndError<E>) -> Self {
+        match err {
+            EndDndError::UnknownError => crate::SlackError::Api("unknown_error".to_owned()),
+            EndDndError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            EndDndError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            EndDndError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            EndDndError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            EndDndError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            EndDndError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            EndDndError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            EndDndError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            EndDndError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            EndDndError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            EndDndError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            EndDndError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            EndDndError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            EndDndError::Unknown(s) => crate::SlackError::Api(s),
+            EndDndError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Ends the current user's snooze mode immediately.
 ///
 /// Wraps https://api.slack.com/methods/dnd.endSnooze
-
 pub fn end_snooze<R>(client: &R, token: &str) -> Result<EndSnoozeResponse, EndSnoozeError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("dnd.endSnooze");
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.endSnooze");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(EndSnoozeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EndSnoozeResponse>(&result).map_err(
+            serde_json::from_str::<EndSnoozeResponse>(&result.body).map_err(
                 EndSnoozeError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EndSnoozeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_end_ts: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<EndSnoozeResponse, EndSnoozeError<E>>> for EndSnoozeResponse {
-    fn into(self) -> Result<EndSnoozeResponse, EndSnoozeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EndSnoozeResponse> for Result<EndSnoozeResponse, EndSnoozeError<E>> {
+    fn from(response: EndSnoozeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -265,56 +279,30 @@ impl<'a, E: Error> From<&'a str> for EndSnoozeError<E> {
 
 impl<E: Error> fmt::Display for EndSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for EndSnoozeError<E> {
-    fn description(&self) -> &str {
         match *self {
-            EndSnoozeError::SnoozeNotActive => {
-                "snooze_not_active: Snooze is not active for this user and cannot be ended"
-            }
-            EndSnoozeError::SnoozeEndFailed => {
-                "snooze_end_failed: There was a problem setting the user's Do Not Disturb status"
-            }
-            EndSnoozeError::NotAuthed => "not_authed: No authentication token provided.",
-            EndSnoozeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            EndSnoozeError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            EndSnoozeError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            EndSnoozeError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            EndSnoozeError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            EndSnoozeError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            EndSnoozeError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            EndSnoozeError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            EndSnoozeError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            EndSnoozeError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            EndSnoozeError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            EndSnoozeError::MalformedResponse(ref e) => e.description(),
-            EndSnoozeError::Unknown(ref s) => s,
-            EndSnoozeError::Client(ref inner) => inner.description(),
+            EndSnoozeError::SnoozeNotActive => write!(f, "snooze_not_active: Snooze is not active for this user and cannot be ended"),
+            EndSnoozeError::SnoozeEndFailed => write!(f, "snooze_end_failed: There was a problem setting the user's Do Not Disturb status"),
+            EndSnoozeError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            EndSnoozeError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            EndSnoozeError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            EndSnoozeError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            EndSnoozeError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            EndSnoozeError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            EndSnoozeError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            EndSnoozeError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            EndSnoozeError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            EndSnoozeError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            EndSnoozeError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            EndSnoozeError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            EndSnoozeError::MalformedResponse(ref e) => write!(f, "{}", e),
+            EndSnoozeError::Unknown(ref s) => write!(f, "{}", s),
+            EndSnoozeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for EndSnoozeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             EndSnoozeError::MalformedResponse(ref e) => Some(e),
             EndSnoozeError::Client(ref inner) => Some(inner),
@@ -323,10 +311,33 @@ impl<E: Error> Error for EndSnoozeError<E> {
     }
 }
 
+impl<E: Error> From<EndSnoozeError<E>> for crate::SlackError<E> {
+    fn from(err: EndSnoozeError<E>) -> Self {
+        match err {
+            EndSnoozeError::SnoozeNotActive => crate::SlackError::Api("snooze_not_active".to_owned()),
+            EndSnoozeError::SnoozeEndFailed => crate::SlackError::Api("snooze_end_failed".to_owned()),
+            EndSnoozeError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            EndSnoozeError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            EndSnoozeError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            EndSnoozeError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            EndSnoozeError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            EndSnoozeError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            EndSnoozeError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            EndSnoozeError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            EndSnoozeError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            EndSnoozeError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            EndSnoozeError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            EndSnoozeError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            EndSnoozeError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            EndSnoozeError::Unknown(s) => crate::SlackError::Api(s),
+            EndSnoozeError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Retrieves a user's current Do Not Disturb status.
 ///
 /// Wraps https://api.slack.com/methods/dnd.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -335,18 +346,20 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("dnd.info");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -354,29 +367,67 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct InfoRequest<'a> {
     /// User to fetch status for (defaults to current user)
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    user: Option<crate::UserId>,
+}
+
+impl InfoRequestBuilder {
+    /// User to fetch status for (defaults to current user)
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            user: self.user.as_ref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dnd_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_end_ts: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub next_dnd_start_ts: Option<f32>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_endtime: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_remaining: Option<f32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -436,50 +487,28 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -488,10 +517,31 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Turns on Do Not Disturb mode for the current user, or changes its duration.
 ///
 /// Wraps https://api.slack.com/methods/dnd.setSnooze
-
 pub fn set_snooze<R>(
     client: &R,
     token: &str,
@@ -502,16 +552,19 @@ where
 {
     let num_minutes = request.num_minutes.to_string();
     let params = vec![
-        Some(("token", token)),
         Some(("num_minutes", &num_minutes[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("dnd.setSnooze");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.setSnooze");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SetSnoozeError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetSnoozeResponse>(&result).map_err(
+            serde_json::from_str::<SetSnoozeResponse>(&result.body).map_err(
                 SetSnoozeError::MalformedResponse,
             )
         })
@@ -524,23 +577,58 @@ pub struct SetSnoozeRequest {
     pub num_minutes: u32,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl SetSnoozeRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetSnoozeRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetSnoozeRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetSnoozeRequestBuilder {
+    num_minutes: Option<u32>,
+}
+
+impl SetSnoozeRequestBuilder {
+    /// Number of minutes, from now, to snooze until.
+    pub fn num_minutes(mut self, num_minutes: u32) -> Self {
+        self.num_minutes = Some(num_minutes);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetSnoozeRequest, crate::MissingField> {
+        Ok(SetSnoozeRequest {
+            num_minutes: self.num_minutes.ok_or(crate::MissingField("num_minutes"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetSnoozeResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_endtime: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub snooze_remaining: Option<f32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetSnoozeResponse, SetSnoozeError<E>>> for SetSnoozeResponse {
-    fn into(self) -> Result<SetSnoozeResponse, SetSnoozeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetSnoozeResponse> for Result<SetSnoozeResponse, SetSnoozeError<E>> {
+    fn from(response: SetSnoozeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -606,56 +694,30 @@ impl<'a, E: Error> From<&'a str> for SetSnoozeError<E> {
 
 impl<E: Error> fmt::Display for SetSnoozeError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for SetSnoozeError<E> {
-    fn description(&self) -> &str {
         match *self {
-            SetSnoozeError::MissingDuration => {
-                "missing_duration: No value provided for num_minutes"
-            }
-            SetSnoozeError::SnoozeFailed => {
-                "snooze_failed: There was a problem setting the user's Do Not Disturb status"
-            }
-            SetSnoozeError::NotAuthed => "not_authed: No authentication token provided.",
-            SetSnoozeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetSnoozeError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetSnoozeError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            SetSnoozeError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetSnoozeError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetSnoozeError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetSnoozeError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetSnoozeError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetSnoozeError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetSnoozeError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetSnoozeError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetSnoozeError::MalformedResponse(ref e) => e.description(),
-            SetSnoozeError::Unknown(ref s) => s,
-            SetSnoozeError::Client(ref inner) => inner.description(),
+            SetSnoozeError::MissingDuration => write!(f, "missing_duration: No value provided for num_minutes"),
+            SetSnoozeError::SnoozeFailed => write!(f, "snooze_failed: There was a problem setting the user's Do Not Disturb status"),
+            SetSnoozeError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetSnoozeError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetSnoozeError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetSnoozeError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            SetSnoozeError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetSnoozeError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetSnoozeError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetSnoozeError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetSnoozeError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetSnoozeError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetSnoozeError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetSnoozeError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetSnoozeError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetSnoozeError::Unknown(ref s) => write!(f, "{}", s),
+            SetSnoozeError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for SetSnoozeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SetSnoozeError::MalformedResponse(ref e) => Some(e),
             SetSnoozeError::Client(ref inner) => Some(inner),
@@ -664,10 +726,33 @@ impl<E: Error> Error for SetSnoozeError<E> {
     }
 }
 
+impl<E: Error> From<SetSnoozeError<E>> for crate::SlackError<E> {
+    fn from(err: SetSnoozeError<E>) -> Self {
+        match err {
+            SetSnoozeError::MissingDuration => crate::SlackError::Api("missing_duration".to_owned()),
+            SetSnoozeError::SnoozeFailed => crate::SlackError::Api("snooze_failed".to_owned()),
+            SetSnoozeError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetSnoozeError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetSnoozeError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetSnoozeError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            SetSnoozeError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetSnoozeError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetSnoozeError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetSnoozeError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetSnoozeError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetSnoozeError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetSnoozeError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetSnoozeError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetSnoozeError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetSnoozeError::Unknown(s) => crate::SlackError::Api(s),
+            SetSnoozeError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Retrieves the Do Not Disturb status for users on a team.
 ///
 /// Wraps https://api.slack.com/methods/dnd.teamInfo
-
 pub fn team_info<R>(
     client: &R,
     token: &str,
@@ -676,18 +761,20 @@ pub fn team_info<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.users.map(|users| ("users", users)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("dnd.teamInfo");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "dnd.teamInfo");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(TeamInfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<TeamInfoResponse>(&result).map_err(
+            serde_json::from_str::<TeamInfoResponse>(&result.body).map_err(
                 TeamInfoError::MalformedResponse,
             )
         })
@@ -700,21 +787,54 @@ pub struct TeamInfoRequest<'a> {
     pub users: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> TeamInfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> TeamInfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`TeamInfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct TeamInfoRequestBuilder {
+    users: Option<String>,
+}
+
+impl TeamInfoRequestBuilder {
+    /// Comma-separated list of users to fetch Do Not Disturb status for
+    pub fn users<S: Into<String>>(mut self, users: S) -> Self {
+        self.users = Some(users.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<TeamInfoRequest<'_>, crate::MissingField> {
+        Ok(TeamInfoRequest {
+            users: self.users.as_deref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamInfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub users: Option<HashMap<String, bool>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<TeamInfoResponse, TeamInfoError<E>>> for TeamInfoResponse {
-    fn into(self) -> Result<TeamInfoResponse, TeamInfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TeamInfoResponse> for Result<TeamInfoResponse, TeamInfoError<E>> {
+    fn from(response: TeamInfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -771,49 +891,27 @@ impl<'a, E: Error> From<&'a str> for TeamInfoError<E> {
 
 impl<E: Error> fmt::Display for TeamInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for TeamInfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            TeamInfoError::NotAuthed => "not_authed: No authentication token provided.",
-            TeamInfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            TeamInfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            TeamInfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            TeamInfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            TeamInfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            TeamInfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            TeamInfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            TeamInfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            TeamInfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            TeamInfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            TeamInfoError::MalformedResponse(ref e) => e.description(),
-            TeamInfoError::Unknown(ref s) => s,
-            TeamInfoError::Client(ref inner) => inner.description(),
+            TeamInfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            TeamInfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            TeamInfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            TeamInfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            TeamInfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            TeamInfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            TeamInfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            TeamInfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            TeamInfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            TeamInfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            TeamInfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            TeamInfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            TeamInfoError::Unknown(ref s) => write!(f, "{}", s),
+            TeamInfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for TeamInfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             TeamInfoError::MalformedResponse(ref e) => Some(e),
             TeamInfoError::Client(ref inner) => Some(inner),
@@ -821,3 +919,84 @@ impl<E: Error> Error for TeamInfoError<E> {
         }
     }
 }
+
+impl<E: Error> From<TeamInfoError<E>> for crate::SlackError<E> {
+    fn from(err: TeamInfoError<E>) -> Self {
+        match err {
+            TeamInfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            TeamInfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            TeamInfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            TeamInfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            TeamInfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            TeamInfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            TeamInfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            TeamInfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            TeamInfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            TeamInfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            TeamInfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            TeamInfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            TeamInfoError::Unknown(s) => crate::SlackError::Api(s),
+            TeamInfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`dnd`](dnd/index.html) module.
+    pub fn dnd(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Ends the current user's Do Not Disturb session immediately.
+    ///
+    /// Wraps https://api.slack.com/methods/dnd.endDnd
+    pub fn end_dnd(&self) -> Result<EndDndResponse, EndDndError<S::Error>> {
+        end_dnd(self.slack.sender(), self.slack.token())
+    }
+
+    /// Ends the current user's snooze mode immediately.
+    ///
+    /// Wraps https://api.slack.com/methods/dnd.endSnooze
+    pub fn end_snooze(&self) -> Result<EndSnoozeResponse, EndSnoozeError<S::Error>> {
+        end_snooze(self.slack.sender(), self.slack.token())
+    }
+
+    /// Retrieves a user's current Do Not Disturb status.
+    ///
+    /// Wraps https://api.slack.com/methods/dnd.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Turns on Do Not Disturb mode for the current user, or changes its duration.
+    ///
+    /// Wraps https://api.slack.com/methods/dnd.setSnooze
+    pub fn set_snooze(
+        &self,
+        request: &SetSnoozeRequest,
+    ) -> Result<SetSnoozeResponse, SetSnoozeError<S::Error>> {
+        set_snooze(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Retrieves the Do Not Disturb status for users on a team.
+    ///
+    /// Wraps https://api.slack.com/methods/dnd.teamInfo
+    pub fn team_info(
+        &self,
+        request: &TeamInfoRequest,
+    ) -> Result<TeamInfoResponse, TeamInfoError<S::Error>> {
+        team_info(self.slack.sender(), self.slack.token(), request)
+    }
+}
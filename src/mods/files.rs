@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{MultipartFile, RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Deletes a file.
 ///
 /// Wraps https://api.slack.com/methods/files.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -23,39 +21,74 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.delete");
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.delete");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result).map_err(DeleteError::MalformedResponse)
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct DeleteRequest<'a> {
     /// ID of file to delete.
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
+}
+
+impl<'a> DeleteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> DeleteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`DeleteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequestBuilder {
+    file: Option<crate::FileId>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl DeleteRequestBuilder {
+    /// ID of file to delete.
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<DeleteRequest<'_>, crate::MissingField> {
+        Ok(DeleteRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -121,56 +154,30 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DeleteError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DeleteError::FileNotFound => {
-                "file_not_found: The file does not exist, or is not visible to the calling user."
-            }
-            DeleteError::FileDeleted => "file_deleted: The file has already been deleted.",
-            DeleteError::CantDeleteFile => {
-                "cant_delete_file: Authenticated user does not have permission to delete this file."
-            }
-            DeleteError::NotAuthed => "not_authed: No authentication token provided.",
-            DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DeleteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DeleteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DeleteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DeleteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DeleteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DeleteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DeleteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DeleteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DeleteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DeleteError::MalformedResponse(ref e) => e.description(),
-            DeleteError::Unknown(ref s) => s,
-            DeleteError::Client(ref inner) => inner.description(),
+            DeleteError::FileNotFound => write!(f, "file_not_found: The file does not exist, or is not visible to the calling user."),
+            DeleteError::FileDeleted => write!(f, "file_deleted: The file has already been deleted."),
+            DeleteError::CantDeleteFile => write!(f, "cant_delete_file: Authenticated user does not have permission to delete this file."),
+            DeleteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DeleteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DeleteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DeleteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DeleteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DeleteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DeleteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DeleteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DeleteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DeleteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DeleteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DeleteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DeleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DeleteError::MalformedResponse(ref e) => Some(e),
             DeleteError::Client(ref inner) => Some(inner),
@@ -179,10 +186,33 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        match err {
+            DeleteError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            DeleteError::FileDeleted => crate::SlackError::Api("file_deleted".to_owned()),
+            DeleteError::CantDeleteFile => crate::SlackError::Api("cant_delete_file".to_owned()),
+            DeleteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DeleteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DeleteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DeleteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DeleteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DeleteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DeleteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DeleteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DeleteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DeleteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DeleteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DeleteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DeleteError::Unknown(s) => crate::SlackError::Api(s),
+            DeleteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets information about a team file.
 ///
 /// Wraps https://api.slack.com/methods/files.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -194,49 +224,103 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", request.file.as_str())),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.info");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct InfoRequest<'a> {
     /// Specify a file by providing its ID.
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    file: Option<crate::FileId>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl InfoRequestBuilder {
+    /// Specify a file by providing its ID.
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
-    pub comments: Option<Vec<::FileComment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<crate::FileComment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
     #[serde(default)]
     ok: bool,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -299,51 +383,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::FileNotFound => "file_not_found: Value passed for file was invalid",
-            InfoError::FileDeleted => "file_deleted: The requested file has been deleted",
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::FileNotFound => write!(f, "file_not_found: Value passed for file was invalid"),
+            InfoError::FileDeleted => write!(f, "file_deleted: The requested file has been deleted"),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -352,10 +414,32 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            InfoError::FileDeleted => crate::SlackError::Api("file_deleted".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists & filters team files.
 ///
 /// Wraps https://api.slack.com/methods/files.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -369,22 +453,25 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
-        request.channel.map(|channel| ("channel", channel)),
+        request.user.map(|user| ("user", user.as_str())),
+        request.channel.map(|channel| ("channel", channel.as_str())),
         ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
         ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
         request.types.map(|types| ("types", types)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -392,9 +479,9 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Filter files created by a single user.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
     /// Filter files appearing in a specific channel, indicated by its ID.
-    pub channel: Option<&'a str>,
+    pub channel: Option<&'a crate::ChannelId>,
     /// Filter files created after this timestamp (inclusive).
     pub ts_from: Option<u32>,
     /// Filter files created before this timestamp (inclusive).
@@ -419,22 +506,116 @@ pub struct ListRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    user: Option<crate::UserId>,
+    channel: Option<crate::ChannelId>,
+    ts_from: Option<u32>,
+    ts_to: Option<u32>,
+    types: Option<String>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl ListRequestBuilder {
+    /// Filter files created by a single user.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Filter files created after this timestamp (inclusive).
+    pub fn ts_from(mut self, ts_from: u32) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    /// Filter files created before this timestamp (inclusive).
+    pub fn ts_to(mut self, ts_to: u32) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    /// Filter files by type:
+    ///
+    ///
+    /// all - All files
+    /// spaces - Posts
+    /// snippets - Snippets
+    /// images - Image files
+    /// gdocs - Google docs
+    /// zips - Zip files
+    /// pdfs - PDF files
+    ///
+    ///
+    /// You can pass multiple values in the types argument, like types=spaces,snippets.The default value is all, which does not filter the list.
+    pub fn types<S: Into<String>>(mut self, types: S) -> Self {
+        self.types = Some(types.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest<'_>, crate::MissingField> {
+        Ok(ListRequest {
+            user: self.user.as_ref(),
+            channel: self.channel.as_ref(),
+            ts_from: self.ts_from,
+            ts_to: self.ts_to,
+            types: self.types.as_deref(),
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub files: Option<Vec<::File>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<crate::File>>,
     #[serde(default)]
     ok: bool,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -500,52 +681,30 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::UserNotFound => "user_not_found: Value passed for user was invalid",
-            ListError::UnknownType => "unknown_type: Value passed for types was invalid",
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid"),
+            ListError::UnknownType => write!(f, "unknown_type: Value passed for types was invalid"),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -554,10 +713,33 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            ListError::UnknownType => crate::SlackError::Api("unknown_type".to_owned()),
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Revokes public/external sharing access for a file
 ///
 /// Wraps https://api.slack.com/methods/files.revokePublicURL
-
 pub fn revoke_public_url<R>(
     client: &R,
     token: &str,
@@ -566,42 +748,77 @@ pub fn revoke_public_url<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.revokePublicURL");
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.revokePublicURL");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RevokePublicURLError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RevokePublicURLResponse>(&result)
+            serde_json::from_str::<RevokePublicURLResponse>(&result.body)
                 .map_err(RevokePublicURLError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RevokePublicURLRequest<'a> {
     /// File to revoke
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
+}
+
+impl<'a> RevokePublicURLRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RevokePublicURLRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RevokePublicURLRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RevokePublicURLRequestBuilder {
+    file: Option<crate::FileId>,
+}
+
+impl RevokePublicURLRequestBuilder {
+    /// File to revoke
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RevokePublicURLRequest<'_>, crate::MissingField> {
+        Ok(RevokePublicURLRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RevokePublicURLResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RevokePublicURLResponse, RevokePublicURLError<E>>>
-    for RevokePublicURLResponse {
-    fn into(self) -> Result<RevokePublicURLResponse, RevokePublicURLError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RevokePublicURLResponse> for Result<RevokePublicURLResponse, RevokePublicURLError<E>> {
+    fn from(response: RevokePublicURLResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -667,58 +884,30 @@ impl<'a, E: Error> From<&'a str> for RevokePublicURLError<E> {
 
 impl<E: Error> fmt::Display for RevokePublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for RevokePublicURLError<E> {
-    fn description(&self) -> &str {
         match *self {
-            RevokePublicURLError::FileNotFound => {
-                "file_not_found: Value passed for file was invalid"
-            }
-            RevokePublicURLError::NotAuthed => "not_authed: No authentication token provided.",
-            RevokePublicURLError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RevokePublicURLError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RevokePublicURLError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            RevokePublicURLError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            RevokePublicURLError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RevokePublicURLError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RevokePublicURLError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RevokePublicURLError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RevokePublicURLError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RevokePublicURLError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RevokePublicURLError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RevokePublicURLError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RevokePublicURLError::MalformedResponse(ref e) => e.description(),
-            RevokePublicURLError::Unknown(ref s) => s,
-            RevokePublicURLError::Client(ref inner) => inner.description(),
+            RevokePublicURLError::FileNotFound => write!(f, "file_not_found: Value passed for file was invalid"),
+            RevokePublicURLError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RevokePublicURLError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RevokePublicURLError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RevokePublicURLError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            RevokePublicURLError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            RevokePublicURLError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RevokePublicURLError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RevokePublicURLError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RevokePublicURLError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RevokePublicURLError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RevokePublicURLError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RevokePublicURLError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RevokePublicURLError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RevokePublicURLError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RevokePublicURLError::Unknown(ref s) => write!(f, "{}", s),
+            RevokePublicURLError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RevokePublicURLError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RevokePublicURLError::MalformedResponse(ref e) => Some(e),
             RevokePublicURLError::Client(ref inner) => Some(inner),
@@ -727,10 +916,33 @@ impl<E: Error> Error for RevokePublicURLError<E> {
     }
 }
 
+impl<E: Error> From<RevokePublicURLError<E>> for crate::SlackError<E> {
+    fn from(err: RevokePublicURLError<E>) -> Self {
+        match err {
+            RevokePublicURLError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            RevokePublicURLError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RevokePublicURLError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RevokePublicURLError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RevokePublicURLError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            RevokePublicURLError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            RevokePublicURLError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RevokePublicURLError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RevokePublicURLError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RevokePublicURLError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RevokePublicURLError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RevokePublicURLError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RevokePublicURLError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RevokePublicURLError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RevokePublicURLError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RevokePublicURLError::Unknown(s) => crate::SlackError::Api(s),
+            RevokePublicURLError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Enables a file for public/external sharing.
 ///
 /// Wraps https://api.slack.com/methods/files.sharedPublicURL
-
 pub fn shared_public_url<R>(
     client: &R,
     token: &str,
@@ -739,42 +951,77 @@ pub fn shared_public_url<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.sharedPublicURL");
+    let params = vec![Some(("file", request.file.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.sharedPublicURL");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SharedPublicURLError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SharedPublicURLResponse>(&result)
+            serde_json::from_str::<SharedPublicURLResponse>(&result.body)
                 .map_err(SharedPublicURLError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct SharedPublicURLRequest<'a> {
     /// File to share
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> SharedPublicURLRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SharedPublicURLRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SharedPublicURLRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SharedPublicURLRequestBuilder {
+    file: Option<crate::FileId>,
+}
+
+impl SharedPublicURLRequestBuilder {
+    /// File to share
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SharedPublicURLRequest<'_>, crate::MissingField> {
+        Ok(SharedPublicURLRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SharedPublicURLResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SharedPublicURLResponse, SharedPublicURLError<E>>>
-    for SharedPublicURLResponse {
-    fn into(self) -> Result<SharedPublicURLResponse, SharedPublicURLError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SharedPublicURLResponse> for Result<SharedPublicURLResponse, SharedPublicURLError<E>> {
+    fn from(response: SharedPublicURLResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -843,65 +1090,430 @@ impl<'a, E: Error> From<&'a str> for SharedPublicURLError<E> {
 
 impl<E: Error> fmt::Display for SharedPublicURLError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            SharedPublicURLError::FileNotFound => write!(f, "file_not_found: Value passed for file was invalid"),
+            SharedPublicURLError::NotAllowed => write!(f, "not_allowed: Public sharing has been disabled for this team"),
+            SharedPublicURLError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SharedPublicURLError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SharedPublicURLError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SharedPublicURLError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            SharedPublicURLError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            SharedPublicURLError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SharedPublicURLError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SharedPublicURLError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SharedPublicURLError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SharedPublicURLError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SharedPublicURLError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SharedPublicURLError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SharedPublicURLError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SharedPublicURLError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SharedPublicURLError::Unknown(ref s) => write!(f, "{}", s),
+            SharedPublicURLError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for SharedPublicURLError<E> {
-    fn description(&self) -> &str {
+impl<E: Error + 'static> Error for SharedPublicURLError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            SharedPublicURLError::FileNotFound => {
-                "file_not_found: Value passed for file was invalid"
-            }
-            SharedPublicURLError::NotAllowed => {
-                "not_allowed: Public sharing has been disabled for this team"
-            }
-            SharedPublicURLError::NotAuthed => "not_authed: No authentication token provided.",
-            SharedPublicURLError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SharedPublicURLError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SharedPublicURLError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            SharedPublicURLError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            SharedPublicURLError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SharedPublicURLError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SharedPublicURLError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SharedPublicURLError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SharedPublicURLError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SharedPublicURLError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SharedPublicURLError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SharedPublicURLError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SharedPublicURLError::MalformedResponse(ref e) => e.description(),
-            SharedPublicURLError::Unknown(ref s) => s,
-            SharedPublicURLError::Client(ref inner) => inner.description(),
+            SharedPublicURLError::MalformedResponse(ref e) => Some(e),
+            SharedPublicURLError::Client(ref inner) => Some(inner),
+            _ => None,
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error> From<SharedPublicURLError<E>> for crate::SlackError<E> {
+    fn from(err: SharedPublicURLError<E>) -> Self {
+        match err {
+            SharedPublicURLError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            SharedPublicURLError::NotAllowed => crate::SlackError::Api("not_allowed".to_owned()),
+            SharedPublicURLError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SharedPublicURLError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SharedPublicURLError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SharedPublicURLError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            SharedPublicURLError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            SharedPublicURLError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SharedPublicURLError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SharedPublicURLError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SharedPublicURLError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SharedPublicURLError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SharedPublicURLError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SharedPublicURLError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SharedPublicURLError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SharedPublicURLError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SharedPublicURLError::Unknown(s) => crate::SlackError::Api(s),
+            SharedPublicURLError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// Uploads or creates a file.
+///
+/// Wraps https://api.slack.com/methods/files.upload
+pub fn upload<R>(
+    client: &R,
+    token: &str,
+    request: &UploadRequest,
+) -> Result<UploadResponse, UploadError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = vec![
+        request.content.map(|content| ("content", content)),
+        request.filetype.map(|filetype| ("filetype", filetype)),
+        request.filename.map(|filename| ("filename", filename)),
+        request.title.map(|title| ("title", title)),
+        request.initial_comment.map(|initial_comment| ("initial_comment", initial_comment)),
+        request.channels.map(|channels| ("channels", channels)),
+        request.thread_ts.map(|thread_ts| ("thread_ts", thread_ts.as_str())),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.upload");
+    let body = match request.file {
+        Some(file) => RequestBody::Multipart(
+            &params[..],
+            MultipartFile {
+                name: "file",
+                filename: request.filename.unwrap_or("file"),
+                contents: file,
+            },
+        ),
+        None => RequestBody::Form(&params[..]),
+    };
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body,
+        })
+        .map_err(UploadError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<UploadResponse>(&result.body)
+                .map_err(UploadError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UploadRequest<'a> {
+    /// File contents via multipart/form-data. If omitting this parameter, you must submit content.
+    pub file: Option<&'a [u8]>,
+    /// File contents via a POST variable. If omitting this parameter, you must provide a file.
+    pub content: Option<&'a str>,
+    /// A file type identifier.
+    pub filetype: Option<&'a str>,
+    /// Filename of file.
+    pub filename: Option<&'a str>,
+    /// Title of file.
+    pub title: Option<&'a str>,
+    /// Initial comment to add to file.
+    pub initial_comment: Option<&'a str>,
+    /// Comma-separated list of channel names or IDs where the file will be shared.
+    pub channels: Option<&'a str>,
+    /// Provide another message's ts value to upload this file as a reply. Never use a reply's ts value; use its parent instead.
+    pub thread_ts: Option<&'a crate::Ts>,
+}
+
+impl<'a> UploadRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> UploadRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`UploadRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct UploadRequestBuilder {
+    file: Option<Vec<u8>>,
+    content: Option<String>,
+    filetype: Option<String>,
+    filename: Option<String>,
+    title: Option<String>,
+    initial_comment: Option<String>,
+    channels: Option<String>,
+    thread_ts: Option<crate::Ts>,
+}
+
+impl UploadRequestBuilder {
+    /// File contents via multipart/form-data. If omitting this parameter, you must submit content.
+    pub fn file<B: Into<Vec<u8>>>(mut self, file: B) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// File contents via a POST variable. If omitting this parameter, you must provide a file.
+    pub fn content<S: Into<String>>(mut self, content: S) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// A file type identifier.
+    pub fn filetype<S: Into<String>>(mut self, filetype: S) -> Self {
+        self.filetype = Some(filetype.into());
+        self
+    }
+
+    /// Filename of file.
+    pub fn filename<S: Into<String>>(mut self, filename: S) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Title of file.
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Initial comment to add to file.
+    pub fn initial_comment<S: Into<String>>(mut self, initial_comment: S) -> Self {
+        self.initial_comment = Some(initial_comment.into());
+        self
+    }
+
+    /// Comma-separated list of channel names or IDs where the file will be shared.
+    pub fn channels<S: Into<String>>(mut self, channels: S) -> Self {
+        self.channels = Some(channels.into());
+        self
+    }
+
+    /// Provide another message's ts value to upload this file as a reply. Never use a reply's ts value; use its parent instead.
+    pub fn thread_ts(mut self, thread_ts: crate::Ts) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<UploadRequest<'_>, crate::MissingField> {
+        Ok(UploadRequest {
+            file: self.file.as_deref(),
+            content: self.content.as_deref(),
+            filetype: self.filetype.as_deref(),
+            filename: self.filename.as_deref(),
+            title: self.title.as_deref(),
+            initial_comment: self.initial_comment.as_deref(),
+            channels: self.channels.as_deref(),
+            thread_ts: self.thread_ts.as_ref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct UploadResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
+    #[serde(default)]
+    ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+
+impl<E: Error> From<UploadResponse> for Result<UploadResponse, UploadError<E>> {
+    fn from(response: UploadResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UploadError<E: Error> {
+    /// An admin has restricted posting to the #general channel.
+    PostingToGeneralChannelDenied,
+    /// One or more channels supplied are invalid
+    InvalidChannel,
+    /// File uploads are disabled on this team.
+    FileUploadsDisabled,
+    /// File uploads (except images) are disabled on this team.
+    FileUploadsExceptImagesDisabled,
+    /// The team has reached its storage limit.
+    StorageLimitReached,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UploadError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "posting_to_general_channel_denied" => UploadError::PostingToGeneralChannelDenied,
+            "invalid_channel" => UploadError::InvalidChannel,
+            "file_uploads_disabled" => UploadError::FileUploadsDisabled,
+            "file_uploads_except_images_disabled" => UploadError::FileUploadsExceptImagesDisabled,
+            "storage_limit_reached" => UploadError::StorageLimitReached,
+            "not_authed" => UploadError::NotAuthed,
+            "invalid_auth" => UploadError::InvalidAuth,
+            "account_inactive" => UploadError::AccountInactive,
+            "invalid_arg_name" => UploadError::InvalidArgName,
+            "invalid_array_arg" => UploadError::InvalidArrayArg,
+            "invalid_charset" => UploadError::InvalidCharset,
+            "invalid_form_data" => UploadError::InvalidFormData,
+            "invalid_post_type" => UploadError::InvalidPostType,
+            "missing_post_type" => UploadError::MissingPostType,
+            "team_added_to_org" => UploadError::TeamAddedToOrg,
+            "request_timeout" => UploadError::RequestTimeout,
+            _ => UploadError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UploadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            SharedPublicURLError::MalformedResponse(ref e) => Some(e),
-            SharedPublicURLError::Client(ref inner) => Some(inner),
+            UploadError::PostingToGeneralChannelDenied => write!(f, "posting_to_general_channel_denied: An admin has restricted posting to the #general channel."),
+            UploadError::InvalidChannel => write!(f, "invalid_channel: One or more channels supplied are invalid"),
+            UploadError::FileUploadsDisabled => write!(f, "file_uploads_disabled: File uploads are disabled on this team."),
+            UploadError::FileUploadsExceptImagesDisabled => write!(f, "file_uploads_except_images_disabled: File uploads (except images) are disabled on this team."),
+            UploadError::StorageLimitReached => write!(f, "storage_limit_reached: The team has reached its storage limit."),
+            UploadError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            UploadError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            UploadError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            UploadError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            UploadError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            UploadError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            UploadError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            UploadError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            UploadError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            UploadError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            UploadError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            UploadError::MalformedResponse(ref e) => write!(f, "{}", e),
+            UploadError::Unknown(ref s) => write!(f, "{}", s),
+            UploadError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for UploadError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UploadError::MalformedResponse(ref e) => Some(e),
+            UploadError::Client(ref inner) => Some(inner),
             _ => None,
         }
     }
 }
+
+impl<E: Error> From<UploadError<E>> for crate::SlackError<E> {
+    fn from(err: UploadError<E>) -> Self {
+        match err {
+            UploadError::PostingToGeneralChannelDenied => crate::SlackError::Api("posting_to_general_channel_denied".to_owned()),
+            UploadError::InvalidChannel => crate::SlackError::Api("invalid_channel".to_owned()),
+            UploadError::FileUploadsDisabled => crate::SlackError::Api("file_uploads_disabled".to_owned()),
+            UploadError::FileUploadsExceptImagesDisabled => crate::SlackError::Api("file_uploads_except_images_disabled".to_owned()),
+            UploadError::StorageLimitReached => crate::SlackError::Api("storage_limit_reached".to_owned()),
+            UploadError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            UploadError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            UploadError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            UploadError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            UploadError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            UploadError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            UploadError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            UploadError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            UploadError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            UploadError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            UploadError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            UploadError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            UploadError::Unknown(s) => crate::SlackError::Api(s),
+            UploadError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`files`](files/index.html) module.
+    pub fn files(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Deletes a file.
+    ///
+    /// Wraps https://api.slack.com/methods/files.delete
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<S::Error>> {
+        delete(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets information about a team file.
+    ///
+    /// Wraps https://api.slack.com/methods/files.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists & filters team files.
+    ///
+    /// Wraps https://api.slack.com/methods/files.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Revokes public/external sharing access for a file
+    ///
+    /// Wraps https://api.slack.com/methods/files.revokePublicURL
+    pub fn revoke_public_url(
+        &self,
+        request: &RevokePublicURLRequest,
+    ) -> Result<RevokePublicURLResponse, RevokePublicURLError<S::Error>> {
+        revoke_public_url(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Enables a file for public/external sharing.
+    ///
+    /// Wraps https://api.slack.com/methods/files.sharedPublicURL
+    pub fn shared_public_url(
+        &self,
+        request: &SharedPublicURLRequest,
+    ) -> Result<SharedPublicURLResponse, SharedPublicURLError<S::Error>> {
+        shared_public_url(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Uploads or creates a file.
+    ///
+    /// Wraps https://api.slack.com/methods/files.upload
+    pub fn upload(&self, request: &UploadRequest) -> Result<UploadResponse, UploadError<S::Error>> {
+        upload(self.slack.sender(), self.slack.token(), request)
+    }
+}
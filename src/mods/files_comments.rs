@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Add a comment to an existing file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.add
-
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -22,46 +20,89 @@ pub fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", request.file.as_str())),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.comments.add");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.add");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result).map_err(AddError::MalformedResponse)
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct AddRequest<'a> {
     /// File to add a comment to.
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
     /// Text of the comment to add.
     pub comment: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AddRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AddRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`AddRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AddRequestBuilder {
+    file: Option<crate::FileId>,
+    comment: Option<String>,
+}
+
+impl AddRequestBuilder {
+    /// File to add a comment to.
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// Text of the comment to add.
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AddRequest<'_>, crate::MissingField> {
+        Ok(AddRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+            comment: self.comment.as_deref().ok_or(crate::MissingField("comment"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
-    pub comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<crate::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -127,52 +168,30 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AddError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AddError::FileNotFound => "file_not_found: The requested file could not be found.",
-            AddError::FileDeleted => "file_deleted: The requested file was previously deleted.",
-            AddError::NoComment => "no_comment: The comment field was empty.",
-            AddError::NotAuthed => "not_authed: No authentication token provided.",
-            AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AddError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AddError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AddError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AddError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AddError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AddError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AddError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AddError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AddError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AddError::MalformedResponse(ref e) => e.description(),
-            AddError::Unknown(ref s) => s,
-            AddError::Client(ref inner) => inner.description(),
+            AddError::FileNotFound => write!(f, "file_not_found: The requested file could not be found."),
+            AddError::FileDeleted => write!(f, "file_deleted: The requested file was previously deleted."),
+            AddError::NoComment => write!(f, "no_comment: The comment field was empty."),
+            AddError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AddError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AddError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AddError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AddError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AddError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AddError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AddError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AddError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AddError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AddError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AddError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AddError::MalformedResponse(ref e) => Some(e),
             AddError::Client(ref inner) => Some(inner),
@@ -181,10 +200,33 @@ impl<E: Error> Error for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(err: AddError<E>) -> Self {
+        match err {
+            AddError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            AddError::FileDeleted => crate::SlackError::Api("file_deleted".to_owned()),
+            AddError::NoComment => crate::SlackError::Api("no_comment".to_owned()),
+            AddError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AddError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AddError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AddError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AddError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AddError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AddError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AddError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AddError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AddError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AddError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AddError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AddError::Unknown(s) => crate::SlackError::Api(s),
+            AddError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Deletes an existing comment on a file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -193,45 +235,87 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", request.file.as_str())),
         Some(("id", request.id)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.comments.delete");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.delete");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result).map_err(DeleteError::MalformedResponse)
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct DeleteRequest<'a> {
     /// File to delete a comment from.
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
     /// The comment to delete.
     pub id: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DeleteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> DeleteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`DeleteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequestBuilder {
+    file: Option<crate::FileId>,
+    id: Option<String>,
+}
+
+impl DeleteRequestBuilder {
+    /// File to delete a comment from.
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// The comment to delete.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<DeleteRequest<'_>, crate::MissingField> {
+        Ok(DeleteRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+            id: self.id.as_deref().ok_or(crate::MissingField("id"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -297,52 +381,30 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DeleteError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DeleteError::FileNotFound => "file_not_found: The requested file could not be found.",
-            DeleteError::FileDeleted => "file_deleted: The requested file was previously deleted.",
-            DeleteError::CantDelete => "cant_delete: The requested comment could not be deleted.",
-            DeleteError::NotAuthed => "not_authed: No authentication token provided.",
-            DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DeleteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DeleteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DeleteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DeleteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DeleteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DeleteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DeleteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DeleteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DeleteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DeleteError::MalformedResponse(ref e) => e.description(),
-            DeleteError::Unknown(ref s) => s,
-            DeleteError::Client(ref inner) => inner.description(),
+            DeleteError::FileNotFound => write!(f, "file_not_found: The requested file could not be found."),
+            DeleteError::FileDeleted => write!(f, "file_deleted: The requested file was previously deleted."),
+            DeleteError::CantDelete => write!(f, "cant_delete: The requested comment could not be deleted."),
+            DeleteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DeleteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DeleteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DeleteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DeleteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DeleteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DeleteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DeleteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DeleteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DeleteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DeleteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DeleteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DeleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DeleteError::MalformedResponse(ref e) => Some(e),
             DeleteError::Client(ref inner) => Some(inner),
@@ -351,10 +413,33 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        match err {
+            DeleteError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            DeleteError::FileDeleted => crate::SlackError::Api("file_deleted".to_owned()),
+            DeleteError::CantDelete => crate::SlackError::Api("cant_delete".to_owned()),
+            DeleteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DeleteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DeleteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DeleteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DeleteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DeleteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DeleteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DeleteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DeleteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DeleteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DeleteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DeleteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DeleteError::Unknown(s) => crate::SlackError::Api(s),
+            DeleteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Edit an existing file comment.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.edit
-
 pub fn edit<R>(
     client: &R,
     token: &str,
@@ -363,49 +448,100 @@ pub fn edit<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("file", request.file)),
+        Some(("file", request.file.as_str())),
         Some(("id", request.id)),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("files.comments.edit");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "files.comments.edit");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(EditError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EditResponse>(&result).map_err(EditError::MalformedResponse)
+            serde_json::from_str::<EditResponse>(&result.body).map_err(EditError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct EditRequest<'a> {
     /// File containing the comment to edit.
-    pub file: &'a str,
+    pub file: &'a crate::FileId,
     /// The comment to edit.
     pub id: &'a str,
     /// Text of the comment to edit.
     pub comment: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> EditRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> EditRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`EditRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct EditRequestBuilder {
+    file: Option<crate::FileId>,
+    id: Option<String>,
+    comment: Option<String>,
+}
+
+impl EditRequestBuilder {
+    /// File containing the comment to edit.
+    pub fn file(mut self, file: crate::FileId) -> Self {
+        self.file = Some(file);
+        self
+    }
+
+    /// The comment to edit.
+    pub fn id<S: Into<String>>(mut self, id: S) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Text of the comment to edit.
+    pub fn comment<S: Into<String>>(mut self, comment: S) -> Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<EditRequest<'_>, crate::MissingField> {
+        Ok(EditRequest {
+            file: self.file.as_ref().ok_or(crate::MissingField("file"))?,
+            id: self.id.as_deref().ok_or(crate::MissingField("id"))?,
+            comment: self.comment.as_deref().ok_or(crate::MissingField("comment"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EditResponse {
-    pub comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<crate::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<EditResponse, EditError<E>>> for EditResponse {
-    fn into(self) -> Result<EditResponse, EditError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EditResponse> for Result<EditResponse, EditError<E>> {
+    fn from(response: EditResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -477,56 +613,32 @@ impl<'a, E: Error> From<&'a str> for EditError<E> {
 
 impl<E: Error> fmt::Display for EditError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for EditError<E> {
-    fn description(&self) -> &str {
         match *self {
-            EditError::FileNotFound => "file_not_found: The requested file could not be found.",
-            EditError::FileDeleted => "file_deleted: The requested file was previously deleted.",
-            EditError::NoComment => "no_comment: The comment field was empty.",
-            EditError::EditWindowClosed => {
-                "edit_window_closed: The timeframe for editing the comment has expired."
-            }
-            EditError::CantEdit => "cant_edit: The requested file could not be found.",
-            EditError::NotAuthed => "not_authed: No authentication token provided.",
-            EditError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            EditError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            EditError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            EditError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            EditError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            EditError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            EditError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            EditError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            EditError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            EditError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            EditError::MalformedResponse(ref e) => e.description(),
-            EditError::Unknown(ref s) => s,
-            EditError::Client(ref inner) => inner.description(),
+            EditError::FileNotFound => write!(f, "file_not_found: The requested file could not be found."),
+            EditError::FileDeleted => write!(f, "file_deleted: The requested file was previously deleted."),
+            EditError::NoComment => write!(f, "no_comment: The comment field was empty."),
+            EditError::EditWindowClosed => write!(f, "edit_window_closed: The timeframe for editing the comment has expired."),
+            EditError::CantEdit => write!(f, "cant_edit: The requested file could not be found."),
+            EditError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            EditError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            EditError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            EditError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            EditError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            EditError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            EditError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            EditError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            EditError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            EditError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            EditError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            EditError::MalformedResponse(ref e) => write!(f, "{}", e),
+            EditError::Unknown(ref s) => write!(f, "{}", s),
+            EditError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for EditError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             EditError::MalformedResponse(ref e) => Some(e),
             EditError::Client(ref inner) => Some(inner),
@@ -534,3 +646,69 @@ impl<E: Error> Error for EditError<E> {
         }
     }
 }
+
+impl<E: Error> From<EditError<E>> for crate::SlackError<E> {
+    fn from(err: EditError<E>) -> Self {
+        match err {
+            EditError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            EditError::FileDeleted => crate::SlackError::Api("file_deleted".to_owned()),
+            EditError::NoComment => crate::SlackError::Api("no_comment".to_owned()),
+            EditError::EditWindowClosed => crate::SlackError::Api("edit_window_closed".to_owned()),
+            EditError::CantEdit => crate::SlackError::Api("cant_edit".to_owned()),
+            EditError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            EditError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            EditError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            EditError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            EditError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            EditError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            EditError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            EditError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            EditError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            EditError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            EditError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            EditError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            EditError::Unknown(s) => crate::SlackError::Api(s),
+            EditError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`files_comments`](files_comments/index.html) module.
+    pub fn files_comments(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Add a comment to an existing file.
+    ///
+    /// Wraps https://api.slack.com/methods/files.comments.add
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<S::Error>> {
+        add(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Deletes an existing comment on a file.
+    ///
+    /// Wraps https://api.slack.com/methods/files.comments.delete
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<S::Error>> {
+        delete(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Edit an existing file comment.
+    ///
+    /// Wraps https://api.slack.com/methods/files.comments.edit
+    pub fn edit(&self, request: &EditRequest) -> Result<EditResponse, EditError<S::Error>> {
+        edit(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Close a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.close
-
 pub fn close<R>(
     client: &R,
     token: &str,
@@ -23,39 +21,74 @@ pub fn close<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.close");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.close");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(CloseError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result).map_err(CloseError::MalformedResponse)
+            serde_json::from_str::<CloseResponse>(&result.body).map_err(CloseError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct CloseRequest<'a> {
     /// Direct message channel to close.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+}
+
+impl<'a> CloseRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> CloseRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// An owned builder for a [`CloseRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct CloseRequestBuilder {
+    channel: Option<crate::ChannelId>,
+}
+
+impl CloseRequestBuilder {
+    /// Direct message channel to close.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<CloseRequest<'_>, crate::MissingField> {
+        Ok(CloseRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CloseResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
-    fn into(self) -> Result<CloseResponse, CloseError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CloseResponse> for Result<CloseResponse, CloseError<E>> {
+    fn from(response: CloseResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -118,55 +151,29 @@ impl<'a, E: Error> From<&'a str> for CloseError<E> {
 
 impl<E: Error> fmt::Display for CloseError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for CloseError<E> {
-    fn description(&self) -> &str {
         match *self {
-            CloseError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            CloseError::UserDoesNotOwnChannel => {
-                "user_does_not_own_channel: Calling user does not own this DM channel."
-            }
-            CloseError::NotAuthed => "not_authed: No authentication token provided.",
-            CloseError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            CloseError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            CloseError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            CloseError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            CloseError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            CloseError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            CloseError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            CloseError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            CloseError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            CloseError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            CloseError::MalformedResponse(ref e) => e.description(),
-            CloseError::Unknown(ref s) => s,
-            CloseError::Client(ref inner) => inner.description(),
+            CloseError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            CloseError::UserDoesNotOwnChannel => write!(f, "user_does_not_own_channel: Calling user does not own this DM channel."),
+            CloseError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            CloseError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            CloseError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            CloseError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            CloseError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            CloseError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            CloseError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            CloseError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            CloseError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            CloseError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            CloseError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            CloseError::MalformedResponse(ref e) => write!(f, "{}", e),
+            CloseError::Unknown(ref s) => write!(f, "{}", s),
+            CloseError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for CloseError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             CloseError::MalformedResponse(ref e) => Some(e),
             CloseError::Client(ref inner) => Some(inner),
@@ -175,10 +182,32 @@ impl<E: Error> Error for CloseError<E> {
     }
 }
 
+impl<E: Error> From<CloseError<E>> for crate::SlackError<E> {
+    fn from(err: CloseError<E>) -> Self {
+        match err {
+            CloseError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            CloseError::UserDoesNotOwnChannel => crate::SlackError::Api("user_does_not_own_channel".to_owned()),
+            CloseError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            CloseError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            CloseError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            CloseError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            CloseError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            CloseError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            CloseError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            CloseError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            CloseError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            CloseError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            CloseError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            CloseError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            CloseError::Unknown(s) => crate::SlackError::Api(s),
+            CloseError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Fetches history of messages and events from direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.history
-
 pub fn history<R>(
     client: &R,
     token: &str,
@@ -189,10 +218,9 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.latest.map(|latest| ("latest", latest)),
-        request.oldest.map(|oldest| ("oldest", oldest)),
+        Some(("channel", request.channel.as_str())),
+        request.latest.map(|latest| ("latest", latest.as_str())),
+        request.oldest.map(|oldest| ("oldest", oldest.as_str())),
         request.inclusive.map(|inclusive| {
             ("inclusive", if inclusive { "1" } else { "0" })
         }),
@@ -201,27 +229,31 @@ where
             ("unreads", if unreads { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.history");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.history");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(HistoryError::Client)
         .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result).map_err(
+            serde_json::from_str::<HistoryResponse>(&result.body).map_err(
                 HistoryError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct HistoryRequest<'a> {
     /// Direct message channel to fetch history for.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// End of time range of messages to include in results.
-    pub latest: Option<&'a str>,
+    pub latest: Option<&'a crate::Ts>,
     /// Start of time range of messages to include in results.
-    pub oldest: Option<&'a str>,
+    pub oldest: Option<&'a crate::Ts>,
     /// Include messages with latest or oldest timestamp in results.
     pub inclusive: Option<bool>,
     /// Number of messages to return, between 1 and 1000.
@@ -230,23 +262,98 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> HistoryRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> HistoryRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`HistoryRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct HistoryRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    latest: Option<crate::Ts>,
+    oldest: Option<crate::Ts>,
+    inclusive: Option<bool>,
+    count: Option<u32>,
+    unreads: Option<bool>,
+}
+
+impl HistoryRequestBuilder {
+    /// Direct message channel to fetch history for.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// End of time range of messages to include in results.
+    pub fn latest(mut self, latest: crate::Ts) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Start of time range of messages to include in results.
+    pub fn oldest(mut self, oldest: crate::Ts) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Include messages with latest or oldest timestamp in results.
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    /// Number of messages to return, between 1 and 1000.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Include unread_count_display in the output?
+    pub fn unreads(mut self, unreads: bool) -> Self {
+        self.unreads = Some(unreads);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<HistoryRequest<'_>, crate::MissingField> {
+        Ok(HistoryRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            latest: self.latest.as_ref(),
+            oldest: self.oldest.as_ref(),
+            inclusive: self.inclusive,
+            count: self.count,
+            unreads: self.unreads,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct HistoryResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_more: Option<bool>,
-    pub latest: Option<String>,
-    pub messages: Option<Vec<::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<crate::Message>>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
-    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<HistoryResponse> for Result<HistoryResponse, HistoryError<E>> {
+    fn from(response: HistoryResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -312,58 +419,30 @@ impl<'a, E: Error> From<&'a str> for HistoryError<E> {
 
 impl<E: Error> fmt::Display for HistoryError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for HistoryError<E> {
-    fn description(&self) -> &str {
         match *self {
-            HistoryError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            HistoryError::InvalidTsLatest => {
-                "invalid_ts_latest: Value passed for latest was invalid"
-            }
-            HistoryError::InvalidTsOldest => {
-                "invalid_ts_oldest: Value passed for oldest was invalid"
-            }
-            HistoryError::NotAuthed => "not_authed: No authentication token provided.",
-            HistoryError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            HistoryError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            HistoryError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            HistoryError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            HistoryError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            HistoryError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            HistoryError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            HistoryError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            HistoryError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            HistoryError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            HistoryError::MalformedResponse(ref e) => e.description(),
-            HistoryError::Unknown(ref s) => s,
-            HistoryError::Client(ref inner) => inner.description(),
+            HistoryError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            HistoryError::InvalidTsLatest => write!(f, "invalid_ts_latest: Value passed for latest was invalid"),
+            HistoryError::InvalidTsOldest => write!(f, "invalid_ts_oldest: Value passed for oldest was invalid"),
+            HistoryError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            HistoryError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            HistoryError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            HistoryError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            HistoryError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            HistoryError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            HistoryError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            HistoryError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            HistoryError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            HistoryError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            HistoryError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            HistoryError::MalformedResponse(ref e) => write!(f, "{}", e),
+            HistoryError::Unknown(ref s) => write!(f, "{}", s),
+            HistoryError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for HistoryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             HistoryError::MalformedResponse(ref e) => Some(e),
             HistoryError::Client(ref inner) => Some(inner),
@@ -372,10 +451,33 @@ impl<E: Error> Error for HistoryError<E> {
     }
 }
 
+impl<E: Error> From<HistoryError<E>> for crate::SlackError<E> {
+    fn from(err: HistoryError<E>) -> Self {
+        match err {
+            HistoryError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            HistoryError::InvalidTsLatest => crate::SlackError::Api("invalid_ts_latest".to_owned()),
+            HistoryError::InvalidTsOldest => crate::SlackError::Api("invalid_ts_oldest".to_owned()),
+            HistoryError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            HistoryError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            HistoryError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            HistoryError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            HistoryError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            HistoryError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            HistoryError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            HistoryError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            HistoryError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            HistoryError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            HistoryError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            HistoryError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            HistoryError::Unknown(s) => crate::SlackError::Api(s),
+            HistoryError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists direct message channels for the calling user.
 ///
 /// Wraps https://api.slack.com/methods/im.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -386,17 +488,20 @@ where
 {
     let limit = request.limit.map(|limit| limit.to_string());
     let params = vec![
-        Some(("token", token)),
         request.cursor.map(|cursor| ("cursor", cursor)),
         limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -409,21 +514,62 @@ pub struct ListRequest<'a> {
     pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> ListRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    cursor: Option<String>,
+    limit: Option<u32>,
+}
+
+impl ListRequestBuilder {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub fn cursor<S: Into<String>>(mut self, cursor: S) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest<'_>, crate::MissingField> {
+        Ok(ListRequest {
+            cursor: self.cursor.as_deref(),
+            limit: self.limit,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub ims: Option<Vec<::Im>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ims: Option<Vec<crate::Im>>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -480,49 +626,27 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -531,10 +655,30 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Sets the read cursor in a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.mark
-
 pub fn mark<R>(
     client: &R,
     token: &str,
@@ -543,45 +687,87 @@ pub fn mark<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("ts", request.ts)),
+        Some(("channel", request.channel.as_str())),
+        Some(("ts", request.ts.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.mark");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.mark");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(MarkError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result).map_err(MarkError::MalformedResponse)
+            serde_json::from_str::<MarkResponse>(&result.body).map_err(MarkError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct MarkRequest<'a> {
     /// Direct message channel to set reading cursor in.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
     /// Timestamp of the most recently seen message.
-    pub ts: &'a str,
+    pub ts: &'a crate::Ts,
+}
+
+impl<'a> MarkRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> MarkRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// An owned builder for a [`MarkRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct MarkRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    ts: Option<crate::Ts>,
+}
+
+impl MarkRequestBuilder {
+    /// Direct message channel to set reading cursor in.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Timestamp of the most recently seen message.
+    pub fn ts(mut self, ts: crate::Ts) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<MarkRequest<'_>, crate::MissingField> {
+        Ok(MarkRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            ts: self.ts.as_ref().ok_or(crate::MissingField("ts"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MarkResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
-    fn into(self) -> Result<MarkResponse, MarkError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MarkResponse> for Result<MarkResponse, MarkError<E>> {
+    fn from(response: MarkResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -647,56 +833,30 @@ impl<'a, E: Error> From<&'a str> for MarkError<E> {
 
 impl<E: Error> fmt::Display for MarkError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for MarkError<E> {
-    fn description(&self) -> &str {
         match *self {
-            MarkError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            MarkError::InvalidTimestamp => {
-                "invalid_timestamp: Value passed for timestamp was invalid."
-            }
-            MarkError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
-            MarkError::NotAuthed => "not_authed: No authentication token provided.",
-            MarkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            MarkError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            MarkError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            MarkError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            MarkError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            MarkError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            MarkError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            MarkError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            MarkError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            MarkError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            MarkError::MalformedResponse(ref e) => e.description(),
-            MarkError::Unknown(ref s) => s,
-            MarkError::Client(ref inner) => inner.description(),
+            MarkError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            MarkError::InvalidTimestamp => write!(f, "invalid_timestamp: Value passed for timestamp was invalid."),
+            MarkError::NotInChannel => write!(f, "not_in_channel: Caller is not a member of the channel."),
+            MarkError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            MarkError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            MarkError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            MarkError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            MarkError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            MarkError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            MarkError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            MarkError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            MarkError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            MarkError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            MarkError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            MarkError::MalformedResponse(ref e) => write!(f, "{}", e),
+            MarkError::Unknown(ref s) => write!(f, "{}", s),
+            MarkError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for MarkError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             MarkError::MalformedResponse(ref e) => Some(e),
             MarkError::Client(ref inner) => Some(inner),
@@ -705,10 +865,33 @@ impl<E: Error> Error for MarkError<E> {
     }
 }
 
+impl<E: Error> From<MarkError<E>> for crate::SlackError<E> {
+    fn from(err: MarkError<E>) -> Self {
+        match err {
+            MarkError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            MarkError::InvalidTimestamp => crate::SlackError::Api("invalid_timestamp".to_owned()),
+            MarkError::NotInChannel => crate::SlackError::Api("not_in_channel".to_owned()),
+            MarkError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            MarkError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            MarkError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            MarkError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            MarkError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            MarkError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            MarkError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            MarkError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            MarkError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            MarkError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            MarkError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            MarkError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            MarkError::Unknown(s) => crate::SlackError::Api(s),
+            MarkError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Opens a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.open
-
 pub fn open<R>(
     client: &R,
     token: &str,
@@ -717,48 +900,91 @@ pub fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("user", request.user)),
+        Some(("user", request.user.as_str())),
         request.return_im.map(|return_im| {
             ("return_im", if return_im { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.open");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.open");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(OpenError::Client)
         .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result).map_err(OpenError::MalformedResponse)
+            serde_json::from_str::<OpenResponse>(&result.body).map_err(OpenError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct OpenRequest<'a> {
     /// User to open a direct message channel with.
-    pub user: &'a str,
+    pub user: &'a crate::UserId,
     /// Boolean, indicates you want the full IM channel definition in the response.
     pub return_im: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> OpenRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> OpenRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`OpenRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequestBuilder {
+    user: Option<crate::UserId>,
+    return_im: Option<bool>,
+}
+
+impl OpenRequestBuilder {
+    /// User to open a direct message channel with.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub fn return_im(mut self, return_im: bool) -> Self {
+        self.return_im = Some(return_im);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<OpenRequest<'_>, crate::MissingField> {
+        Ok(OpenRequest {
+            user: self.user.as_ref().ok_or(crate::MissingField("user"))?,
+            return_im: self.return_im,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpenResponse {
-    pub channel: Option<::Im>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::Im>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
-    fn into(self) -> Result<OpenResponse, OpenError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -824,54 +1050,30 @@ impl<'a, E: Error> From<&'a str> for OpenError<E> {
 
 impl<E: Error> fmt::Display for OpenError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for OpenError<E> {
-    fn description(&self) -> &str {
         match *self {
-            OpenError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            OpenError::UserNotVisible => {
-                "user_not_visible: The calling user is restricted from seeing the requested user."
-            }
-            OpenError::UserDisabled => "user_disabled: The user has been disabled.",
-            OpenError::NotAuthed => "not_authed: No authentication token provided.",
-            OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            OpenError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            OpenError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            OpenError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            OpenError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            OpenError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            OpenError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            OpenError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            OpenError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            OpenError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            OpenError::MalformedResponse(ref e) => e.description(),
-            OpenError::Unknown(ref s) => s,
-            OpenError::Client(ref inner) => inner.description(),
+            OpenError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            OpenError::UserNotVisible => write!(f, "user_not_visible: The calling user is restricted from seeing the requested user."),
+            OpenError::UserDisabled => write!(f, "user_disabled: The user has been disabled."),
+            OpenError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            OpenError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            OpenError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            OpenError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            OpenError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            OpenError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            OpenError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            OpenError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            OpenError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            OpenError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            OpenError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            OpenError::MalformedResponse(ref e) => write!(f, "{}", e),
+            OpenError::Unknown(ref s) => write!(f, "{}", s),
+            OpenError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for OpenError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             OpenError::MalformedResponse(ref e) => Some(e),
             OpenError::Client(ref inner) => Some(inner),
@@ -880,10 +1082,33 @@ impl<E: Error> Error for OpenError<E> {
     }
 }
 
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(err: OpenError<E>) -> Self {
+        match err {
+            OpenError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            OpenError::UserNotVisible => crate::SlackError::Api("user_not_visible".to_owned()),
+            OpenError::UserDisabled => crate::SlackError::Api("user_disabled".to_owned()),
+            OpenError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            OpenError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            OpenError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            OpenError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            OpenError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            OpenError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            OpenError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            OpenError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            OpenError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            OpenError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            OpenError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            OpenError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            OpenError::Unknown(s) => crate::SlackError::Api(s),
+            OpenError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Retrieve a thread of messages posted to a direct message conversation
 ///
 /// Wraps https://api.slack.com/methods/im.replies
-
 pub fn replies<R>(
     client: &R,
     token: &str,
@@ -892,49 +1117,93 @@ pub fn replies<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        Some(("thread_ts", request.thread_ts)),
+        Some(("channel", request.channel.as_str())),
+        Some(("thread_ts", request.thread_ts.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("im.replies");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "im.replies");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RepliesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result).map_err(
+            serde_json::from_str::<RepliesResponse>(&result.body).map_err(
                 RepliesError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RepliesRequest<'a> {
     /// Direct message channel to fetch thread from
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
+    /// Unique identifier of a thread's parent message
+    pub thread_ts: &'a crate::Ts,
+}
+
+impl<'a> RepliesRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RepliesRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RepliesRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RepliesRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    thread_ts: Option<crate::Ts>,
+}
+
+impl RepliesRequestBuilder {
+    /// Direct message channel to fetch thread from
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
     /// Unique identifier of a thread's parent message
-    pub thread_ts: &'a str,
+    pub fn thread_ts(mut self, thread_ts: crate::Ts) -> Self {
+        self.thread_ts = Some(thread_ts);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RepliesRequest<'_>, crate::MissingField> {
+        Ok(RepliesRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+            thread_ts: self.thread_ts.as_ref().ok_or(crate::MissingField("thread_ts"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RepliesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub messages: Option<Vec<::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<crate::Message>>,
     #[serde(default)]
     ok: bool,
-    pub thread_info: Option<::ThreadInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_info: Option<crate::ThreadInfo>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
-    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RepliesResponse> for Result<RepliesResponse, RepliesError<E>> {
+    fn from(response: RepliesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1000,56 +1269,30 @@ impl<'a, E: Error> From<&'a str> for RepliesError<E> {
 
 impl<E: Error> fmt::Display for RepliesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for RepliesError<E> {
-    fn description(&self) -> &str {
         match *self {
-            RepliesError::ChannelNotFound => {
-                "channel_not_found: Value for channel was missing or invalid."
-            }
-            RepliesError::ThreadNotFound => {
-                "thread_not_found: Value for thread_ts was missing or invalid."
-            }
-            RepliesError::NotAuthed => "not_authed: No authentication token provided.",
-            RepliesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RepliesError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RepliesError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            RepliesError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RepliesError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RepliesError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RepliesError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RepliesError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RepliesError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RepliesError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RepliesError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RepliesError::MalformedResponse(ref e) => e.description(),
-            RepliesError::Unknown(ref s) => s,
-            RepliesError::Client(ref inner) => inner.description(),
+            RepliesError::ChannelNotFound => write!(f, "channel_not_found: Value for channel was missing or invalid."),
+            RepliesError::ThreadNotFound => write!(f, "thread_not_found: Value for thread_ts was missing or invalid."),
+            RepliesError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RepliesError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RepliesError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RepliesError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            RepliesError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RepliesError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RepliesError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RepliesError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RepliesError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RepliesError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RepliesError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RepliesError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RepliesError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RepliesError::Unknown(ref s) => write!(f, "{}", s),
+            RepliesError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RepliesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RepliesError::MalformedResponse(ref e) => Some(e),
             RepliesError::Client(ref inner) => Some(inner),
@@ -1057,3 +1300,94 @@ impl<E: Error> Error for RepliesError<E> {
         }
     }
 }
+
+impl<E: Error> From<RepliesError<E>> for crate::SlackError<E> {
+    fn from(err: RepliesError<E>) -> Self {
+        match err {
+            RepliesError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            RepliesError::ThreadNotFound => crate::SlackError::Api("thread_not_found".to_owned()),
+            RepliesError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RepliesError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RepliesError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RepliesError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            RepliesError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RepliesError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RepliesError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RepliesError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RepliesError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RepliesError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RepliesError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RepliesError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RepliesError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RepliesError::Unknown(s) => crate::SlackError::Api(s),
+            RepliesError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`im`](im/index.html) module.
+    pub fn im(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Close a direct message channel.
+    ///
+    /// Wraps https://api.slack.com/methods/im.close
+    pub fn close(&self, request: &CloseRequest) -> Result<CloseResponse, CloseError<S::Error>> {
+        close(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Fetches history of messages and events from direct message channel.
+    ///
+    /// Wraps https://api.slack.com/methods/im.history
+    pub fn history(
+        &self,
+        request: &HistoryRequest,
+    ) -> Result<HistoryResponse, HistoryError<S::Error>> {
+        history(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists direct message channels for the calling user.
+    ///
+    /// Wraps https://api.slack.com/methods/im.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Sets the read cursor in a direct message channel.
+    ///
+    /// Wraps https://api.slack.com/methods/im.mark
+    pub fn mark(&self, request: &MarkRequest) -> Result<MarkResponse, MarkError<S::Error>> {
+        mark(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Opens a direct message channel.
+    ///
+    /// Wraps https://api.slack.com/methods/im.open
+    pub fn open(&self, request: &OpenRequest) -> Result<OpenResponse, OpenError<S::Error>> {
+        open(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Retrieve a thread of messages posted to a direct message conversation
+    ///
+    /// Wraps https://api.slack.com/methods/im.replies
+    pub fn replies(
+        &self,
+        request: &RepliesRequest,
+    ) -> Result<RepliesResponse, RepliesError<S::Error>> {
+        replies(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Exchanges a temporary OAuth code for an API token.
 ///
 /// Wraps https://api.slack.com/methods/oauth.access
-
 pub fn access<R>(
     client: &R,
     request: &AccessRequest,
@@ -21,7 +19,6 @@ pub fn access<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
         Some(("client_id", request.client_id)),
         Some(("client_secret", request.client_secret)),
@@ -30,13 +27,17 @@ where
             ("redirect_uri", redirect_uri)
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("oauth.access");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "oauth.access");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: None,
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AccessError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AccessResponse>(&result).map_err(AccessError::MalformedResponse)
+            serde_json::from_str::<AccessResponse>(&result.body).map_err(AccessError::MalformedResponse)
         })
 }
 
@@ -52,10 +53,67 @@ pub struct AccessRequest<'a> {
     pub redirect_uri: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AccessRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AccessRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`AccessRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AccessRequestBuilder {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    code: Option<String>,
+    redirect_uri: Option<String>,
+}
+
+impl AccessRequestBuilder {
+    /// Issued when you created your application.
+    pub fn client_id<S: Into<String>>(mut self, client_id: S) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Issued when you created your application.
+    pub fn client_secret<S: Into<String>>(mut self, client_secret: S) -> Self {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    /// The code param returned via the OAuth callback.
+    pub fn code<S: Into<String>>(mut self, code: S) -> Self {
+        self.code = Some(code.into());
+        self
+    }
+
+    /// This must match the originally submitted URI (if one was sent).
+    pub fn redirect_uri<S: Into<String>>(mut self, redirect_uri: S) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AccessRequest<'_>, crate::MissingField> {
+        Ok(AccessRequest {
+            client_id: self.client_id.as_deref().ok_or(crate::MissingField("client_id"))?,
+            client_secret: self.client_secret.as_deref().ok_or(crate::MissingField("client_secret"))?,
+            code: self.code.as_deref().ok_or(crate::MissingField("code"))?,
+            redirect_uri: self.redirect_uri.as_deref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
@@ -116,54 +174,28 @@ impl<'a, E: Error> From<&'a str> for AccessError<E> {
 
 impl<E: Error> fmt::Display for AccessError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AccessError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AccessError::InvalidClientId => {
-                "invalid_client_id: Value passed for client_id was invalid."
-            }
-            AccessError::BadClientSecret => {
-                "bad_client_secret: Value passed for client_secret was invalid."
-            }
-            AccessError::InvalidCode => "invalid_code: Value passed for code was invalid.",
-            AccessError::BadRedirectUri => {
-                "bad_redirect_uri: Value passed for redirect_uri did not match the redirect_uri in the original request."
-            }
-            AccessError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AccessError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AccessError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AccessError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AccessError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AccessError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AccessError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AccessError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AccessError::MalformedResponse(ref e) => e.description(),
-            AccessError::Unknown(ref s) => s,
-            AccessError::Client(ref inner) => inner.description(),
+            AccessError::InvalidClientId => write!(f, "invalid_client_id: Value passed for client_id was invalid."),
+            AccessError::BadClientSecret => write!(f, "bad_client_secret: Value passed for client_secret was invalid."),
+            AccessError::InvalidCode => write!(f, "invalid_code: Value passed for code was invalid."),
+            AccessError::BadRedirectUri => write!(f, "bad_redirect_uri: Value passed for redirect_uri did not match the redirect_uri in the original request."),
+            AccessError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AccessError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AccessError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AccessError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AccessError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AccessError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AccessError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AccessError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AccessError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AccessError::Unknown(ref s) => write!(f, "{}", s),
+            AccessError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AccessError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AccessError::MalformedResponse(ref e) => Some(e),
             AccessError::Client(ref inner) => Some(inner),
@@ -171,3 +203,51 @@ impl<E: Error> Error for AccessError<E> {
         }
     }
 }
+
+impl<E: Error> From<AccessError<E>> for crate::SlackError<E> {
+    fn from(err: AccessError<E>) -> Self {
+        match err {
+            AccessError::InvalidClientId => crate::SlackError::Api("invalid_client_id".to_owned()),
+            AccessError::BadClientSecret => crate::SlackError::Api("bad_client_secret".to_owned()),
+            AccessError::InvalidCode => crate::SlackError::Api("invalid_code".to_owned()),
+            AccessError::BadRedirectUri => crate::SlackError::Api("bad_redirect_uri".to_owned()),
+            AccessError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AccessError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AccessError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AccessError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AccessError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AccessError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AccessError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AccessError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AccessError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AccessError::Unknown(s) => crate::SlackError::Api(s),
+            AccessError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`oauth`](oauth/index.html) module.
+    pub fn oauth(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Exchanges a temporary OAuth code for an API token.
+    ///
+    /// Wraps https://api.slack.com/methods/oauth.access
+    pub fn access(&self, request: &AccessRequest) -> Result<AccessResponse, AccessError<S::Error>> {
+        access(self.slack.sender(), request)
+    }
+}
@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Pins an item to a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.add
-
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -22,53 +20,85 @@ pub fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
-    ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("pins.add");
+    let [item, item_ts] = request.item.params_in(request.channel)?;
+    let params = vec![item, item_ts];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.add");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result).map_err(AddError::MalformedResponse)
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct AddRequest<'a> {
-    /// Channel to pin the item in.
-    pub channel: &'a str,
-    /// File to pin.
-    pub file: Option<&'a str>,
-    /// File comment to pin.
-    pub file_comment: Option<&'a str>,
-    /// Timestamp of the message to pin.
-    pub timestamp: Option<&'a str>,
+    /// Channel to pin the item in. Can be left out when the item is a message, and must be its channel if set.
+    pub channel: Option<&'a crate::ChannelId>,
+    /// Item to pin.
+    pub item: &'a crate::ItemRef,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AddRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`AddRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AddRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    item: Option<crate::ItemRef>,
+}
+
+impl AddRequestBuilder {
+    /// Channel to pin the item in. Can be left out when the item is a message, and must be its channel if set.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Item to pin.
+    pub fn item(mut self, item: crate::ItemRef) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AddRequest<'_>, crate::MissingField> {
+        Ok(AddRequest {
+            channel: self.channel.as_ref(),
+            item: self.item.as_ref().ok_or(crate::MissingField("item"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -152,72 +182,36 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AddError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AddError::BadTimestamp => "bad_timestamp: Value passed for timestamp was invalid.",
-            AddError::FileNotFound => "file_not_found: File specified by file does not exist.",
-            AddError::FileCommentNotFound => {
-                "file_comment_not_found: File comment specified by file_comment does not exist."
-            }
-            AddError::MessageNotFound => {
-                "message_not_found: Message specified by channel and timestamp does not exist."
-            }
-            AddError::ChannelNotFound => {
-                "channel_not_found: The channel argument was not specified or was invalid"
-            }
-            AddError::NoItemSpecified => {
-                "no_item_specified: One of file, file_comment, or timestamp was not specified."
-            }
-            AddError::AlreadyPinned => {
-                "already_pinned: The specified item is already pinned to the channel."
-            }
-            AddError::PermissionDenied => {
-                "permission_denied: The user does not have permission to add pins to the channel."
-            }
-            AddError::FileNotShared => {
-                "file_not_shared: File specified by file is not public nor shared to the channel."
-            }
-            AddError::NotAuthed => "not_authed: No authentication token provided.",
-            AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AddError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AddError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AddError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AddError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AddError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AddError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AddError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AddError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AddError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AddError::MalformedResponse(ref e) => e.description(),
-            AddError::Unknown(ref s) => s,
-            AddError::Client(ref inner) => inner.description(),
+            AddError::BadTimestamp => write!(f, "bad_timestamp: Value passed for timestamp was invalid."),
+            AddError::FileNotFound => write!(f, "file_not_found: File specified by file does not exist."),
+            AddError::FileCommentNotFound => write!(f, "file_comment_not_found: File comment specified by file_comment does not exist."),
+            AddError::MessageNotFound => write!(f, "message_not_found: Message specified by channel and timestamp does not exist."),
+            AddError::ChannelNotFound => write!(f, "channel_not_found: The channel argument was not specified or was invalid"),
+            AddError::NoItemSpecified => write!(f, "no_item_specified: One of file, file_comment, or timestamp was not specified."),
+            AddError::AlreadyPinned => write!(f, "already_pinned: The specified item is already pinned to the channel."),
+            AddError::PermissionDenied => write!(f, "permission_denied: The user does not have permission to add pins to the channel."),
+            AddError::FileNotShared => write!(f, "file_not_shared: File specified by file is not public nor shared to the channel."),
+            AddError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AddError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AddError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AddError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AddError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AddError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AddError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AddError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AddError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AddError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AddError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AddError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AddError::MalformedResponse(ref e) => Some(e),
             AddError::Client(ref inner) => Some(inner),
@@ -226,10 +220,39 @@ impl<E: Error> Error for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(err: AddError<E>) -> Self {
+        match err {
+            AddError::BadTimestamp => crate::SlackError::Api("bad_timestamp".to_owned()),
+            AddError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            AddError::FileCommentNotFound => crate::SlackError::Api("file_comment_not_found".to_owned()),
+            AddError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            AddError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            AddError::NoItemSpecified => crate::SlackError::Api("no_item_specified".to_owned()),
+            AddError::AlreadyPinned => crate::SlackError::Api("already_pinned".to_owned()),
+            AddError::PermissionDenied => crate::SlackError::Api("permission_denied".to_owned()),
+            AddError::FileNotShared => crate::SlackError::Api("file_not_shared".to_owned()),
+            AddError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AddError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AddError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AddError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AddError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AddError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AddError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AddError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AddError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AddError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AddError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AddError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AddError::Unknown(s) => crate::SlackError::Api(s),
+            AddError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists items pinned to a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -238,120 +261,75 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("pins.list");
+    let params = vec![Some(("channel", request.channel.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct ListRequest<'a> {
     /// Channel to get pinned items for.
-    pub channel: &'a str,
+    pub channel: &'a crate::ChannelId,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-}
-
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
-}
-
-impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
-
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(ListResponseItem::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(ListResponseItem::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+impl<'a> ListRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    channel: Option<crate::ChannelId>,
 }
 
+impl ListRequestBuilder {
+    /// Channel to get pinned items for.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest<'_>, crate::MissingField> {
+        Ok(ListRequest {
+            channel: self.channel.as_ref().ok_or(crate::MissingField("channel"))?,
+        })
+    }
 }
 
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub created: Option<f32>,
-    pub created_by: Option<String>,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::Item>>,
+    #[serde(default)]
+    ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -411,52 +389,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::ChannelNotFound => {
-                "channel_not_found: Value passed for channel was invalid."
-            }
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::ChannelNotFound => write!(f, "channel_not_found: Value passed for channel was invalid."),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -465,10 +419,31 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::ChannelNotFound => crate::SlackError::Api("channel_not_found".to_owned()),
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Un-pins an item from a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.remove
-
 pub fn remove<R>(
     client: &R,
     token: &str,
@@ -477,53 +452,85 @@ pub fn remove<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![
-        Some(("token", token)),
-        Some(("channel", request.channel)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
-    ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("pins.remove");
+    let [item, item_ts] = request.item.params_in(request.channel)?;
+    let params = vec![item, item_ts];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "pins.remove");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result).map_err(RemoveError::MalformedResponse)
+            serde_json::from_str::<RemoveResponse>(&result.body).map_err(RemoveError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RemoveRequest<'a> {
-    /// Channel where the item is pinned to.
-    pub channel: &'a str,
-    /// File to un-pin.
-    pub file: Option<&'a str>,
-    /// File comment to un-pin.
-    pub file_comment: Option<&'a str>,
-    /// Timestamp of the message to un-pin.
-    pub timestamp: Option<&'a str>,
+    /// Channel where the item is pinned to. Can be left out when the item is a message, and must be its channel if set.
+    pub channel: Option<&'a crate::ChannelId>,
+    /// Item to un-pin.
+    pub item: &'a crate::ItemRef,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> RemoveRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RemoveRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RemoveRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequestBuilder {
+    channel: Option<crate::ChannelId>,
+    item: Option<crate::ItemRef>,
+}
+
+impl RemoveRequestBuilder {
+    /// Channel where the item is pinned to. Can be left out when the item is a message, and must be its channel if set.
+    pub fn channel(mut self, channel: crate::ChannelId) -> Self {
+        self.channel = Some(channel);
+        self
+    }
+
+    /// Item to un-pin.
+    pub fn item(mut self, item: crate::ItemRef) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RemoveRequest<'_>, crate::MissingField> {
+        Ok(RemoveRequest {
+            channel: self.channel.as_ref(),
+            item: self.item.as_ref().ok_or(crate::MissingField("item"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
-    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RemoveResponse> for Result<RemoveResponse, RemoveError<E>> {
+    fn from(response: RemoveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -601,66 +608,34 @@ impl<'a, E: Error> From<&'a str> for RemoveError<E> {
 
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for RemoveError<E> {
-    fn description(&self) -> &str {
         match *self {
-            RemoveError::BadTimestamp => "bad_timestamp: Value passed for timestamp was invalid.",
-            RemoveError::FileNotFound => "file_not_found: File specified by file does not exist.",
-            RemoveError::FileCommentNotFound => {
-                "file_comment_not_found: File comment specified by file_comment does not exist."
-            }
-            RemoveError::MessageNotFound => {
-                "message_not_found: Message specified by channel and timestamp does not exist."
-            }
-            RemoveError::NoItemSpecified => {
-                "no_item_specified: One of file, file_comment, or timestamp was not specified."
-            }
-            RemoveError::NotPinned => {
-                "not_pinned: The specified item is not pinned to the channel."
-            }
-            RemoveError::PermissionDenied => {
-                "permission_denied: The user does not have permission to remove pins from the channel."
-            }
-            RemoveError::NotAuthed => "not_authed: No authentication token provided.",
-            RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RemoveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RemoveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RemoveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RemoveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RemoveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RemoveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RemoveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RemoveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RemoveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RemoveError::MalformedResponse(ref e) => e.description(),
-            RemoveError::Unknown(ref s) => s,
-            RemoveError::Client(ref inner) => inner.description(),
+            RemoveError::BadTimestamp => write!(f, "bad_timestamp: Value passed for timestamp was invalid."),
+            RemoveError::FileNotFound => write!(f, "file_not_found: File specified by file does not exist."),
+            RemoveError::FileCommentNotFound => write!(f, "file_comment_not_found: File comment specified by file_comment does not exist."),
+            RemoveError::MessageNotFound => write!(f, "message_not_found: Message specified by channel and timestamp does not exist."),
+            RemoveError::NoItemSpecified => write!(f, "no_item_specified: One of file, file_comment, or timestamp was not specified."),
+            RemoveError::NotPinned => write!(f, "not_pinned: The specified item is not pinned to the channel."),
+            RemoveError::PermissionDenied => write!(f, "permission_denied: The user does not have permission to remove pins from the channel."),
+            RemoveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RemoveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RemoveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RemoveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RemoveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RemoveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RemoveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RemoveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RemoveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RemoveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RemoveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RemoveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RemoveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RemoveError::MalformedResponse(ref e) => Some(e),
             RemoveError::Client(ref inner) => Some(inner),
@@ -668,3 +643,74 @@ impl<E: Error> Error for RemoveError<E> {
         }
     }
 }
+
+impl<E: Error> From<RemoveError<E>> for crate::SlackError<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        match err {
+            RemoveError::BadTimestamp => crate::SlackError::Api("bad_timestamp".to_owned()),
+            RemoveError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            RemoveError::FileCommentNotFound => crate::SlackError::Api("file_comment_not_found".to_owned()),
+            RemoveError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            RemoveError::NoItemSpecified => crate::SlackError::Api("no_item_specified".to_owned()),
+            RemoveError::NotPinned => crate::SlackError::Api("not_pinned".to_owned()),
+            RemoveError::PermissionDenied => crate::SlackError::Api("permission_denied".to_owned()),
+            RemoveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RemoveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RemoveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RemoveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RemoveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RemoveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RemoveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RemoveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RemoveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RemoveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RemoveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RemoveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RemoveError::Unknown(s) => crate::SlackError::Api(s),
+            RemoveError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`pins`](pins/index.html) module.
+    pub fn pins(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Pins an item to a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/pins.add
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<S::Error>> {
+        add(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists items pinned to a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/pins.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Un-pins an item from a channel.
+    ///
+    /// Wraps https://api.slack.com/methods/pins.remove
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<S::Error>> {
+        remove(self.slack.sender(), self.slack.token(), request)
+    }
+}
+
+#[cfg(test)]
+mod tests;
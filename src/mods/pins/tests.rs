@@ -0,0 +1,56 @@
+//! Tests of the generated pins methods, kept apart so that regenerating them keeps the tests.
+
+use super::{add, AddError, AddRequest};
+use crate::mock::MockSender;
+use crate::ItemRef;
+
+fn message() -> ItemRef {
+    ItemRef::Message { channel: "C1234567890".parse().unwrap(), ts: "1355517523.000005".parse().unwrap() }
+}
+
+#[test]
+fn test_messages_are_pinned_in_their_own_channel() {
+    let client = MockSender::new();
+    client
+        .expect("pins.add")
+        .param("channel", "C1234567890")
+        .param("timestamp", "1355517523.000005")
+        .respond(r#"{"ok": true}"#);
+    add(&client, "xoxb-token", &AddRequest::builder().item(message()).build().unwrap()).unwrap();
+    assert_eq!(2, client.calls()[0].params.len());
+    client.assert_all_called();
+}
+
+#[test]
+fn test_files_are_pinned_in_the_given_channel() {
+    let client = MockSender::new();
+    client
+        .expect("pins.add")
+        .param("channel", "C1234567890")
+        .param("file", "F1234567890")
+        .respond(r#"{"ok": true}"#);
+    let request = AddRequest::builder().item(ItemRef::File("F1234567890".parse().unwrap()));
+    match add(&client, "xoxb-token", &request.build().unwrap()) {
+        Err(AddError::ChannelNotFound) => {}
+        other => panic!("{:?}", other),
+    }
+    let request = request.channel("C1234567890".parse().unwrap());
+    add(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+    client.assert_all_called();
+}
+
+#[test]
+fn test_items_outside_the_channel_are_not_sent() {
+    let client = MockSender::new();
+    let request = AddRequest::builder().channel("C0987654321".parse().unwrap()).item(message());
+    match add(&client, "xoxb-token", &request.build().unwrap()) {
+        Err(AddError::ChannelNotFound) => {}
+        other => panic!("{:?}", other),
+    }
+    let request = request.item(ItemRef::Channel("C0987654321".parse().unwrap()));
+    match add(&client, "xoxb-token", &request.build().unwrap()) {
+        Err(AddError::NoItemSpecified) => {}
+        other => panic!("{:?}", other),
+    }
+    assert!(client.calls().is_empty());
+}
@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Adds a reaction to an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.add
-
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -22,56 +20,89 @@ pub fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let [item, item_ts] = request.item.params();
     let params = vec![
-        Some(("token", token)),
         Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        item,
+        item_ts,
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reactions.add");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.add");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result).map_err(AddError::MalformedResponse)
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct AddRequest<'a> {
     /// Reaction (emoji) name.
     pub name: &'a str,
-    /// File to add reaction to.
-    pub file: Option<&'a str>,
-    /// File comment to add reaction to.
-    pub file_comment: Option<&'a str>,
-    /// Channel where the message to add reaction to was posted.
-    pub channel: Option<&'a str>,
-    /// Timestamp of the message to add reaction to.
-    pub timestamp: Option<&'a str>,
+    /// Item to add reaction to.
+    pub item: &'a crate::ItemRef,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AddRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// An owned builder for a [`AddRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AddRequestBuilder {
+    name: Option<String>,
+    item: Option<crate::ItemRef>,
+}
+
+impl AddRequestBuilder {
+    /// Reaction (emoji) name.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Item to add reaction to.
+    pub fn item(mut self, item: crate::ItemRef) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AddRequest<'_>, crate::MissingField> {
+        Ok(AddRequest {
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            item: self.item.as_ref().ok_or(crate::MissingField("item"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -155,70 +186,36 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AddError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AddError::BadTimestamp => "bad_timestamp: Value passed for timestamp was invalid.",
-            AddError::FileNotFound => "file_not_found: File specified by file does not exist.",
-            AddError::FileCommentNotFound => {
-                "file_comment_not_found: File comment specified by file_comment does not exist."
-            }
-            AddError::MessageNotFound => {
-                "message_not_found: Message specified by channel and timestamp does not exist."
-            }
-            AddError::NoItemSpecified => {
-                "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."
-            }
-            AddError::InvalidName => "invalid_name: Value passed for name was invalid.",
-            AddError::AlreadyReacted => {
-                "already_reacted: The specified item already has the user/reaction combination."
-            }
-            AddError::TooManyEmoji => {
-                "too_many_emoji: The limit for distinct reactions (i.e emoji) on the item has been reached."
-            }
-            AddError::TooManyReactions => {
-                "too_many_reactions: The limit for reactions a person may add to the item has been reached."
-            }
-            AddError::NotAuthed => "not_authed: No authentication token provided.",
-            AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AddError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AddError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AddError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AddError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AddError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AddError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AddError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AddError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AddError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AddError::MalformedResponse(ref e) => e.description(),
-            AddError::Unknown(ref s) => s,
-            AddError::Client(ref inner) => inner.description(),
+            AddError::BadTimestamp => write!(f, "bad_timestamp: Value passed for timestamp was invalid."),
+            AddError::FileNotFound => write!(f, "file_not_found: File specified by file does not exist."),
+            AddError::FileCommentNotFound => write!(f, "file_comment_not_found: File comment specified by file_comment does not exist."),
+            AddError::MessageNotFound => write!(f, "message_not_found: Message specified by channel and timestamp does not exist."),
+            AddError::NoItemSpecified => write!(f, "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."),
+            AddError::InvalidName => write!(f, "invalid_name: Value passed for name was invalid."),
+            AddError::AlreadyReacted => write!(f, "already_reacted: The specified item already has the user/reaction combination."),
+            AddError::TooManyEmoji => write!(f, "too_many_emoji: The limit for distinct reactions (i.e emoji) on the item has been reached."),
+            AddError::TooManyReactions => write!(f, "too_many_reactions: The limit for reactions a person may add to the item has been reached."),
+            AddError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AddError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AddError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AddError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AddError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AddError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AddError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AddError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AddError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AddError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AddError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AddError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AddError::MalformedResponse(ref e) => Some(e),
             AddError::Client(ref inner) => Some(inner),
@@ -227,10 +224,39 @@ impl<E: Error> Error for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(err: AddError<E>) -> Self {
+        match err {
+            AddError::BadTimestamp => crate::SlackError::Api("bad_timestamp".to_owned()),
+            AddError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            AddError::FileCommentNotFound => crate::SlackError::Api("file_comment_not_found".to_owned()),
+            AddError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            AddError::NoItemSpecified => crate::SlackError::Api("no_item_specified".to_owned()),
+            AddError::InvalidName => crate::SlackError::Api("invalid_name".to_owned()),
+            AddError::AlreadyReacted => crate::SlackError::Api("already_reacted".to_owned()),
+            AddError::TooManyEmoji => crate::SlackError::Api("too_many_emoji".to_owned()),
+            AddError::TooManyReactions => crate::SlackError::Api("too_many_reactions".to_owned()),
+            AddError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AddError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AddError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AddError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AddError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AddError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AddError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AddError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AddError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AddError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AddError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AddError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AddError::Unknown(s) => crate::SlackError::Api(s),
+            AddError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets reactions for an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.get
-
 pub fn get<R>(
     client: &R,
     token: &str,
@@ -239,174 +265,126 @@ pub fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let [item, item_ts] = request.item.params();
     let params = vec![
-        Some(("token", token)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        item,
+        item_ts,
         request.full.map(
             |full| ("full", if full { "1" } else { "0" })
         ),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reactions.get");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.get");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result).map_err(GetError::MalformedResponse)
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct GetRequest<'a> {
-    /// File to get reactions for.
-    pub file: Option<&'a str>,
-    /// File comment to get reactions for.
-    pub file_comment: Option<&'a str>,
-    /// Channel where the message to get reactions for was posted.
-    pub channel: Option<&'a str>,
-    /// Timestamp of the message to get reactions for.
-    pub timestamp: Option<&'a str>,
+    /// Item to get reactions for.
+    pub item: &'a crate::ItemRef,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
 }
 
-#[derive(Clone, Debug)]
-pub enum GetResponse {
-    Message(GetResponseMessage),
-    File(GetResponseFile),
-    FileComment(GetResponseFileComment),
-}
-
-impl<'de> ::serde::Deserialize<'de> for GetResponse {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
-
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<GetResponseMessage>(value.clone())
-                            .map(GetResponse::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<GetResponseFile>(value.clone())
-                            .map(GetResponse::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<GetResponseFileComment>(value.clone())
-                            .map(GetResponse::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+impl<'a> GetRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> GetRequestBuilder {
+        Default::default()
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFile {
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
+/// An owned builder for a [`GetRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct GetRequestBuilder {
+    item: Option<crate::ItemRef>,
+    full: Option<bool>,
 }
 
+impl GetRequestBuilder {
+    /// Item to get reactions for.
+    pub fn item(mut self, item: crate::ItemRef) -> Self {
+        self.item = Some(item);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseFileComment {
-    pub comment: ::FileComment,
-    error: Option<String>,
-    pub file: ::File,
-    #[serde(default)]
-    ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
 
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<GetRequest<'_>, crate::MissingField> {
+        Ok(GetRequest {
+            item: self.item.as_ref().ok_or(crate::MissingField("item"))?,
+            full: self.full,
+        })
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct GetResponseMessage {
-    pub channel: String,
+#[derive(Clone, Debug, Serialize)]
+pub struct GetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub message: ::Message,
-    #[serde(default)]
+    /// The item, which is always set when the response is `ok`
+    #[serde(flatten)]
+    pub item: Option<crate::Item>,
     ok: bool,
-    #[serde(rename = "type")]
-    pub ty: String,
+    /// Fields that are not in the schema, which the item keeps instead when there is one
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
+impl<'de> ::serde::Deserialize<'de> for GetResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        match self {
-            GetResponse::Message(inner) => {
-                let x: Result<GetResponseMessage, GetError<E>> = inner.into();
-                x.map(GetResponse::Message)
+        let mut fields = serde_json::Map::deserialize(deserializer)?;
+        let mut take = |name: &str| fields.remove(name).unwrap_or(serde_json::Value::Null);
+        let ok = Option::<bool>::deserialize(take("ok")).map_err(D::Error::custom)?.unwrap_or_default();
+        let error = Option::<String>::deserialize(take("error")).map_err(D::Error::custom)?;
+        // The item is flattened next to `ok`, so only a failed response can leave it out
+        let item = if ok || fields.contains_key("type") {
+            let item = crate::Item::deserialize(serde_json::Value::Object(fields.clone())).map_err(D::Error::custom)?;
+            if let serde_json::Value::Object(own) = serde_json::to_value(&item).map_err(D::Error::custom)? {
+                fields.retain(|name, _| !own.contains_key(name));
             }
-            GetResponse::File(inner) => {
-                let x: Result<GetResponseFile, GetError<E>> = inner.into();
-                x.map(GetResponse::File)
-            }
-            GetResponse::FileComment(inner) => {
-                let x: Result<GetResponseFileComment, GetError<E>> = inner.into();
-                x.map(GetResponse::FileComment)
-            }
-        }
-    }
-}
-
-impl<E: Error> Into<Result<GetResponseMessage, GetError<E>>> for GetResponseMessage {
-    fn into(self) -> Result<GetResponseMessage, GetError<E>> {
-        if self.ok {
-            Ok(self)
-        } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
-        }
-    }
-}
-impl<E: Error> Into<Result<GetResponseFile, GetError<E>>> for GetResponseFile {
-    fn into(self) -> Result<GetResponseFile, GetError<E>> {
-        if self.ok {
-            Ok(self)
+            Some(item)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
-        }
+            None
+        };
+        Ok(GetResponse {
+            error,
+            item,
+            ok,
+            extra: fields.into_iter().collect(),
+        })
     }
 }
-impl<E: Error> Into<Result<GetResponseFileComment, GetError<E>>> for GetResponseFileComment {
-    fn into(self) -> Result<GetResponseFileComment, GetError<E>> {
-        if self.ok {
-            Ok(self)
+
+impl<E: Error> From<GetResponse> for Result<GetResponse, GetError<E>> {
+    fn from(response: GetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
+
 #[derive(Debug)]
 pub enum GetError<E: Error> {
     /// Value passed for timestamp was invalid.
@@ -475,60 +453,32 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for GetError<E> {
-    fn description(&self) -> &str {
         match *self {
-            GetError::BadTimestamp => "bad_timestamp: Value passed for timestamp was invalid.",
-            GetError::FileNotFound => "file_not_found: File specified by file does not exist.",
-            GetError::FileCommentNotFound => {
-                "file_comment_not_found: File comment specified by file_comment does not exist."
-            }
-            GetError::MessageNotFound => {
-                "message_not_found: Message specified by channel and timestamp does not exist."
-            }
-            GetError::NoItemSpecified => {
-                "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."
-            }
-            GetError::NotAuthed => "not_authed: No authentication token provided.",
-            GetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            GetError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            GetError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            GetError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            GetError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            GetError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            GetError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            GetError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            GetError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            GetError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            GetError::MalformedResponse(ref e) => e.description(),
-            GetError::Unknown(ref s) => s,
-            GetError::Client(ref inner) => inner.description(),
+            GetError::BadTimestamp => write!(f, "bad_timestamp: Value passed for timestamp was invalid."),
+            GetError::FileNotFound => write!(f, "file_not_found: File specified by file does not exist."),
+            GetError::FileCommentNotFound => write!(f, "file_comment_not_found: File comment specified by file_comment does not exist."),
+            GetError::MessageNotFound => write!(f, "message_not_found: Message specified by channel and timestamp does not exist."),
+            GetError::NoItemSpecified => write!(f, "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."),
+            GetError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            GetError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            GetError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            GetError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            GetError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            GetError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            GetError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            GetError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            GetError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            GetError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            GetError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            GetError::MalformedResponse(ref e) => write!(f, "{}", e),
+            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for GetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             GetError::MalformedResponse(ref e) => Some(e),
             GetError::Client(ref inner) => Some(inner),
@@ -537,10 +487,35 @@ impl<E: Error> Error for GetError<E> {
     }
 }
 
+impl<E: Error> From<GetError<E>> for crate::SlackError<E> {
+    fn from(err: GetError<E>) -> Self {
+        match err {
+            GetError::BadTimestamp => crate::SlackError::Api("bad_timestamp".to_owned()),
+            GetError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            GetError::FileCommentNotFound => crate::SlackError::Api("file_comment_not_found".to_owned()),
+            GetError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            GetError::NoItemSpecified => crate::SlackError::Api("no_item_specified".to_owned()),
+            GetError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            GetError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            GetError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            GetError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            GetError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            GetError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            GetError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            GetError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            GetError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            GetError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            GetError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            GetError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            GetError::Unknown(s) => crate::SlackError::Api(s),
+            GetError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists reactions made by a user.
 ///
 /// Wraps https://api.slack.com/methods/reactions.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -552,21 +527,24 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
         request.full.map(
             |full| ("full", if full { "1" } else { "0" })
         ),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reactions.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -574,7 +552,7 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Show reactions made by this user. Defaults to the authed user.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
     /// If true always return the complete reaction list.
     pub full: Option<bool>,
     /// Number of items to return per page.
@@ -583,96 +561,79 @@ pub struct ListRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponse {
-    error: Option<String>,
-    pub items: Option<Vec<ListResponseItem>>,
-    #[serde(default)]
-    ok: bool,
-    pub paging: Option<::Paging>,
+impl<'a> ListRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug)]
-pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    user: Option<crate::UserId>,
+    full: Option<bool>,
+    count: Option<u32>,
+    page: Option<u32>,
 }
 
-impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: ::serde::Deserializer<'de>,
-    {
-        use serde::de::Error as SerdeError;
+impl ListRequestBuilder {
+    /// Show reactions made by this user. Defaults to the authed user.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
 
-        const VARIANTS: &'static [&'static str] = &["message", "file", "file_comment"];
-
-        let value = ::serde_json::Value::deserialize(deserializer)?;
-        if let Some(ty_val) = value.get("type") {
-            if let Some(ty) = ty_val.as_str() {
-                match ty {
-                    "message" => {
-                        ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                            .map(ListResponseItem::Message)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file" => {
-                        ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                            .map(ListResponseItem::File)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
-                    }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
-                }
-            } else {
-                Err(D::Error::invalid_type(
-                    ::serde::de::Unexpected::Unit,
-                    &"a string",
-                ))
-            }
-        } else {
-            Err(D::Error::missing_field("type"))
-        }
+    /// If true always return the complete reaction list.
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
     }
-}
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
 
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: ::FileComment,
-    pub file: ::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest<'_>, crate::MissingField> {
+        Ok(ListRequest {
+            user: self.user.as_ref(),
+            full: self.full,
+            count: self.count,
+            page: self.page,
+        })
+    }
 }
 
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub message: ::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<crate::Item>>,
+    #[serde(default)]
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -732,50 +693,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -784,10 +723,31 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Removes a reaction from an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.remove
-
 pub fn remove<R>(
     client: &R,
     token: &str,
@@ -796,56 +756,89 @@ pub fn remove<R>(
 where
     R: SlackWebRequestSender,
 {
-
+    let [item, item_ts] = request.item.params();
     let params = vec![
-        Some(("token", token)),
         Some(("name", request.name)),
-        request.file.map(|file| ("file", file)),
-        request.file_comment.map(|file_comment| {
-            ("file_comment", file_comment)
-        }),
-        request.channel.map(|channel| ("channel", channel)),
-        request.timestamp.map(|timestamp| ("timestamp", timestamp)),
+        item,
+        item_ts,
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reactions.remove");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reactions.remove");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(RemoveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result).map_err(RemoveError::MalformedResponse)
+            serde_json::from_str::<RemoveResponse>(&result.body).map_err(RemoveError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct RemoveRequest<'a> {
     /// Reaction (emoji) name.
     pub name: &'a str,
-    /// File to remove reaction from.
-    pub file: Option<&'a str>,
-    /// File comment to remove reaction from.
-    pub file_comment: Option<&'a str>,
-    /// Channel where the message to remove reaction from was posted.
-    pub channel: Option<&'a str>,
-    /// Timestamp of the message to remove reaction from.
-    pub timestamp: Option<&'a str>,
+    /// Item to remove reaction from.
+    pub item: &'a crate::ItemRef,
+}
+
+impl<'a> RemoveRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> RemoveRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`RemoveRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequestBuilder {
+    name: Option<String>,
+    item: Option<crate::ItemRef>,
+}
+
+impl RemoveRequestBuilder {
+    /// Reaction (emoji) name.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Item to remove reaction from.
+    pub fn item(mut self, item: crate::ItemRef) -> Self {
+        self.item = Some(item);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<RemoveRequest<'_>, crate::MissingField> {
+        Ok(RemoveRequest {
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            item: self.item.as_ref().ok_or(crate::MissingField("item"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct RemoveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
-    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RemoveResponse> for Result<RemoveResponse, RemoveError<E>> {
+    fn from(response: RemoveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -923,64 +916,34 @@ impl<'a, E: Error> From<&'a str> for RemoveError<E> {
 
 impl<E: Error> fmt::Display for RemoveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for RemoveError<E> {
-    fn description(&self) -> &str {
         match *self {
-            RemoveError::BadTimestamp => "bad_timestamp: Value passed for timestamp was invalid.",
-            RemoveError::FileNotFound => "file_not_found: File specified by file does not exist.",
-            RemoveError::FileCommentNotFound => {
-                "file_comment_not_found: File comment specified by file_comment does not exist."
-            }
-            RemoveError::MessageNotFound => {
-                "message_not_found: Message specified by channel and timestamp does not exist."
-            }
-            RemoveError::NoItemSpecified => {
-                "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."
-            }
-            RemoveError::InvalidName => "invalid_name: Value passed for name was invalid.",
-            RemoveError::NoReaction => {
-                "no_reaction: The specified item does not have the user/reaction combination."
-            }
-            RemoveError::NotAuthed => "not_authed: No authentication token provided.",
-            RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            RemoveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            RemoveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            RemoveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            RemoveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            RemoveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            RemoveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            RemoveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            RemoveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            RemoveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            RemoveError::MalformedResponse(ref e) => e.description(),
-            RemoveError::Unknown(ref s) => s,
-            RemoveError::Client(ref inner) => inner.description(),
+            RemoveError::BadTimestamp => write!(f, "bad_timestamp: Value passed for timestamp was invalid."),
+            RemoveError::FileNotFound => write!(f, "file_not_found: File specified by file does not exist."),
+            RemoveError::FileCommentNotFound => write!(f, "file_comment_not_found: File comment specified by file_comment does not exist."),
+            RemoveError::MessageNotFound => write!(f, "message_not_found: Message specified by channel and timestamp does not exist."),
+            RemoveError::NoItemSpecified => write!(f, "no_item_specified: file, file_comment, or combination of channel and timestamp was not specified."),
+            RemoveError::InvalidName => write!(f, "invalid_name: Value passed for name was invalid."),
+            RemoveError::NoReaction => write!(f, "no_reaction: The specified item does not have the user/reaction combination."),
+            RemoveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            RemoveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            RemoveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            RemoveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            RemoveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            RemoveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            RemoveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            RemoveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            RemoveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            RemoveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            RemoveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            RemoveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            RemoveError::Unknown(ref s) => write!(f, "{}", s),
+            RemoveError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for RemoveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             RemoveError::MalformedResponse(ref e) => Some(e),
             RemoveError::Client(ref inner) => Some(inner),
@@ -988,3 +951,78 @@ impl<E: Error> Error for RemoveError<E> {
         }
     }
 }
+
+impl<E: Error> From<RemoveError<E>> for crate::SlackError<E> {
+    fn from(err: RemoveError<E>) -> Self {
+        match err {
+            RemoveError::BadTimestamp => crate::SlackError::Api("bad_timestamp".to_owned()),
+            RemoveError::FileNotFound => crate::SlackError::Api("file_not_found".to_owned()),
+            RemoveError::FileCommentNotFound => crate::SlackError::Api("file_comment_not_found".to_owned()),
+            RemoveError::MessageNotFound => crate::SlackError::Api("message_not_found".to_owned()),
+            RemoveError::NoItemSpecified => crate::SlackError::Api("no_item_specified".to_owned()),
+            RemoveError::InvalidName => crate::SlackError::Api("invalid_name".to_owned()),
+            RemoveError::NoReaction => crate::SlackError::Api("no_reaction".to_owned()),
+            RemoveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            RemoveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            RemoveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            RemoveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            RemoveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            RemoveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            RemoveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            RemoveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            RemoveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            RemoveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            RemoveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            RemoveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            RemoveError::Unknown(s) => crate::SlackError::Api(s),
+            RemoveError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`reactions`](reactions/index.html) module.
+    pub fn reactions(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Adds a reaction to an item.
+    ///
+    /// Wraps https://api.slack.com/methods/reactions.add
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<S::Error>> {
+        add(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets reactions for an item.
+    ///
+    /// Wraps https://api.slack.com/methods/reactions.get
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<S::Error>> {
+        get(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists reactions made by a user.
+    ///
+    /// Wraps https://api.slack.com/methods/reactions.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Removes a reaction from an item.
+    ///
+    /// Wraps https://api.slack.com/methods/reactions.remove
+    pub fn remove(&self, request: &RemoveRequest) -> Result<RemoveResponse, RemoveError<S::Error>> {
+        remove(self.slack.sender(), self.slack.token(), request)
+    }
+}
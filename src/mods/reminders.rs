@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Creates a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.add
-
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -24,18 +22,21 @@ where
 {
     let time = request.time.to_string();
     let params = vec![
-        Some(("token", token)),
         Some(("text", request.text)),
         Some(("time", &time[..])),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reminders.add");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.add");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AddError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result).map_err(AddError::MalformedResponse)
+            serde_json::from_str::<AddResponse>(&result.body).map_err(AddError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -47,24 +48,73 @@ pub struct AddRequest<'a> {
     /// When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
     pub time: u32,
     /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
+}
+
+impl<'a> AddRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AddRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// An owned builder for a [`AddRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AddRequestBuilder {
+    text: Option<String>,
+    time: Option<u32>,
+    user: Option<crate::UserId>,
+}
+
+impl AddRequestBuilder {
+    /// The content of the reminder
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
+    pub fn time(mut self, time: u32) -> Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AddRequest<'_>, crate::MissingField> {
+        Ok(AddRequest {
+            text: self.text.as_deref().ok_or(crate::MissingField("text"))?,
+            time: self.time.ok_or(crate::MissingField("time"))?,
+            user: self.user.as_ref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub reminder: Option<::Reminder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder: Option<crate::Reminder>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -142,64 +192,34 @@ impl<'a, E: Error> From<&'a str> for AddError<E> {
 
 impl<E: Error> fmt::Display for AddError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AddError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AddError::CannotParse => {
-                "cannot_parse: The phrasing of the timing for this reminder is unclear. You must include a complete time description. Some examples that work: 1458678068, 20, in 5 minutes, tomorrow, at 3:30pm, on Tuesday, or next week."
-            }
-            AddError::UserNotFound => "user_not_found: That user can't be found.",
-            AddError::CannotAddBot => "cannot_add_bot: Reminders can't be sent to bots.",
-            AddError::CannotAddSlackbot => {
-                "cannot_add_slackbot: Reminders can't be sent to Slackbot."
-            }
-            AddError::CannotAddOthers => {
-                "cannot_add_others: Guests can't set reminders for other team members."
-            }
-            AddError::CannotAddOthersRecurring => {
-                "cannot_add_others_recurring: Recurring reminders can't be set for other team members."
-            }
-            AddError::NotAuthed => "not_authed: No authentication token provided.",
-            AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AddError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AddError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            AddError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AddError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AddError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AddError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AddError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AddError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AddError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AddError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AddError::MalformedResponse(ref e) => e.description(),
-            AddError::Unknown(ref s) => s,
-            AddError::Client(ref inner) => inner.description(),
+            AddError::CannotParse => write!(f, "cannot_parse: The phrasing of the timing for this reminder is unclear. You must include a complete time description. Some examples that work: 1458678068, 20, in 5 minutes, tomorrow, at 3:30pm, on Tuesday, or next week."),
+            AddError::UserNotFound => write!(f, "user_not_found: That user can't be found."),
+            AddError::CannotAddBot => write!(f, "cannot_add_bot: Reminders can't be sent to bots."),
+            AddError::CannotAddSlackbot => write!(f, "cannot_add_slackbot: Reminders can't be sent to Slackbot."),
+            AddError::CannotAddOthers => write!(f, "cannot_add_others: Guests can't set reminders for other team members."),
+            AddError::CannotAddOthersRecurring => write!(f, "cannot_add_others_recurring: Recurring reminders can't be set for other team members."),
+            AddError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AddError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AddError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AddError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            AddError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AddError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AddError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AddError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AddError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AddError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AddError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AddError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AddError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AddError::Unknown(ref s) => write!(f, "{}", s),
+            AddError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AddError::MalformedResponse(ref e) => Some(e),
             AddError::Client(ref inner) => Some(inner),
@@ -208,10 +228,37 @@ impl<E: Error> Error for AddError<E> {
     }
 }
 
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(err: AddError<E>) -> Self {
+        match err {
+            AddError::CannotParse => crate::SlackError::Api("cannot_parse".to_owned()),
+            AddError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            AddError::CannotAddBot => crate::SlackError::Api("cannot_add_bot".to_owned()),
+            AddError::CannotAddSlackbot => crate::SlackError::Api("cannot_add_slackbot".to_owned()),
+            AddError::CannotAddOthers => crate::SlackError::Api("cannot_add_others".to_owned()),
+            AddError::CannotAddOthersRecurring => crate::SlackError::Api("cannot_add_others_recurring".to_owned()),
+            AddError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AddError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AddError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AddError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            AddError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AddError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AddError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AddError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AddError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AddError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AddError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AddError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AddError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AddError::Unknown(s) => crate::SlackError::Api(s),
+            AddError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Marks a reminder as complete.
 ///
 /// Wraps https://api.slack.com/methods/reminders.complete
-
 pub fn complete<R>(
     client: &R,
     token: &str,
@@ -220,15 +267,18 @@ pub fn complete<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reminders.complete");
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.complete");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(CompleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CompleteResponse>(&result).map_err(
+            serde_json::from_str::<CompleteResponse>(&result.body).map_err(
                 CompleteError::MalformedResponse,
             )
         })
@@ -241,20 +291,52 @@ pub struct CompleteRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CompleteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> CompleteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`CompleteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct CompleteRequestBuilder {
+    reminder: Option<String>,
+}
+
+impl CompleteRequestBuilder {
+    /// The ID of the reminder to be marked as complete
+    pub fn reminder<S: Into<String>>(mut self, reminder: S) -> Self {
+        self.reminder = Some(reminder.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<CompleteRequest<'_>, crate::MissingField> {
+        Ok(CompleteRequest {
+            reminder: self.reminder.as_deref().ok_or(crate::MissingField("reminder"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CompleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<CompleteResponse, CompleteError<E>>> for CompleteResponse {
-    fn into(self) -> Result<CompleteResponse, CompleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CompleteResponse> for Result<CompleteResponse, CompleteError<E>> {
+    fn from(response: CompleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -323,57 +405,31 @@ impl<'a, E: Error> From<&'a str> for CompleteError<E> {
 
 impl<E: Error> fmt::Display for CompleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for CompleteError<E> {
-    fn description(&self) -> &str {
         match *self {
-            CompleteError::NotFound => "not_found: That reminder can't be found.",
-            CompleteError::CannotCompleteRecurring => {
-                "cannot_complete_recurring: Recurring reminders can't be marked complete."
-            }
-            CompleteError::CannotCompleteOthers => {
-                "cannot_complete_others: Reminders for other team members can't be marked complete."
-            }
-            CompleteError::NotAuthed => "not_authed: No authentication token provided.",
-            CompleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            CompleteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            CompleteError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            CompleteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            CompleteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            CompleteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            CompleteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            CompleteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            CompleteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            CompleteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            CompleteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            CompleteError::MalformedResponse(ref e) => e.description(),
-            CompleteError::Unknown(ref s) => s,
-            CompleteError::Client(ref inner) => inner.description(),
+            CompleteError::NotFound => write!(f, "not_found: That reminder can't be found."),
+            CompleteError::CannotCompleteRecurring => write!(f, "cannot_complete_recurring: Recurring reminders can't be marked complete."),
+            CompleteError::CannotCompleteOthers => write!(f, "cannot_complete_others: Reminders for other team members can't be marked complete."),
+            CompleteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            CompleteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            CompleteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            CompleteError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            CompleteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            CompleteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            CompleteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            CompleteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            CompleteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            CompleteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            CompleteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            CompleteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            CompleteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            CompleteError::Unknown(ref s) => write!(f, "{}", s),
+            CompleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for CompleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             CompleteError::MalformedResponse(ref e) => Some(e),
             CompleteError::Client(ref inner) => Some(inner),
@@ -382,10 +438,34 @@ impl<E: Error> Error for CompleteError<E> {
     }
 }
 
+impl<E: Error> From<CompleteError<E>> for crate::SlackError<E> {
+    fn from(err: CompleteError<E>) -> Self {
+        match err {
+            CompleteError::NotFound => crate::SlackError::Api("not_found".to_owned()),
+            CompleteError::CannotCompleteRecurring => crate::SlackError::Api("cannot_complete_recurring".to_owned()),
+            CompleteError::CannotCompleteOthers => crate::SlackError::Api("cannot_complete_others".to_owned()),
+            CompleteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            CompleteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            CompleteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            CompleteError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            CompleteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            CompleteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            CompleteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            CompleteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            CompleteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            CompleteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            CompleteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            CompleteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            CompleteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            CompleteError::Unknown(s) => crate::SlackError::Api(s),
+            CompleteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Deletes a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -394,15 +474,18 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reminders.delete");
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.delete");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(DeleteError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result).map_err(DeleteError::MalformedResponse)
+            serde_json::from_str::<DeleteResponse>(&result.body).map_err(DeleteError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -413,20 +496,52 @@ pub struct DeleteRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DeleteRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> DeleteRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`DeleteRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequestBuilder {
+    reminder: Option<String>,
+}
+
+impl DeleteRequestBuilder {
+    /// The ID of the reminder
+    pub fn reminder<S: Into<String>>(mut self, reminder: S) -> Self {
+        self.reminder = Some(reminder.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<DeleteRequest<'_>, crate::MissingField> {
+        Ok(DeleteRequest {
+            reminder: self.reminder.as_deref().ok_or(crate::MissingField("reminder"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeleteResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -489,51 +604,29 @@ impl<'a, E: Error> From<&'a str> for DeleteError<E> {
 
 impl<E: Error> fmt::Display for DeleteError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DeleteError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DeleteError::NotFound => "not_found: That reminder can't be found.",
-            DeleteError::NotAuthed => "not_authed: No authentication token provided.",
-            DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DeleteError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DeleteError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            DeleteError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DeleteError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DeleteError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DeleteError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DeleteError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DeleteError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DeleteError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DeleteError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DeleteError::MalformedResponse(ref e) => e.description(),
-            DeleteError::Unknown(ref s) => s,
-            DeleteError::Client(ref inner) => inner.description(),
+            DeleteError::NotFound => write!(f, "not_found: That reminder can't be found."),
+            DeleteError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DeleteError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DeleteError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DeleteError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            DeleteError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DeleteError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DeleteError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DeleteError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DeleteError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DeleteError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DeleteError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DeleteError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DeleteError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DeleteError::Unknown(ref s) => write!(f, "{}", s),
+            DeleteError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DeleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DeleteError::MalformedResponse(ref e) => Some(e),
             DeleteError::Client(ref inner) => Some(inner),
@@ -542,10 +635,32 @@ impl<E: Error> Error for DeleteError<E> {
     }
 }
 
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(err: DeleteError<E>) -> Self {
+        match err {
+            DeleteError::NotFound => crate::SlackError::Api("not_found".to_owned()),
+            DeleteError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DeleteError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DeleteError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DeleteError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            DeleteError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DeleteError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DeleteError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DeleteError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DeleteError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DeleteError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DeleteError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DeleteError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DeleteError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DeleteError::Unknown(s) => crate::SlackError::Api(s),
+            DeleteError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets information about a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -554,15 +669,18 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("reminders.info");
+    let params = vec![Some(("reminder", request.reminder))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -573,21 +691,54 @@ pub struct InfoRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    reminder: Option<String>,
+}
+
+impl InfoRequestBuilder {
+    /// The ID of the reminder
+    pub fn reminder<S: Into<String>>(mut self, reminder: S) -> Self {
+        self.reminder = Some(reminder.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            reminder: self.reminder.as_deref().ok_or(crate::MissingField("reminder"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub reminder: Option<::Reminder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminder: Option<crate::Reminder>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -650,51 +801,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::NotFound => "not_found: That reminder can't be found.",
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::NotFound => write!(f, "not_found: That reminder can't be found."),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -703,40 +832,70 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::NotFound => crate::SlackError::Api("not_found".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists all reminders created by or for a given user.
 ///
 /// Wraps https://api.slack.com/methods/reminders.list
-
 pub fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("reminders.list");
+    let url = crate::get_slack_url_for_method(client.base_url(), "reminders.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub reminders: Option<Vec<::Reminder>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Vec<crate::Reminder>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -796,50 +955,28 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -847,3 +984,82 @@ impl<E: Error> Error for ListError<E> {
         }
     }
 }
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`reminders`](reminders/index.html) module.
+    pub fn reminders(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Creates a reminder.
+    ///
+    /// Wraps https://api.slack.com/methods/reminders.add
+    pub fn add(&self, request: &AddRequest) -> Result<AddResponse, AddError<S::Error>> {
+        add(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Marks a reminder as complete.
+    ///
+    /// Wraps https://api.slack.com/methods/reminders.complete
+    pub fn complete(
+        &self,
+        request: &CompleteRequest,
+    ) -> Result<CompleteResponse, CompleteError<S::Error>> {
+        complete(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Deletes a reminder.
+    ///
+    /// Wraps https://api.slack.com/methods/reminders.delete
+    pub fn delete(&self, request: &DeleteRequest) -> Result<DeleteResponse, DeleteError<S::Error>> {
+        delete(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets information about a reminder.
+    ///
+    /// Wraps https://api.slack.com/methods/reminders.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists all reminders created by or for a given user.
+    ///
+    /// Wraps https://api.slack.com/methods/reminders.list
+    pub fn list(&self) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token())
+    }
+}
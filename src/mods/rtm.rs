@@ -6,65 +6,86 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Starts a Real Time Messaging session.
 ///
 /// Wraps https://api.slack.com/methods/rtm.connect
-
 pub fn connect<R>(client: &R, token: &str) -> Result<ConnectResponse, ConnectError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("rtm.connect");
+    let url = crate::get_slack_url_for_method(client.base_url(), "rtm.connect");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(ConnectError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ConnectResponse>(&result).map_err(
+            serde_json::from_str::<ConnectResponse>(&result.body).map_err(
                 ConnectError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
     #[serde(rename = "self")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub slf: Option<ConnectResponseSelf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<ConnectResponseTeam>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponseSelf {
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ConnectResponseTeam {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enterprise_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub enterprise_name: Option<String>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ConnectResponse, ConnectError<E>>> for ConnectResponse {
-    fn into(self) -> Result<ConnectResponse, ConnectError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ConnectResponse> for Result<ConnectResponse, ConnectError<E>> {
+    fn from(response: ConnectResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -121,49 +142,27 @@ impl<'a, E: Error> From<&'a str> for ConnectError<E> {
 
 impl<E: Error> fmt::Display for ConnectError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ConnectError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ConnectError::NotAuthed => "not_authed: No authentication token provided.",
-            ConnectError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ConnectError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ConnectError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ConnectError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ConnectError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ConnectError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ConnectError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ConnectError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ConnectError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ConnectError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ConnectError::MalformedResponse(ref e) => e.description(),
-            ConnectError::Unknown(ref s) => s,
-            ConnectError::Client(ref inner) => inner.description(),
+            ConnectError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ConnectError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ConnectError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ConnectError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ConnectError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ConnectError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ConnectError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ConnectError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ConnectError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ConnectError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ConnectError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ConnectError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ConnectError::Unknown(ref s) => write!(f, "{}", s),
+            ConnectError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ConnectError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ConnectError::MalformedResponse(ref e) => Some(e),
             ConnectError::Client(ref inner) => Some(inner),
@@ -172,10 +171,30 @@ impl<E: Error> Error for ConnectError<E> {
     }
 }
 
+impl<E: Error> From<ConnectError<E>> for crate::SlackError<E> {
+    fn from(err: ConnectError<E>) -> Self {
+        match err {
+            ConnectError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ConnectError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ConnectError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ConnectError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ConnectError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ConnectError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ConnectError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ConnectError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ConnectError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ConnectError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ConnectError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ConnectError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ConnectError::Unknown(s) => crate::SlackError::Api(s),
+            ConnectError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Starts a Real Time Messaging session.
 ///
 /// Wraps https://api.slack.com/methods/rtm.start
-
 pub fn start<R>(
     client: &R,
     token: &str,
@@ -184,9 +203,7 @@ pub fn start<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.no_unreads.map(|no_unreads| {
             ("no_unreads", if no_unreads { "1" } else { "0" })
         }),
@@ -206,13 +223,17 @@ where
             ("include_locale", if include_locale { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("rtm.start");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "rtm.start");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(StartError::Client)
         .and_then(|result| {
-            serde_json::from_str::<StartResponse>(&result).map_err(StartError::MalformedResponse)
+            serde_json::from_str::<StartResponse>(&result.body).map_err(StartError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -231,30 +252,103 @@ pub struct StartRequest {
     pub include_locale: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl StartRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> StartRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`StartRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct StartRequestBuilder {
+    no_unreads: Option<bool>,
+    mpim_aware: Option<bool>,
+    no_latest: Option<bool>,
+    batch_presence_aware: Option<bool>,
+    include_locale: Option<bool>,
+}
+
+impl StartRequestBuilder {
+    /// Skip unread counts for each channel (improves performance).
+    pub fn no_unreads(mut self, no_unreads: bool) -> Self {
+        self.no_unreads = Some(no_unreads);
+        self
+    }
+
+    /// Returns MPIMs to the client in the API response.
+    pub fn mpim_aware(mut self, mpim_aware: bool) -> Self {
+        self.mpim_aware = Some(mpim_aware);
+        self
+    }
+
+    /// Exclude latest timestamps for channels, groups, mpims, and ims. Automatically sets no_unreads to 1
+    pub fn no_latest(mut self, no_latest: bool) -> Self {
+        self.no_latest = Some(no_latest);
+        self
+    }
+
+    /// Only deliver presence events when requested by subscription. See [presence subscriptions](/docs/presence-and-status#subscriptions).
+    pub fn batch_presence_aware(mut self, batch_presence_aware: bool) -> Self {
+        self.batch_presence_aware = Some(batch_presence_aware);
+        self
+    }
+
+    /// Set this to `true` to receive the locale for users and channels. Defaults to `false`
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<StartRequest, crate::MissingField> {
+        Ok(StartRequest {
+            no_unreads: self.no_unreads,
+            mpim_aware: self.mpim_aware,
+            no_latest: self.no_latest,
+            batch_presence_aware: self.batch_presence_aware,
+            include_locale: self.include_locale,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StartResponse {
-    pub bots: Option<Vec<::Bot>>,
-    pub channels: Option<Vec<::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bots: Option<Vec<crate::Bot>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<crate::Channel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub groups: Option<Vec<::Group>>,
-    pub ims: Option<Vec<::Im>>,
-    pub mpims: Option<Vec<::Mpim>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<crate::Group>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ims: Option<Vec<crate::Im>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mpims: Option<Vec<crate::Mpim>>,
     #[serde(default)]
     ok: bool,
     #[serde(rename = "self")]
-    pub slf: Option<::User>,
-    pub team: Option<::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slf: Option<crate::User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<crate::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
-    pub users: Option<Vec<::User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<crate::User>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<StartResponse, StartError<E>>> for StartResponse {
-    fn into(self) -> Result<StartResponse, StartError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<StartResponse> for Result<StartResponse, StartError<E>> {
+    fn from(response: StartResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -314,52 +408,28 @@ impl<'a, E: Error> From<&'a str> for StartError<E> {
 
 impl<E: Error> fmt::Display for StartError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for StartError<E> {
-    fn description(&self) -> &str {
         match *self {
-            StartError::MigrationInProgress => {
-                "migration_in_progress: Team is being migrated between servers. See the team_migration_started event documentation for details."
-            }
-            StartError::NotAuthed => "not_authed: No authentication token provided.",
-            StartError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            StartError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            StartError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            StartError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            StartError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            StartError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            StartError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            StartError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            StartError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            StartError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            StartError::MalformedResponse(ref e) => e.description(),
-            StartError::Unknown(ref s) => s,
-            StartError::Client(ref inner) => inner.description(),
+            StartError::MigrationInProgress => write!(f, "migration_in_progress: Team is being migrated between servers. See the team_migration_started event documentation for details."),
+            StartError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            StartError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            StartError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            StartError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            StartError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            StartError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            StartError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            StartError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            StartError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            StartError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            StartError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            StartError::MalformedResponse(ref e) => write!(f, "{}", e),
+            StartError::Unknown(ref s) => write!(f, "{}", s),
+            StartError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for StartError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             StartError::MalformedResponse(ref e) => Some(e),
             StartError::Client(ref inner) => Some(inner),
@@ -367,3 +437,58 @@ impl<E: Error> Error for StartError<E> {
         }
     }
 }
+
+impl<E: Error> From<StartError<E>> for crate::SlackError<E> {
+    fn from(err: StartError<E>) -> Self {
+        match err {
+            StartError::MigrationInProgress => crate::SlackError::Api("migration_in_progress".to_owned()),
+            StartError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            StartError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            StartError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            StartError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            StartError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            StartError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            StartError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            StartError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            StartError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            StartError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            StartError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            StartError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            StartError::Unknown(s) => crate::SlackError::Api(s),
+            StartError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`rtm`](rtm/index.html) module.
+    pub fn rtm(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Starts a Real Time Messaging session.
+    ///
+    /// Wraps https://api.slack.com/methods/rtm.connect
+    pub fn connect(&self) -> Result<ConnectResponse, ConnectError<S::Error>> {
+        connect(self.slack.sender(), self.slack.token())
+    }
+
+    /// Starts a Real Time Messaging session.
+    ///
+    /// Wraps https://api.slack.com/methods/rtm.start
+    pub fn start(&self, request: &StartRequest) -> Result<StartResponse, StartError<S::Error>> {
+        start(self.slack.sender(), self.slack.token(), request)
+    }
+}
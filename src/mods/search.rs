@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Searches for messages and files matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.all
-
 pub fn all<R>(
     client: &R,
     token: &str,
@@ -26,7 +24,6 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
         request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
@@ -36,13 +33,17 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("search.all");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.all");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AllError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AllResponse>(&result).map_err(AllError::MalformedResponse)
+            serde_json::from_str::<AllResponse>(&result.body).map_err(AllError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -63,36 +64,117 @@ pub struct AllRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> AllRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AllRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`AllRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AllRequestBuilder {
+    query: Option<String>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+    highlight: Option<bool>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl AllRequestBuilder {
+    /// Search query. May contains booleans, etc.
+    pub fn query<S: Into<String>>(mut self, query: S) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort<S: Into<String>>(mut self, sort: S) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir<S: Into<String>>(mut self, sort_dir: S) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AllRequest<'_>, crate::MissingField> {
+        Ok(AllRequest {
+            query: self.query.as_deref().ok_or(crate::MissingField("query"))?,
+            sort: self.sort.as_deref(),
+            sort_dir: self.sort_dir.as_deref(),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<AllResponseFiles>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<AllResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponseFiles {
-    pub matches: Vec<::File>,
-    pub paging: ::Paging,
+    pub matches: Vec<crate::File>,
+    pub paging: crate::Paging,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AllResponseMessages {
-    pub matches: Vec<::Message>,
-    pub paging: ::Paging,
+    pub matches: Vec<crate::Message>,
+    pub paging: crate::Paging,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AllResponse, AllError<E>>> for AllResponse {
-    fn into(self) -> Result<AllResponse, AllError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AllResponse> for Result<AllResponse, AllError<E>> {
+    fn from(response: AllResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -152,50 +234,28 @@ impl<'a, E: Error> From<&'a str> for AllError<E> {
 
 impl<E: Error> fmt::Display for AllError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AllError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AllError::NotAuthed => "not_authed: No authentication token provided.",
-            AllError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AllError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AllError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            AllError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AllError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AllError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AllError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AllError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AllError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AllError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AllError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AllError::MalformedResponse(ref e) => e.description(),
-            AllError::Unknown(ref s) => s,
-            AllError::Client(ref inner) => inner.description(),
+            AllError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AllError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AllError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AllError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            AllError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AllError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AllError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AllError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AllError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AllError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AllError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AllError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AllError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AllError::Unknown(ref s) => write!(f, "{}", s),
+            AllError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AllError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AllError::MalformedResponse(ref e) => Some(e),
             AllError::Client(ref inner) => Some(inner),
@@ -204,10 +264,31 @@ impl<E: Error> Error for AllError<E> {
     }
 }
 
+impl<E: Error> From<AllError<E>> for crate::SlackError<E> {
+    fn from(err: AllError<E>) -> Self {
+        match err {
+            AllError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AllError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AllError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AllError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            AllError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AllError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AllError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AllError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AllError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AllError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AllError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AllError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AllError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AllError::Unknown(s) => crate::SlackError::Api(s),
+            AllError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Searches for files matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.files
-
 pub fn files<R>(
     client: &R,
     token: &str,
@@ -219,7 +300,6 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
         request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
@@ -229,13 +309,17 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("search.files");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.files");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(FilesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<FilesResponse>(&result).map_err(FilesError::MalformedResponse)
+            serde_json::from_str::<FilesResponse>(&result.body).map_err(FilesError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -256,29 +340,109 @@ pub struct FilesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> FilesRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> FilesRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`FilesRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct FilesRequestBuilder {
+    query: Option<String>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+    highlight: Option<bool>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl FilesRequestBuilder {
+    /// Search query. May contain booleans, etc.
+    pub fn query<S: Into<String>>(mut self, query: S) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort<S: Into<String>>(mut self, sort: S) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir<S: Into<String>>(mut self, sort_dir: S) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<FilesRequest<'_>, crate::MissingField> {
+        Ok(FilesRequest {
+            query: self.query.as_deref().ok_or(crate::MissingField("query"))?,
+            sort: self.sort.as_deref(),
+            sort_dir: self.sort_dir.as_deref(),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FilesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<FilesResponseFiles>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FilesResponseFiles {
-    pub matches: Option<Vec<::File>>,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matches: Option<Vec<crate::File>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<FilesResponse, FilesError<E>>> for FilesResponse {
-    fn into(self) -> Result<FilesResponse, FilesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<FilesResponse> for Result<FilesResponse, FilesError<E>> {
+    fn from(response: FilesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -338,50 +502,28 @@ impl<'a, E: Error> From<&'a str> for FilesError<E> {
 
 impl<E: Error> fmt::Display for FilesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for FilesError<E> {
-    fn description(&self) -> &str {
         match *self {
-            FilesError::NotAuthed => "not_authed: No authentication token provided.",
-            FilesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            FilesError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            FilesError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            FilesError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            FilesError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            FilesError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            FilesError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            FilesError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            FilesError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            FilesError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            FilesError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            FilesError::MalformedResponse(ref e) => e.description(),
-            FilesError::Unknown(ref s) => s,
-            FilesError::Client(ref inner) => inner.description(),
+            FilesError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            FilesError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            FilesError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            FilesError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            FilesError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            FilesError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            FilesError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            FilesError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            FilesError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            FilesError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            FilesError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            FilesError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            FilesError::MalformedResponse(ref e) => write!(f, "{}", e),
+            FilesError::Unknown(ref s) => write!(f, "{}", s),
+            FilesError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for FilesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             FilesError::MalformedResponse(ref e) => Some(e),
             FilesError::Client(ref inner) => Some(inner),
@@ -390,10 +532,31 @@ impl<E: Error> Error for FilesError<E> {
     }
 }
 
+impl<E: Error> From<FilesError<E>> for crate::SlackError<E> {
+    fn from(err: FilesError<E>) -> Self {
+        match err {
+            FilesError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            FilesError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            FilesError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            FilesError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            FilesError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            FilesError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            FilesError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            FilesError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            FilesError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            FilesError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            FilesError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            FilesError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            FilesError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            FilesError::Unknown(s) => crate::SlackError::Api(s),
+            FilesError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Searches for messages matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.messages
-
 pub fn messages<R>(
     client: &R,
     token: &str,
@@ -405,7 +568,6 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
         request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
@@ -415,13 +577,17 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("search.messages");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "search.messages");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(MessagesError::Client)
         .and_then(|result| {
-            serde_json::from_str::<MessagesResponse>(&result).map_err(
+            serde_json::from_str::<MessagesResponse>(&result.body).map_err(
                 MessagesError::MalformedResponse,
             )
         })
@@ -444,29 +610,109 @@ pub struct MessagesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> MessagesRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> MessagesRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`MessagesRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct MessagesRequestBuilder {
+    query: Option<String>,
+    sort: Option<String>,
+    sort_dir: Option<String>,
+    highlight: Option<bool>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl MessagesRequestBuilder {
+    /// Search query. May contains booleans, etc.
+    pub fn query<S: Into<String>>(mut self, query: S) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Return matches sorted by either score or timestamp.
+    pub fn sort<S: Into<String>>(mut self, sort: S) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Change sort direction to ascending (asc) or descending (desc).
+    pub fn sort_dir<S: Into<String>>(mut self, sort_dir: S) -> Self {
+        self.sort_dir = Some(sort_dir.into());
+        self
+    }
+
+    /// Pass a value of true to enable query highlight markers (see below).
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<MessagesRequest<'_>, crate::MissingField> {
+        Ok(MessagesRequest {
+            query: self.query.as_deref().ok_or(crate::MissingField("query"))?,
+            sort: self.sort.as_deref(),
+            sort_dir: self.sort_dir.as_deref(),
+            highlight: self.highlight,
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagesResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<MessagesResponseMessages>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub query: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagesResponseMessages {
-    pub matches: Option<Vec<::Message>>,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matches: Option<Vec<crate::Message>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<MessagesResponse, MessagesError<E>>> for MessagesResponse {
-    fn into(self) -> Result<MessagesResponse, MessagesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MessagesResponse> for Result<MessagesResponse, MessagesError<E>> {
+    fn from(response: MessagesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -526,50 +772,28 @@ impl<'a, E: Error> From<&'a str> for MessagesError<E> {
 
 impl<E: Error> fmt::Display for MessagesError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for MessagesError<E> {
-    fn description(&self) -> &str {
         match *self {
-            MessagesError::NotAuthed => "not_authed: No authentication token provided.",
-            MessagesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            MessagesError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            MessagesError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            MessagesError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            MessagesError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            MessagesError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            MessagesError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            MessagesError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            MessagesError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            MessagesError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            MessagesError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            MessagesError::MalformedResponse(ref e) => e.description(),
-            MessagesError::Unknown(ref s) => s,
-            MessagesError::Client(ref inner) => inner.description(),
+            MessagesError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            MessagesError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            MessagesError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            MessagesError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            MessagesError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            MessagesError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            MessagesError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            MessagesError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            MessagesError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            MessagesError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            MessagesError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            MessagesError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            MessagesError::MalformedResponse(ref e) => write!(f, "{}", e),
+            MessagesError::Unknown(ref s) => write!(f, "{}", s),
+            MessagesError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for MessagesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             MessagesError::MalformedResponse(ref e) => Some(e),
             MessagesError::Client(ref inner) => Some(inner),
@@ -577,3 +801,68 @@ impl<E: Error> Error for MessagesError<E> {
         }
     }
 }
+
+impl<E: Error> From<MessagesError<E>> for crate::SlackError<E> {
+    fn from(err: MessagesError<E>) -> Self {
+        match err {
+            MessagesError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            MessagesError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            MessagesError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            MessagesError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            MessagesError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            MessagesError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            MessagesError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            MessagesError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            MessagesError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            MessagesError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            MessagesError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            MessagesError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            MessagesError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            MessagesError::Unknown(s) => crate::SlackError::Api(s),
+            MessagesError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`search`](search/index.html) module.
+    pub fn search(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Searches for messages and files matching a query.
+    ///
+    /// Wraps https://api.slack.com/methods/search.all
+    pub fn all(&self, request: &AllRequest) -> Result<AllResponse, AllError<S::Error>> {
+        all(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Searches for files matching a query.
+    ///
+    /// Wraps https://api.slack.com/methods/search.files
+    pub fn files(&self, request: &FilesRequest) -> Result<FilesResponse, FilesError<S::Error>> {
+        files(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Searches for messages matching a query.
+    ///
+    /// Wraps https://api.slack.com/methods/search.messages
+    pub fn messages(
+        &self,
+        request: &MessagesRequest,
+    ) -> Result<MessagesResponse, MessagesError<S::Error>> {
+        messages(self.slack.sender(), self.slack.token(), request)
+    }
+}
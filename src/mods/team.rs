@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Gets the access logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.accessLogs
-
 pub fn access_logs<R>(
     client: &R,
     token: &str,
@@ -26,18 +24,21 @@ where
     let page = request.page.map(|page| page.to_string());
     let before = request.before.map(|before| before.to_string());
     let params = vec![
-        Some(("token", token)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
         before.as_ref().map(|before| ("before", &before[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("team.accessLogs");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.accessLogs");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(AccessLogsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<AccessLogsResponse>(&result)
+            serde_json::from_str::<AccessLogsResponse>(&result.body)
                 .map_err(AccessLogsError::MalformedResponse)
         })
         .and_then(|o| o.into())
@@ -53,36 +54,99 @@ pub struct AccessLogsRequest {
     pub before: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl AccessLogsRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> AccessLogsRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`AccessLogsRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct AccessLogsRequestBuilder {
+    count: Option<u32>,
+    page: Option<u32>,
+    before: Option<u32>,
+}
+
+impl AccessLogsRequestBuilder {
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// End of time range of logs to include in results (inclusive).
+    pub fn before(mut self, before: u32) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<AccessLogsRequest, crate::MissingField> {
+        Ok(AccessLogsRequest {
+            count: self.count,
+            page: self.page,
+            before: self.before,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logins: Option<Vec<AccessLogsResponseLogin>>,
     #[serde(default)]
     ok: bool,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AccessLogsResponseLogin {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_first: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_last: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub isp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_agent: Option<String>,
-    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<AccessLogsResponse, AccessLogsError<E>>> for AccessLogsResponse {
-    fn into(self) -> Result<AccessLogsResponse, AccessLogsError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AccessLogsResponse> for Result<AccessLogsResponse, AccessLogsError<E>> {
+    fn from(response: AccessLogsResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -148,56 +212,30 @@ impl<'a, E: Error> From<&'a str> for AccessLogsError<E> {
 
 impl<E: Error> fmt::Display for AccessLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for AccessLogsError<E> {
-    fn description(&self) -> &str {
         match *self {
-            AccessLogsError::PaidOnly => "paid_only: This is only available to paid teams.",
-            AccessLogsError::OverPaginationLimit => {
-                "over_pagination_limit: It is not possible to request more than 1000 items per page or more than 100 pages."
-            }
-            AccessLogsError::NotAuthed => "not_authed: No authentication token provided.",
-            AccessLogsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            AccessLogsError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            AccessLogsError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            AccessLogsError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            AccessLogsError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            AccessLogsError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            AccessLogsError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            AccessLogsError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            AccessLogsError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            AccessLogsError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            AccessLogsError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            AccessLogsError::MalformedResponse(ref e) => e.description(),
-            AccessLogsError::Unknown(ref s) => s,
-            AccessLogsError::Client(ref inner) => inner.description(),
+            AccessLogsError::PaidOnly => write!(f, "paid_only: This is only available to paid teams."),
+            AccessLogsError::OverPaginationLimit => write!(f, "over_pagination_limit: It is not possible to request more than 1000 items per page or more than 100 pages."),
+            AccessLogsError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            AccessLogsError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            AccessLogsError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            AccessLogsError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            AccessLogsError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            AccessLogsError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            AccessLogsError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            AccessLogsError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            AccessLogsError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            AccessLogsError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            AccessLogsError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            AccessLogsError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            AccessLogsError::MalformedResponse(ref e) => write!(f, "{}", e),
+            AccessLogsError::Unknown(ref s) => write!(f, "{}", s),
+            AccessLogsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for AccessLogsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             AccessLogsError::MalformedResponse(ref e) => Some(e),
             AccessLogsError::Client(ref inner) => Some(inner),
@@ -206,10 +244,33 @@ impl<E: Error> Error for AccessLogsError<E> {
     }
 }
 
+impl<E: Error> From<AccessLogsError<E>> for crate::SlackError<E> {
+    fn from(err: AccessLogsError<E>) -> Self {
+        match err {
+            AccessLogsError::PaidOnly => crate::SlackError::Api("paid_only".to_owned()),
+            AccessLogsError::OverPaginationLimit => crate::SlackError::Api("over_pagination_limit".to_owned()),
+            AccessLogsError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            AccessLogsError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            AccessLogsError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            AccessLogsError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            AccessLogsError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            AccessLogsError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            AccessLogsError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            AccessLogsError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            AccessLogsError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            AccessLogsError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            AccessLogsError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            AccessLogsError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            AccessLogsError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            AccessLogsError::Unknown(s) => crate::SlackError::Api(s),
+            AccessLogsError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets billable users information for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.billableInfo
-
 pub fn billable_info<R>(
     client: &R,
     token: &str,
@@ -218,18 +279,20 @@ pub fn billable_info<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("team.billableInfo");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.billableInfo");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(BillableInfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<BillableInfoResponse>(&result)
+            serde_json::from_str::<BillableInfoResponse>(&result.body)
                 .map_err(BillableInfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
@@ -238,24 +301,57 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct BillableInfoRequest<'a> {
     /// A user to retrieve the billable information for. Defaults to all users.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
+}
+
+impl<'a> BillableInfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> BillableInfoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`BillableInfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct BillableInfoRequestBuilder {
+    user: Option<crate::UserId>,
+}
+
+impl BillableInfoRequestBuilder {
+    /// A user to retrieve the billable information for. Defaults to all users.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<BillableInfoRequest<'_>, crate::MissingField> {
+        Ok(BillableInfoRequest {
+            user: self.user.as_ref(),
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BillableInfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub billable_info: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<BillableInfoResponse, BillableInfoError<E>>> for BillableInfoResponse {
-    fn into(self) -> Result<BillableInfoResponse, BillableInfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<BillableInfoResponse> for Result<BillableInfoResponse, BillableInfoError<E>> {
+    fn from(response: BillableInfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -318,53 +414,29 @@ impl<'a, E: Error> From<&'a str> for BillableInfoError<E> {
 
 impl<E: Error> fmt::Display for BillableInfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for BillableInfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            BillableInfoError::UserNotFound => "user_not_found: Unable to find the requested user.",
-            BillableInfoError::NotAuthed => "not_authed: No authentication token provided.",
-            BillableInfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            BillableInfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            BillableInfoError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            BillableInfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            BillableInfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            BillableInfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            BillableInfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            BillableInfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            BillableInfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            BillableInfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            BillableInfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            BillableInfoError::MalformedResponse(ref e) => e.description(),
-            BillableInfoError::Unknown(ref s) => s,
-            BillableInfoError::Client(ref inner) => inner.description(),
+            BillableInfoError::UserNotFound => write!(f, "user_not_found: Unable to find the requested user."),
+            BillableInfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            BillableInfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            BillableInfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            BillableInfoError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            BillableInfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            BillableInfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            BillableInfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            BillableInfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            BillableInfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            BillableInfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            BillableInfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            BillableInfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            BillableInfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            BillableInfoError::Unknown(ref s) => write!(f, "{}", s),
+            BillableInfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for BillableInfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             BillableInfoError::MalformedResponse(ref e) => Some(e),
             BillableInfoError::Client(ref inner) => Some(inner),
@@ -373,40 +445,70 @@ impl<E: Error> Error for BillableInfoError<E> {
     }
 }
 
+impl<E: Error> From<BillableInfoError<E>> for crate::SlackError<E> {
+    fn from(err: BillableInfoError<E>) -> Self {
+        match err {
+            BillableInfoError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            BillableInfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            BillableInfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            BillableInfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            BillableInfoError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            BillableInfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            BillableInfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            BillableInfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            BillableInfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            BillableInfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            BillableInfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            BillableInfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            BillableInfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            BillableInfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            BillableInfoError::Unknown(s) => crate::SlackError::Api(s),
+            BillableInfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets information about the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.info
-
 pub fn info<R>(client: &R, token: &str) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("team.info");
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub team: Option<::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<crate::Team>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -463,49 +565,27 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -514,10 +594,30 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets the integration logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.integrationLogs
-
 pub fn integration_logs<R>(
     client: &R,
     token: &str,
@@ -529,25 +629,28 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let params = vec![
-        Some(("token", token)),
         request.service_id.map(
             |service_id| ("service_id", service_id)
         ),
         request.app_id.map(|app_id| ("app_id", app_id)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
         request.change_type.map(|change_type| {
             ("change_type", change_type)
         }),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("team.integrationLogs");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.integrationLogs");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(IntegrationLogsError::Client)
         .and_then(|result| {
-            serde_json::from_str::<IntegrationLogsResponse>(&result)
+            serde_json::from_str::<IntegrationLogsResponse>(&result.body)
                 .map_err(IntegrationLogsError::MalformedResponse)
         })
         .and_then(|o| o.into())
@@ -560,7 +663,7 @@ pub struct IntegrationLogsRequest<'a> {
     /// Filter logs to this Slack app. Defaults to all logs.
     pub app_id: Option<&'a str>,
     /// Filter logs generated by this user’s actions. Defaults to all logs.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
     /// Filter logs with this change type. Defaults to all logs.
     pub change_type: Option<&'a str>,
     /// Number of items to return per page.
@@ -569,38 +672,125 @@ pub struct IntegrationLogsRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> IntegrationLogsRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> IntegrationLogsRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`IntegrationLogsRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct IntegrationLogsRequestBuilder {
+    service_id: Option<String>,
+    app_id: Option<String>,
+    user: Option<crate::UserId>,
+    change_type: Option<String>,
+    count: Option<u32>,
+    page: Option<u32>,
+}
+
+impl IntegrationLogsRequestBuilder {
+    /// Filter logs to this service. Defaults to all logs.
+    pub fn service_id<S: Into<String>>(mut self, service_id: S) -> Self {
+        self.service_id = Some(service_id.into());
+        self
+    }
+
+    /// Filter logs to this Slack app. Defaults to all logs.
+    pub fn app_id<S: Into<String>>(mut self, app_id: S) -> Self {
+        self.app_id = Some(app_id.into());
+        self
+    }
+
+    /// Filter logs generated by this user’s actions. Defaults to all logs.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Filter logs with this change type. Defaults to all logs.
+    pub fn change_type<S: Into<String>>(mut self, change_type: S) -> Self {
+        self.change_type = Some(change_type.into());
+        self
+    }
+
+    /// Number of items to return per page.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Page number of results to return.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<IntegrationLogsRequest<'_>, crate::MissingField> {
+        Ok(IntegrationLogsRequest {
+            service_id: self.service_id.as_deref(),
+            app_id: self.app_id.as_deref(),
+            user: self.user.as_ref(),
+            change_type: self.change_type.as_deref(),
+            count: self.count,
+            page: self.page,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logs: Option<Vec<IntegrationLogsResponseLog>>,
     #[serde(default)]
     ok: bool,
-    pub paging: Option<::Paging>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paging: Option<crate::Paging>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IntegrationLogsResponseLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub change_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub service_type: Option<String>,
-    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<IntegrationLogsResponse, IntegrationLogsError<E>>>
-    for IntegrationLogsResponse {
-    fn into(self) -> Result<IntegrationLogsResponse, IntegrationLogsError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<IntegrationLogsResponse> for Result<IntegrationLogsResponse, IntegrationLogsError<E>> {
+    fn from(response: IntegrationLogsResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -660,52 +850,28 @@ impl<'a, E: Error> From<&'a str> for IntegrationLogsError<E> {
 
 impl<E: Error> fmt::Display for IntegrationLogsError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for IntegrationLogsError<E> {
-    fn description(&self) -> &str {
         match *self {
-            IntegrationLogsError::NotAuthed => "not_authed: No authentication token provided.",
-            IntegrationLogsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            IntegrationLogsError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            IntegrationLogsError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            IntegrationLogsError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            IntegrationLogsError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            IntegrationLogsError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            IntegrationLogsError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            IntegrationLogsError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            IntegrationLogsError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            IntegrationLogsError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            IntegrationLogsError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            IntegrationLogsError::MalformedResponse(ref e) => e.description(),
-            IntegrationLogsError::Unknown(ref s) => s,
-            IntegrationLogsError::Client(ref inner) => inner.description(),
+            IntegrationLogsError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            IntegrationLogsError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            IntegrationLogsError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            IntegrationLogsError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            IntegrationLogsError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            IntegrationLogsError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            IntegrationLogsError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            IntegrationLogsError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            IntegrationLogsError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            IntegrationLogsError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            IntegrationLogsError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            IntegrationLogsError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            IntegrationLogsError::MalformedResponse(ref e) => write!(f, "{}", e),
+            IntegrationLogsError::Unknown(ref s) => write!(f, "{}", s),
+            IntegrationLogsError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for IntegrationLogsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             IntegrationLogsError::MalformedResponse(ref e) => Some(e),
             IntegrationLogsError::Client(ref inner) => Some(inner),
@@ -713,3 +879,81 @@ impl<E: Error> Error for IntegrationLogsError<E> {
         }
     }
 }
+
+impl<E: Error> From<IntegrationLogsError<E>> for crate::SlackError<E> {
+    fn from(err: IntegrationLogsError<E>) -> Self {
+        match err {
+            IntegrationLogsError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            IntegrationLogsError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            IntegrationLogsError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            IntegrationLogsError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            IntegrationLogsError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            IntegrationLogsError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            IntegrationLogsError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            IntegrationLogsError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            IntegrationLogsError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            IntegrationLogsError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            IntegrationLogsError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            IntegrationLogsError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            IntegrationLogsError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            IntegrationLogsError::Unknown(s) => crate::SlackError::Api(s),
+            IntegrationLogsError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`team`](team/index.html) module.
+    pub fn team(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Gets the access logs for the current team.
+    ///
+    /// Wraps https://api.slack.com/methods/team.accessLogs
+    pub fn access_logs(
+        &self,
+        request: &AccessLogsRequest,
+    ) -> Result<AccessLogsResponse, AccessLogsError<S::Error>> {
+        access_logs(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets billable users information for the current team.
+    ///
+    /// Wraps https://api.slack.com/methods/team.billableInfo
+    pub fn billable_info(
+        &self,
+        request: &BillableInfoRequest,
+    ) -> Result<BillableInfoResponse, BillableInfoError<S::Error>> {
+        billable_info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Gets information about the current team.
+    ///
+    /// Wraps https://api.slack.com/methods/team.info
+    pub fn info(&self) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token())
+    }
+
+    /// Gets the integration logs for the current team.
+    ///
+    /// Wraps https://api.slack.com/methods/team.integrationLogs
+    pub fn integration_logs(
+        &self,
+        request: &IntegrationLogsRequest,
+    ) -> Result<IntegrationLogsResponse, IntegrationLogsError<S::Error>> {
+        integration_logs(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Retrieve a team's profile.
 ///
 /// Wraps https://api.slack.com/methods/team.profile.get
-
 pub fn get<R>(
     client: &R,
     token: &str,
@@ -22,20 +20,22 @@ pub fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.visibility.map(
             |visibility| ("visibility", visibility)
         ),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("team.profile.get");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "team.profile.get");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result).map_err(GetError::MalformedResponse)
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -46,39 +46,87 @@ pub struct GetRequest<'a> {
     pub visibility: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> GetRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> GetRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`GetRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct GetRequestBuilder {
+    visibility: Option<String>,
+}
+
+impl GetRequestBuilder {
+    /// Filter by visibility.
+    pub fn visibility<S: Into<String>>(mut self, visibility: S) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<GetRequest<'_>, crate::MissingField> {
+        Ok(GetRequest {
+            visibility: self.visibility.as_deref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub profile: Option<GetResponseProfile>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<GetResponseProfileField>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponseProfileField {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ordering: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub possible_values: Option<Vec<String>>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetResponse> for Result<GetResponse, GetError<E>> {
+    fn from(response: GetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -138,50 +186,28 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for GetError<E> {
-    fn description(&self) -> &str {
         match *self {
-            GetError::NotAuthed => "not_authed: No authentication token provided.",
-            GetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            GetError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            GetError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            GetError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            GetError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            GetError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            GetError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            GetError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            GetError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            GetError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            GetError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            GetError::MalformedResponse(ref e) => e.description(),
-            GetError::Unknown(ref s) => s,
-            GetError::Client(ref inner) => inner.description(),
+            GetError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            GetError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            GetError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            GetError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            GetError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            GetError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            GetError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            GetError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            GetError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            GetError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            GetError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            GetError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            GetError::MalformedResponse(ref e) => write!(f, "{}", e),
+            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for GetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             GetError::MalformedResponse(ref e) => Some(e),
             GetError::Client(ref inner) => Some(inner),
@@ -189,3 +215,51 @@ impl<E: Error> Error for GetError<E> {
         }
     }
 }
+
+impl<E: Error> From<GetError<E>> for crate::SlackError<E> {
+    fn from(err: GetError<E>) -> Self {
+        match err {
+            GetError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            GetError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            GetError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            GetError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            GetError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            GetError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            GetError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            GetError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            GetError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            GetError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            GetError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            GetError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            GetError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            GetError::Unknown(s) => crate::SlackError::Api(s),
+            GetError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`team_profile`](team_profile/index.html) module.
+    pub fn team_profile(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Retrieve a team's profile.
+    ///
+    /// Wraps https://api.slack.com/methods/team.profile.get
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<S::Error>> {
+        get(self.slack.sender(), self.slack.token(), request)
+    }
+}
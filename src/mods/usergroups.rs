@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Create a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.create
-
 pub fn create<R>(
     client: &R,
     token: &str,
@@ -23,9 +21,7 @@ pub fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         Some(("name", request.name)),
         request.handle.map(|handle| ("handle", handle)),
         request.description.map(|description| {
@@ -36,13 +32,17 @@ where
             ("include_count", if include_count { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("usergroups.create");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.create");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(CreateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result).map_err(CreateError::MalformedResponse)
+            serde_json::from_str::<CreateResponse>(&result.body).map_err(CreateError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -61,21 +61,86 @@ pub struct CreateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> CreateRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> CreateRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`CreateRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequestBuilder {
+    name: Option<String>,
+    handle: Option<String>,
+    description: Option<String>,
+    channels: Option<String>,
+    include_count: Option<bool>,
+}
+
+impl CreateRequestBuilder {
+    /// A name for the User Group. Must be unique among User Groups.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle<S: Into<String>>(mut self, handle: S) -> Self {
+        self.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
+    pub fn channels<S: Into<String>>(mut self, channels: S) -> Self {
+        self.channels = Some(channels.into());
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<CreateRequest<'_>, crate::MissingField> {
+        Ok(CreateRequest {
+            name: self.name.as_deref().ok_or(crate::MissingField("name"))?,
+            handle: self.handle.as_deref(),
+            description: self.description.as_deref(),
+            channels: self.channels.as_deref(),
+            include_count: self.include_count,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct CreateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub usergroup: Option<::Usergroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroup: Option<crate::Usergroup>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
-    fn into(self) -> Result<CreateResponse, CreateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -138,53 +203,29 @@ impl<'a, E: Error> From<&'a str> for CreateError<E> {
 
 impl<E: Error> fmt::Display for CreateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for CreateError<E> {
-    fn description(&self) -> &str {
         match *self {
-            CreateError::NotAuthed => "not_authed: No authentication token provided.",
-            CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            CreateError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            CreateError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            CreateError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            CreateError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            CreateError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            CreateError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            CreateError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            CreateError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            CreateError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            CreateError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            CreateError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            CreateError::MalformedResponse(ref e) => e.description(),
-            CreateError::Unknown(ref s) => s,
-            CreateError::Client(ref inner) => inner.description(),
+            CreateError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            CreateError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            CreateError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            CreateError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            CreateError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            CreateError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            CreateError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            CreateError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            CreateError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            CreateError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            CreateError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            CreateError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            CreateError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            CreateError::MalformedResponse(ref e) => write!(f, "{}", e),
+            CreateError::Unknown(ref s) => write!(f, "{}", s),
+            CreateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for CreateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             CreateError::MalformedResponse(ref e) => Some(e),
             CreateError::Client(ref inner) => Some(inner),
@@ -193,10 +234,32 @@ impl<E: Error> Error for CreateError<E> {
     }
 }
 
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(err: CreateError<E>) -> Self {
+        match err {
+            CreateError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            CreateError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            CreateError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            CreateError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            CreateError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            CreateError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            CreateError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            CreateError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            CreateError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            CreateError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            CreateError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            CreateError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            CreateError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            CreateError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            CreateError::Unknown(s) => crate::SlackError::Api(s),
+            CreateError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Disable an existing User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.disable
-
 pub fn disable<R>(
     client: &R,
     token: &str,
@@ -205,50 +268,93 @@ pub fn disable<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", request.usergroup.as_str())),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("usergroups.disable");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.disable");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(DisableError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DisableResponse>(&result).map_err(
+            serde_json::from_str::<DisableResponse>(&result.body).map_err(
                 DisableError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct DisableRequest<'a> {
     /// The encoded ID of the User Group to disable.
-    pub usergroup: &'a str,
+    pub usergroup: &'a crate::UsergroupId,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> DisableRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> DisableRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`DisableRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct DisableRequestBuilder {
+    usergroup: Option<crate::UsergroupId>,
+    include_count: Option<bool>,
+}
+
+impl DisableRequestBuilder {
+    /// The encoded ID of the User Group to disable.
+    pub fn usergroup(mut self, usergroup: crate::UsergroupId) -> Self {
+        self.usergroup = Some(usergroup);
+        self
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<DisableRequest<'_>, crate::MissingField> {
+        Ok(DisableRequest {
+            usergroup: self.usergroup.as_ref().ok_or(crate::MissingField("usergroup"))?,
+            include_count: self.include_count,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DisableResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub usergroup: Option<::Usergroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroup: Option<crate::Usergroup>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DisableResponse, DisableError<E>>> for DisableResponse {
-    fn into(self) -> Result<DisableResponse, DisableError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DisableResponse> for Result<DisableResponse, DisableError<E>> {
+    fn from(response: DisableResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -311,53 +417,29 @@ impl<'a, E: Error> From<&'a str> for DisableError<E> {
 
 impl<E: Error> fmt::Display for DisableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DisableError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DisableError::NotAuthed => "not_authed: No authentication token provided.",
-            DisableError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DisableError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DisableError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            DisableError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            DisableError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DisableError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DisableError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DisableError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DisableError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DisableError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DisableError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DisableError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DisableError::MalformedResponse(ref e) => e.description(),
-            DisableError::Unknown(ref s) => s,
-            DisableError::Client(ref inner) => inner.description(),
+            DisableError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DisableError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DisableError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DisableError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            DisableError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            DisableError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DisableError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DisableError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DisableError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DisableError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DisableError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DisableError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DisableError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DisableError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DisableError::Unknown(ref s) => write!(f, "{}", s),
+            DisableError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DisableError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DisableError::MalformedResponse(ref e) => Some(e),
             DisableError::Client(ref inner) => Some(inner),
@@ -366,10 +448,32 @@ impl<E: Error> Error for DisableError<E> {
     }
 }
 
+impl<E: Error> From<DisableError<E>> for crate::SlackError<E> {
+    fn from(err: DisableError<E>) -> Self {
+        match err {
+            DisableError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DisableError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DisableError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DisableError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            DisableError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            DisableError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DisableError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DisableError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DisableError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DisableError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DisableError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DisableError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DisableError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DisableError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DisableError::Unknown(s) => crate::SlackError::Api(s),
+            DisableError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Enable a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.enable
-
 pub fn enable<R>(
     client: &R,
     token: &str,
@@ -378,48 +482,91 @@ pub fn enable<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", request.usergroup.as_str())),
         request.include_count.map(|include_count| {
             ("include_count", if include_count { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("usergroups.enable");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.enable");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(EnableError::Client)
         .and_then(|result| {
-            serde_json::from_str::<EnableResponse>(&result).map_err(EnableError::MalformedResponse)
+            serde_json::from_str::<EnableResponse>(&result.body).map_err(EnableError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct EnableRequest<'a> {
     /// The encoded ID of the User Group to enable.
-    pub usergroup: &'a str,
+    pub usergroup: &'a crate::UsergroupId,
     /// Include the number of users in the User Group.
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> EnableRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> EnableRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`EnableRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct EnableRequestBuilder {
+    usergroup: Option<crate::UsergroupId>,
+    include_count: Option<bool>,
+}
+
+impl EnableRequestBuilder {
+    /// The encoded ID of the User Group to enable.
+    pub fn usergroup(mut self, usergroup: crate::UsergroupId) -> Self {
+        self.usergroup = Some(usergroup);
+        self
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<EnableRequest<'_>, crate::MissingField> {
+        Ok(EnableRequest {
+            usergroup: self.usergroup.as_ref().ok_or(crate::MissingField("usergroup"))?,
+            include_count: self.include_count,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct EnableResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub usergroup: Option<::Usergroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroup: Option<crate::Usergroup>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<EnableResponse, EnableError<E>>> for EnableResponse {
-    fn into(self) -> Result<EnableResponse, EnableError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EnableResponse> for Result<EnableResponse, EnableError<E>> {
+    fn from(response: EnableResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -482,53 +629,29 @@ impl<'a, E: Error> From<&'a str> for EnableError<E> {
 
 impl<E: Error> fmt::Display for EnableError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for EnableError<E> {
-    fn description(&self) -> &str {
         match *self {
-            EnableError::NotAuthed => "not_authed: No authentication token provided.",
-            EnableError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            EnableError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            EnableError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            EnableError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            EnableError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            EnableError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            EnableError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            EnableError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            EnableError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            EnableError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            EnableError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            EnableError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            EnableError::MalformedResponse(ref e) => e.description(),
-            EnableError::Unknown(ref s) => s,
-            EnableError::Client(ref inner) => inner.description(),
+            EnableError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            EnableError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            EnableError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            EnableError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            EnableError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            EnableError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            EnableError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            EnableError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            EnableError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            EnableError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            EnableError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            EnableError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            EnableError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            EnableError::MalformedResponse(ref e) => write!(f, "{}", e),
+            EnableError::Unknown(ref s) => write!(f, "{}", s),
+            EnableError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for EnableError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             EnableError::MalformedResponse(ref e) => Some(e),
             EnableError::Client(ref inner) => Some(inner),
@@ -537,10 +660,32 @@ impl<E: Error> Error for EnableError<E> {
     }
 }
 
+impl<E: Error> From<EnableError<E>> for crate::SlackError<E> {
+    fn from(err: EnableError<E>) -> Self {
+        match err {
+            EnableError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            EnableError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            EnableError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            EnableError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            EnableError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            EnableError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            EnableError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            EnableError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            EnableError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            EnableError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            EnableError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            EnableError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            EnableError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            EnableError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            EnableError::Unknown(s) => crate::SlackError::Api(s),
+            EnableError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// List all User Groups for a team
 ///
 /// Wraps https://api.slack.com/methods/usergroups.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -549,9 +694,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.include_disabled.map(|include_disabled| {
             ("include_disabled", if include_disabled { "1" } else { "0" })
         }),
@@ -562,13 +705,17 @@ where
             ("include_users", if include_users { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("usergroups.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -583,21 +730,70 @@ pub struct ListRequest {
     pub include_users: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    include_disabled: Option<bool>,
+    include_count: Option<bool>,
+    include_users: Option<bool>,
+}
+
+impl ListRequestBuilder {
+    /// Include disabled User Groups.
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    /// Include the number of users in each User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Include the list of users for each User Group.
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest, crate::MissingField> {
+        Ok(ListRequest {
+            include_disabled: self.include_disabled,
+            include_count: self.include_count,
+            include_users: self.include_users,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub usergroups: Option<Vec<::Usergroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroups: Option<Vec<crate::Usergroup>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -660,53 +856,29 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            ListError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            ListError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -715,10 +887,32 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            ListError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Update an existing User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.update
-
 pub fn update<R>(
     client: &R,
     token: &str,
@@ -727,10 +921,8 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        Some(("usergroup", request.usergroup)),
+        Some(("usergroup", request.usergroup.as_str())),
         request.name.map(|name| ("name", name)),
         request.handle.map(|handle| ("handle", handle)),
         request.description.map(|description| {
@@ -741,21 +933,25 @@ where
             ("include_count", if include_count { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("usergroups.update");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "usergroups.update");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(UpdateError::Client)
         .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result).map_err(UpdateError::MalformedResponse)
+            serde_json::from_str::<UpdateResponse>(&result.body).map_err(UpdateError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct UpdateRequest<'a> {
     /// The encoded ID of the User Group to update.
-    pub usergroup: &'a str,
+    pub usergroup: &'a crate::UsergroupId,
     /// A name for the User Group. Must be unique among User Groups.
     pub name: Option<&'a str>,
     /// A mention handle. Must be unique among channels, users and User Groups.
@@ -768,21 +964,94 @@ pub struct UpdateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> UpdateRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> UpdateRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`UpdateRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequestBuilder {
+    usergroup: Option<crate::UsergroupId>,
+    name: Option<String>,
+    handle: Option<String>,
+    description: Option<String>,
+    channels: Option<String>,
+    include_count: Option<bool>,
+}
+
+impl UpdateRequestBuilder {
+    /// The encoded ID of the User Group to update.
+    pub fn usergroup(mut self, usergroup: crate::UsergroupId) -> Self {
+        self.usergroup = Some(usergroup);
+        self
+    }
+
+    /// A name for the User Group. Must be unique among User Groups.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A mention handle. Must be unique among channels, users and User Groups.
+    pub fn handle<S: Into<String>>(mut self, handle: S) -> Self {
+        self.handle = Some(handle.into());
+        self
+    }
+
+    /// A short description of the User Group.
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A comma separated string of encoded channel IDs for which the User Group uses as a default.
+    pub fn channels<S: Into<String>>(mut self, channels: S) -> Self {
+        self.channels = Some(channels.into());
+        self
+    }
+
+    /// Include the number of users in the User Group.
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<UpdateRequest<'_>, crate::MissingField> {
+        Ok(UpdateRequest {
+            usergroup: self.usergroup.as_ref().ok_or(crate::MissingField("usergroup"))?,
+            name: self.name.as_deref(),
+            handle: self.handle.as_deref(),
+            description: self.description.as_deref(),
+            channels: self.channels.as_deref(),
+            include_count: self.include_count,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UpdateResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub usergroup: Option<::Usergroup>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroup: Option<crate::Usergroup>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
-    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -845,53 +1114,29 @@ impl<'a, E: Error> From<&'a str> for UpdateError<E> {
 
 impl<E: Error> fmt::Display for UpdateError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for UpdateError<E> {
-    fn description(&self) -> &str {
         match *self {
-            UpdateError::NotAuthed => "not_authed: No authentication token provided.",
-            UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            UpdateError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            UpdateError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            UpdateError::UserIsRestricted => {
-                "user_is_restricted: This method cannot be called by a restricted user or single channel guest."
-            }
-            UpdateError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            UpdateError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            UpdateError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            UpdateError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            UpdateError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            UpdateError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            UpdateError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            UpdateError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            UpdateError::MalformedResponse(ref e) => e.description(),
-            UpdateError::Unknown(ref s) => s,
-            UpdateError::Client(ref inner) => inner.description(),
+            UpdateError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            UpdateError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            UpdateError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            UpdateError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            UpdateError::UserIsRestricted => write!(f, "user_is_restricted: This method cannot be called by a restricted user or single channel guest."),
+            UpdateError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            UpdateError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            UpdateError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            UpdateError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            UpdateError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            UpdateError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            UpdateError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            UpdateError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            UpdateError::MalformedResponse(ref e) => write!(f, "{}", e),
+            UpdateError::Unknown(ref s) => write!(f, "{}", s),
+            UpdateError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for UpdateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             UpdateError::MalformedResponse(ref e) => Some(e),
             UpdateError::Client(ref inner) => Some(inner),
@@ -899,3 +1144,83 @@ impl<E: Error> Error for UpdateError<E> {
         }
     }
 }
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(err: UpdateError<E>) -> Self {
+        match err {
+            UpdateError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            UpdateError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            UpdateError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            UpdateError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            UpdateError::UserIsRestricted => crate::SlackError::Api("user_is_restricted".to_owned()),
+            UpdateError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            UpdateError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            UpdateError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            UpdateError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            UpdateError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            UpdateError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            UpdateError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            UpdateError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            UpdateError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            UpdateError::Unknown(s) => crate::SlackError::Api(s),
+            UpdateError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`usergroups`](usergroups/index.html) module.
+    pub fn usergroups(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Create a User Group
+    ///
+    /// Wraps https://api.slack.com/methods/usergroups.create
+    pub fn create(&self, request: &CreateRequest) -> Result<CreateResponse, CreateError<S::Error>> {
+        create(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Disable an existing User Group
+    ///
+    /// Wraps https://api.slack.com/methods/usergroups.disable
+    pub fn disable(
+        &self,
+        request: &DisableRequest,
+    ) -> Result<DisableResponse, DisableError<S::Error>> {
+        disable(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Enable a User Group
+    ///
+    /// Wraps https://api.slack.com/methods/usergroups.enable
+    pub fn enable(&self, request: &EnableRequest) -> Result<EnableResponse, EnableError<S::Error>> {
+        enable(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// List all User Groups for a team
+    ///
+    /// Wraps https://api.slack.com/methods/usergroups.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Update an existing User Group
+    ///
+    /// Wraps https://api.slack.com/methods/usergroups.update
+    pub fn update(&self, request: &UpdateRequest) -> Result<UpdateResponse, UpdateError<S::Error>> {
+        update(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -7,14 +7,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{MultipartFile, RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Delete the user profile photo
 ///
 /// Wraps https://api.slack.com/methods/users.deletePhoto
-
 pub fn delete_photo<R>(
     client: &R,
     token: &str,
@@ -22,32 +20,39 @@ pub fn delete_photo<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("users.deletePhoto");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.deletePhoto");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(DeletePhotoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<DeletePhotoResponse>(&result)
+            serde_json::from_str::<DeletePhotoResponse>(&result.body)
                 .map_err(DeletePhotoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct DeletePhotoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<DeletePhotoResponse, DeletePhotoError<E>>> for DeletePhotoResponse {
-    fn into(self) -> Result<DeletePhotoResponse, DeletePhotoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeletePhotoResponse> for Result<DeletePhotoResponse, DeletePhotoError<E>> {
+    fn from(response: DeletePhotoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -107,52 +112,28 @@ impl<'a, E: Error> From<&'a str> for DeletePhotoError<E> {
 
 impl<E: Error> fmt::Display for DeletePhotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for DeletePhotoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            DeletePhotoError::NotAuthed => "not_authed: No authentication token provided.",
-            DeletePhotoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            DeletePhotoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            DeletePhotoError::UserIsBot => {
-                "user_is_bot: This method cannot be called by a bot user."
-            }
-            DeletePhotoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            DeletePhotoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            DeletePhotoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            DeletePhotoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            DeletePhotoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            DeletePhotoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            DeletePhotoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            DeletePhotoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            DeletePhotoError::MalformedResponse(ref e) => e.description(),
-            DeletePhotoError::Unknown(ref s) => s,
-            DeletePhotoError::Client(ref inner) => inner.description(),
+            DeletePhotoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            DeletePhotoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            DeletePhotoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            DeletePhotoError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            DeletePhotoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            DeletePhotoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            DeletePhotoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            DeletePhotoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            DeletePhotoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            DeletePhotoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            DeletePhotoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            DeletePhotoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            DeletePhotoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            DeletePhotoError::Unknown(ref s) => write!(f, "{}", s),
+            DeletePhotoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for DeletePhotoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             DeletePhotoError::MalformedResponse(ref e) => Some(e),
             DeletePhotoError::Client(ref inner) => Some(inner),
@@ -161,10 +142,31 @@ impl<E: Error> Error for DeletePhotoError<E> {
     }
 }
 
+impl<E: Error> From<DeletePhotoError<E>> for crate::SlackError<E> {
+    fn from(err: DeletePhotoError<E>) -> Self {
+        match err {
+            DeletePhotoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            DeletePhotoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            DeletePhotoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            DeletePhotoError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            DeletePhotoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            DeletePhotoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            DeletePhotoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            DeletePhotoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            DeletePhotoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            DeletePhotoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            DeletePhotoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            DeletePhotoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            DeletePhotoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            DeletePhotoError::Unknown(s) => crate::SlackError::Api(s),
+            DeletePhotoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets user presence information.
 ///
 /// Wraps https://api.slack.com/methods/users.getPresence
-
 pub fn get_presence<R>(
     client: &R,
     token: &str,
@@ -173,41 +175,77 @@ pub fn get_presence<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.getPresence");
+    let params = vec![Some(("user", request.user.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.getPresence");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(GetPresenceError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetPresenceResponse>(&result)
+            serde_json::from_str::<GetPresenceResponse>(&result.body)
                 .map_err(GetPresenceError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct GetPresenceRequest<'a> {
     /// User to get presence info on. Defaults to the authed user.
-    pub user: &'a str,
+    pub user: &'a crate::UserId,
+}
+
+impl<'a> GetPresenceRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> GetPresenceRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`GetPresenceRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct GetPresenceRequestBuilder {
+    user: Option<crate::UserId>,
+}
+
+impl GetPresenceRequestBuilder {
+    /// User to get presence info on. Defaults to the authed user.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<GetPresenceRequest<'_>, crate::MissingField> {
+        Ok(GetPresenceRequest {
+            user: self.user.as_ref().ok_or(crate::MissingField("user"))?,
+        })
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetPresenceResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub presence: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<GetPresenceResponse, GetPresenceError<E>>> for GetPresenceResponse {
-    fn into(self) -> Result<GetPresenceResponse, GetPresenceError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetPresenceResponse> for Result<GetPresenceResponse, GetPresenceError<E>> {
+    fn from(response: GetPresenceResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -264,49 +302,27 @@ impl<'a, E: Error> From<&'a str> for GetPresenceError<E> {
 
 impl<E: Error> fmt::Display for GetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for GetPresenceError<E> {
-    fn description(&self) -> &str {
         match *self {
-            GetPresenceError::NotAuthed => "not_authed: No authentication token provided.",
-            GetPresenceError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            GetPresenceError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            GetPresenceError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            GetPresenceError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            GetPresenceError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            GetPresenceError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            GetPresenceError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            GetPresenceError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            GetPresenceError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            GetPresenceError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            GetPresenceError::MalformedResponse(ref e) => e.description(),
-            GetPresenceError::Unknown(ref s) => s,
-            GetPresenceError::Client(ref inner) => inner.description(),
+            GetPresenceError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            GetPresenceError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            GetPresenceError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            GetPresenceError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            GetPresenceError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            GetPresenceError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            GetPresenceError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            GetPresenceError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            GetPresenceError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            GetPresenceError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            GetPresenceError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            GetPresenceError::MalformedResponse(ref e) => write!(f, "{}", e),
+            GetPresenceError::Unknown(ref s) => write!(f, "{}", s),
+            GetPresenceError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for GetPresenceError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             GetPresenceError::MalformedResponse(ref e) => Some(e),
             GetPresenceError::Client(ref inner) => Some(inner),
@@ -315,43 +331,72 @@ impl<E: Error> Error for GetPresenceError<E> {
     }
 }
 
+impl<E: Error> From<GetPresenceError<E>> for crate::SlackError<E> {
+    fn from(err: GetPresenceError<E>) -> Self {
+        match err {
+            GetPresenceError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            GetPresenceError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            GetPresenceError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            GetPresenceError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            GetPresenceError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            GetPresenceError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            GetPresenceError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            GetPresenceError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            GetPresenceError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            GetPresenceError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            GetPresenceError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            GetPresenceError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            GetPresenceError::Unknown(s) => crate::SlackError::Api(s),
+            GetPresenceError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Get a user's identity.
 ///
 /// Wraps https://api.slack.com/methods/users.identity
-
 pub fn identity<R>(client: &R, token: &str) -> Result<IdentityResponse, IdentityError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("users.identity");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.identity");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(IdentityError::Client)
         .and_then(|result| {
-            serde_json::from_str::<IdentityResponse>(&result).map_err(
+            serde_json::from_str::<IdentityResponse>(&result.body).map_err(
                 IdentityError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IdentityResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub team: Option<::Team>,
-    pub user: Option<::User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<crate::Team>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::User>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<IdentityResponse, IdentityError<E>>> for IdentityResponse {
-    fn into(self) -> Result<IdentityResponse, IdentityError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<IdentityResponse> for Result<IdentityResponse, IdentityError<E>> {
+    fn from(response: IdentityResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -411,50 +456,28 @@ impl<'a, E: Error> From<&'a str> for IdentityError<E> {
 
 impl<E: Error> fmt::Display for IdentityError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for IdentityError<E> {
-    fn description(&self) -> &str {
         match *self {
-            IdentityError::NotAuthed => "not_authed: No authentication token provided.",
-            IdentityError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            IdentityError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            IdentityError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            IdentityError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            IdentityError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            IdentityError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            IdentityError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            IdentityError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            IdentityError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            IdentityError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            IdentityError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            IdentityError::MalformedResponse(ref e) => e.description(),
-            IdentityError::Unknown(ref s) => s,
-            IdentityError::Client(ref inner) => inner.description(),
+            IdentityError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            IdentityError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            IdentityError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            IdentityError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            IdentityError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            IdentityError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            IdentityError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            IdentityError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            IdentityError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            IdentityError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            IdentityError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            IdentityError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            IdentityError::MalformedResponse(ref e) => write!(f, "{}", e),
+            IdentityError::Unknown(ref s) => write!(f, "{}", s),
+            IdentityError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for IdentityError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             IdentityError::MalformedResponse(ref e) => Some(e),
             IdentityError::Client(ref inner) => Some(inner),
@@ -463,10 +486,31 @@ impl<E: Error> Error for IdentityError<E> {
     }
 }
 
+impl<E: Error> From<IdentityError<E>> for crate::SlackError<E> {
+    fn from(err: IdentityError<E>) -> Self {
+        match err {
+            IdentityError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            IdentityError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            IdentityError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            IdentityError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            IdentityError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            IdentityError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            IdentityError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            IdentityError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            IdentityError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            IdentityError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            IdentityError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            IdentityError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            IdentityError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            IdentityError::Unknown(s) => crate::SlackError::Api(s),
+            IdentityError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Gets information about a user.
 ///
 /// Wraps https://api.slack.com/methods/users.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -475,40 +519,76 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.info");
+    let params = vec![Some(("user", request.user.as_str()))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.info");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(InfoError::Client)
         .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result).map_err(InfoError::MalformedResponse)
+            serde_json::from_str::<InfoResponse>(&result.body).map_err(InfoError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct InfoRequest<'a> {
     /// User to get info on
-    pub user: &'a str,
+    pub user: &'a crate::UserId,
+}
+
+impl<'a> InfoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> InfoRequestBuilder {
+        Default::default()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// An owned builder for a [`InfoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequestBuilder {
+    user: Option<crate::UserId>,
+}
+
+impl InfoRequestBuilder {
+    /// User to get info on
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<InfoRequest<'_>, crate::MissingField> {
+        Ok(InfoRequest {
+            user: self.user.as_ref().ok_or(crate::MissingField("user"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub user: Option<::User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::User>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -571,53 +651,29 @@ impl<'a, E: Error> From<&'a str> for InfoError<E> {
 
 impl<E: Error> fmt::Display for InfoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for InfoError<E> {
-    fn description(&self) -> &str {
         match *self {
-            InfoError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            InfoError::UserNotVisible => {
-                "user_not_visible: The requested user is not visible to the calling user"
-            }
-            InfoError::NotAuthed => "not_authed: No authentication token provided.",
-            InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            InfoError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            InfoError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            InfoError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            InfoError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            InfoError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            InfoError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            InfoError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            InfoError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            InfoError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            InfoError::MalformedResponse(ref e) => e.description(),
-            InfoError::Unknown(ref s) => s,
-            InfoError::Client(ref inner) => inner.description(),
+            InfoError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            InfoError::UserNotVisible => write!(f, "user_not_visible: The requested user is not visible to the calling user"),
+            InfoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            InfoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            InfoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            InfoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            InfoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            InfoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            InfoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            InfoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            InfoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            InfoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            InfoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            InfoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            InfoError::Unknown(ref s) => write!(f, "{}", s),
+            InfoError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             InfoError::MalformedResponse(ref e) => Some(e),
             InfoError::Client(ref inner) => Some(inner),
@@ -626,10 +682,32 @@ impl<E: Error> Error for InfoError<E> {
     }
 }
 
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(err: InfoError<E>) -> Self {
+        match err {
+            InfoError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            InfoError::UserNotVisible => crate::SlackError::Api("user_not_visible".to_owned()),
+            InfoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            InfoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            InfoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            InfoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            InfoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            InfoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            InfoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            InfoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            InfoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            InfoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            InfoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            InfoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            InfoError::Unknown(s) => crate::SlackError::Api(s),
+            InfoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Lists all users in a Slack team.
 ///
 /// Wraps https://api.slack.com/methods/users.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -638,20 +716,22 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
         request.presence.map(|presence| {
             ("presence", if presence { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.list");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.list");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(ListError::Client)
         .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result).map_err(ListError::MalformedResponse)
+            serde_json::from_str::<ListResponse>(&result.body).map_err(ListError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -662,21 +742,54 @@ pub struct ListRequest {
     pub presence: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl ListRequest {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> ListRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`ListRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct ListRequestBuilder {
+    presence: Option<bool>,
+}
+
+impl ListRequestBuilder {
+    /// Whether to include presence data in the output
+    pub fn presence(mut self, presence: bool) -> Self {
+        self.presence = Some(presence);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<ListRequest, crate::MissingField> {
+        Ok(ListRequest {
+            presence: self.presence,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ListResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
-    pub members: Option<Vec<::User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::User>>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -733,49 +846,27 @@ impl<'a, E: Error> From<&'a str> for ListError<E> {
 
 impl<E: Error> fmt::Display for ListError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for ListError<E> {
-    fn description(&self) -> &str {
         match *self {
-            ListError::NotAuthed => "not_authed: No authentication token provided.",
-            ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            ListError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            ListError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            ListError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            ListError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            ListError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            ListError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            ListError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            ListError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            ListError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            ListError::MalformedResponse(ref e) => e.description(),
-            ListError::Unknown(ref s) => s,
-            ListError::Client(ref inner) => inner.description(),
+            ListError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            ListError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            ListError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            ListError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            ListError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            ListError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            ListError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            ListError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            ListError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            ListError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            ListError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            ListError::MalformedResponse(ref e) => write!(f, "{}", e),
+            ListError::Unknown(ref s) => write!(f, "{}", s),
+            ListError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             ListError::MalformedResponse(ref e) => Some(e),
             ListError::Client(ref inner) => Some(inner),
@@ -784,41 +875,68 @@ impl<E: Error> Error for ListError<E> {
     }
 }
 
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(err: ListError<E>) -> Self {
+        match err {
+            ListError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            ListError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            ListError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            ListError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            ListError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            ListError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            ListError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            ListError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            ListError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            ListError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            ListError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            ListError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            ListError::Unknown(s) => crate::SlackError::Api(s),
+            ListError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Marks a user as active.
 ///
 /// Wraps https://api.slack.com/methods/users.setActive
-
 pub fn set_active<R>(client: &R, token: &str) -> Result<SetActiveResponse, SetActiveError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = &[("token", token)];
-    let url = ::get_slack_url_for_method("users.setActive");
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setActive");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&[]),
+        })
         .map_err(SetActiveError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetActiveResponse>(&result).map_err(
+            serde_json::from_str::<SetActiveResponse>(&result.body).map_err(
                 SetActiveError::MalformedResponse,
             )
         })
         .and_then(|o| o.into())
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetActiveResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetActiveResponse, SetActiveError<E>>> for SetActiveResponse {
-    fn into(self) -> Result<SetActiveResponse, SetActiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetActiveResponse> for Result<SetActiveResponse, SetActiveError<E>> {
+    fn from(response: SetActiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -875,62 +993,307 @@ impl<'a, E: Error> From<&'a str> for SetActiveError<E> {
 
 impl<E: Error> fmt::Display for SetActiveError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+        match *self {
+            SetActiveError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetActiveError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetActiveError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetActiveError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetActiveError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetActiveError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetActiveError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetActiveError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetActiveError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetActiveError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetActiveError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetActiveError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetActiveError::Unknown(ref s) => write!(f, "{}", s),
+            SetActiveError::Client(ref inner) => write!(f, "{}", inner),
+        }
     }
 }
 
-impl<E: Error> Error for SetActiveError<E> {
-    fn description(&self) -> &str {
+impl<E: Error + 'static> Error for SetActiveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            SetActiveError::NotAuthed => "not_authed: No authentication token provided.",
-            SetActiveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetActiveError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetActiveError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetActiveError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetActiveError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetActiveError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetActiveError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetActiveError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetActiveError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetActiveError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetActiveError::MalformedResponse(ref e) => e.description(),
-            SetActiveError::Unknown(ref s) => s,
-            SetActiveError::Client(ref inner) => inner.description(),
+            SetActiveError::MalformedResponse(ref e) => Some(e),
+            SetActiveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error> From<SetActiveError<E>> for crate::SlackError<E> {
+    fn from(err: SetActiveError<E>) -> Self {
+        match err {
+            SetActiveError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetActiveError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetActiveError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetActiveError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetActiveError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetActiveError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetActiveError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetActiveError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetActiveError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetActiveError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetActiveError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetActiveError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetActiveError::Unknown(s) => crate::SlackError::Api(s),
+            SetActiveError::Client(inner) => crate::SlackError::Client(inner),
         }
     }
+}
+
 
-    fn cause(&self) -> Option<&Error> {
+/// Set the user profile photo
+///
+/// Wraps https://api.slack.com/methods/users.setPhoto
+pub fn set_photo<R>(
+    client: &R,
+    token: &str,
+    request: &SetPhotoRequest,
+) -> Result<SetPhotoResponse, SetPhotoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let crop_x = request.crop_x.map(|crop_x| crop_x.to_string());
+    let crop_y = request.crop_y.map(|crop_y| crop_y.to_string());
+    let crop_w = request.crop_w.map(|crop_w| crop_w.to_string());
+    let params = vec![
+        crop_x.as_ref().map(|crop_x| ("crop_x", &crop_x[..])),
+        crop_y.as_ref().map(|crop_y| ("crop_y", &crop_y[..])),
+        crop_w.as_ref().map(|crop_w| ("crop_w", &crop_w[..])),
+    ];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPhoto");
+    let body = RequestBody::Multipart(
+        &params[..],
+        MultipartFile {
+            name: "image",
+            filename: "image",
+            contents: request.image,
+        },
+    );
+    client
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body,
+        })
+        .map_err(SetPhotoError::Client)
+        .and_then(|result| {
+            serde_json::from_str::<SetPhotoResponse>(&result.body)
+                .map_err(SetPhotoError::MalformedResponse)
+        })
+        .and_then(|o| o.into())
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetPhotoRequest<'a> {
+    /// File contents via multipart/form-data.
+    pub image: &'a [u8],
+    /// X coordinate of top-left corner of crop box
+    pub crop_x: Option<u32>,
+    /// Y coordinate of top-left corner of crop box
+    pub crop_y: Option<u32>,
+    /// Width/height of crop box (always square)
+    pub crop_w: Option<u32>,
+}
+
+impl<'a> SetPhotoRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetPhotoRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetPhotoRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetPhotoRequestBuilder {
+    image: Option<Vec<u8>>,
+    crop_x: Option<u32>,
+    crop_y: Option<u32>,
+    crop_w: Option<u32>,
+}
+
+impl SetPhotoRequestBuilder {
+    /// File contents via multipart/form-data.
+    pub fn image<B: Into<Vec<u8>>>(mut self, image: B) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    /// X coordinate of top-left corner of crop box
+    pub fn crop_x(mut self, crop_x: u32) -> Self {
+        self.crop_x = Some(crop_x);
+        self
+    }
+
+    /// Y coordinate of top-left corner of crop box
+    pub fn crop_y(mut self, crop_y: u32) -> Self {
+        self.crop_y = Some(crop_y);
+        self
+    }
+
+    /// Width/height of crop box (always square)
+    pub fn crop_w(mut self, crop_w: u32) -> Self {
+        self.crop_w = Some(crop_w);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetPhotoRequest<'_>, crate::MissingField> {
+        Ok(SetPhotoRequest {
+            image: self.image.as_deref().ok_or(crate::MissingField("image"))?,
+            crop_x: self.crop_x,
+            crop_y: self.crop_y,
+            crop_w: self.crop_w,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SetPhotoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+
+impl<E: Error> From<SetPhotoResponse> for Result<SetPhotoResponse, SetPhotoError<E>> {
+    fn from(response: SetPhotoResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetPhotoError<E: Error> {
+    /// The uploaded image could not be processed
+    BadImage,
+    /// An animated GIF with too many frames was uploaded
+    TooManyFrames,
+    /// The uploaded image had excessive dimensions
+    TooLarge,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetPhotoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "bad_image" => SetPhotoError::BadImage,
+            "too_many_frames" => SetPhotoError::TooManyFrames,
+            "too_large" => SetPhotoError::TooLarge,
+            "not_authed" => SetPhotoError::NotAuthed,
+            "invalid_auth" => SetPhotoError::InvalidAuth,
+            "account_inactive" => SetPhotoError::AccountInactive,
+            "invalid_arg_name" => SetPhotoError::InvalidArgName,
+            "invalid_array_arg" => SetPhotoError::InvalidArrayArg,
+            "invalid_charset" => SetPhotoError::InvalidCharset,
+            "invalid_form_data" => SetPhotoError::InvalidFormData,
+            "invalid_post_type" => SetPhotoError::InvalidPostType,
+            "missing_post_type" => SetPhotoError::MissingPostType,
+            "team_added_to_org" => SetPhotoError::TeamAddedToOrg,
+            "request_timeout" => SetPhotoError::RequestTimeout,
+            _ => SetPhotoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetPhotoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            SetActiveError::MalformedResponse(ref e) => Some(e),
-            SetActiveError::Client(ref inner) => Some(inner),
+            SetPhotoError::BadImage => write!(f, "bad_image: The uploaded image could not be processed"),
+            SetPhotoError::TooManyFrames => write!(f, "too_many_frames: An animated GIF with too many frames was uploaded"),
+            SetPhotoError::TooLarge => write!(f, "too_large: The uploaded image had excessive dimensions"),
+            SetPhotoError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetPhotoError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetPhotoError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetPhotoError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetPhotoError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetPhotoError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetPhotoError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetPhotoError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetPhotoError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetPhotoError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetPhotoError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetPhotoError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetPhotoError::Unknown(ref s) => write!(f, "{}", s),
+            SetPhotoError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SetPhotoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetPhotoError::MalformedResponse(ref e) => Some(e),
+            SetPhotoError::Client(ref inner) => Some(inner),
             _ => None,
         }
     }
 }
 
+impl<E: Error> From<SetPhotoError<E>> for crate::SlackError<E> {
+    fn from(err: SetPhotoError<E>) -> Self {
+        match err {
+            SetPhotoError::BadImage => crate::SlackError::Api("bad_image".to_owned()),
+            SetPhotoError::TooManyFrames => crate::SlackError::Api("too_many_frames".to_owned()),
+            SetPhotoError::TooLarge => crate::SlackError::Api("too_large".to_owned()),
+            SetPhotoError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetPhotoError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetPhotoError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetPhotoError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetPhotoError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetPhotoError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetPhotoError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetPhotoError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetPhotoError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetPhotoError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetPhotoError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetPhotoError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetPhotoError::Unknown(s) => crate::SlackError::Api(s),
+            SetPhotoError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 
 /// Manually sets user presence.
 ///
 /// Wraps https://api.slack.com/methods/users.setPresence
-
 pub fn set_presence<R>(
     client: &R,
     token: &str,
@@ -939,15 +1302,18 @@ pub fn set_presence<R>(
 where
     R: SlackWebRequestSender,
 {
-
-    let params = vec![Some(("token", token)), Some(("presence", request.presence))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.setPresence");
+    let params = vec![Some(("presence", request.presence))];
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.setPresence");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SetPresenceError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetPresenceResponse>(&result)
+            serde_json::from_str::<SetPresenceResponse>(&result.body)
                 .map_err(SetPresenceError::MalformedResponse)
         })
         .and_then(|o| o.into())
@@ -959,20 +1325,52 @@ pub struct SetPresenceRequest<'a> {
     pub presence: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> SetPresenceRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetPresenceRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetPresenceRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetPresenceRequestBuilder {
+    presence: Option<String>,
+}
+
+impl SetPresenceRequestBuilder {
+    /// Either auto or away
+    pub fn presence<S: Into<String>>(mut self, presence: S) -> Self {
+        self.presence = Some(presence.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetPresenceRequest<'_>, crate::MissingField> {
+        Ok(SetPresenceRequest {
+            presence: self.presence.as_deref().ok_or(crate::MissingField("presence"))?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetPresenceResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetPresenceResponse, SetPresenceError<E>>> for SetPresenceResponse {
-    fn into(self) -> Result<SetPresenceResponse, SetPresenceError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetPresenceResponse> for Result<SetPresenceResponse, SetPresenceError<E>> {
+    fn from(response: SetPresenceResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -1032,52 +1430,28 @@ impl<'a, E: Error> From<&'a str> for SetPresenceError<E> {
 
 impl<E: Error> fmt::Display for SetPresenceError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for SetPresenceError<E> {
-    fn description(&self) -> &str {
         match *self {
-            SetPresenceError::InvalidPresence => {
-                "invalid_presence: Value passed for presence was invalid."
-            }
-            SetPresenceError::NotAuthed => "not_authed: No authentication token provided.",
-            SetPresenceError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetPresenceError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetPresenceError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetPresenceError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetPresenceError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetPresenceError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetPresenceError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetPresenceError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetPresenceError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetPresenceError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetPresenceError::MalformedResponse(ref e) => e.description(),
-            SetPresenceError::Unknown(ref s) => s,
-            SetPresenceError::Client(ref inner) => inner.description(),
+            SetPresenceError::InvalidPresence => write!(f, "invalid_presence: Value passed for presence was invalid."),
+            SetPresenceError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetPresenceError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetPresenceError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetPresenceError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetPresenceError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetPresenceError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetPresenceError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetPresenceError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetPresenceError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetPresenceError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetPresenceError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetPresenceError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetPresenceError::Unknown(ref s) => write!(f, "{}", s),
+            SetPresenceError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for SetPresenceError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SetPresenceError::MalformedResponse(ref e) => Some(e),
             SetPresenceError::Client(ref inner) => Some(inner),
@@ -1085,3 +1459,109 @@ impl<E: Error> Error for SetPresenceError<E> {
         }
     }
 }
+
+impl<E: Error> From<SetPresenceError<E>> for crate::SlackError<E> {
+    fn from(err: SetPresenceError<E>) -> Self {
+        match err {
+            SetPresenceError::InvalidPresence => crate::SlackError::Api("invalid_presence".to_owned()),
+            SetPresenceError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetPresenceError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetPresenceError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetPresenceError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetPresenceError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetPresenceError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetPresenceError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetPresenceError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetPresenceError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetPresenceError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetPresenceError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetPresenceError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetPresenceError::Unknown(s) => crate::SlackError::Api(s),
+            SetPresenceError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`users`](users/index.html) module.
+    pub fn users(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Delete the user profile photo
+    ///
+    /// Wraps https://api.slack.com/methods/users.deletePhoto
+    pub fn delete_photo(&self) -> Result<DeletePhotoResponse, DeletePhotoError<S::Error>> {
+        delete_photo(self.slack.sender(), self.slack.token())
+    }
+
+    /// Gets user presence information.
+    ///
+    /// Wraps https://api.slack.com/methods/users.getPresence
+    pub fn get_presence(
+        &self,
+        request: &GetPresenceRequest,
+    ) -> Result<GetPresenceResponse, GetPresenceError<S::Error>> {
+        get_presence(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Get a user's identity.
+    ///
+    /// Wraps https://api.slack.com/methods/users.identity
+    pub fn identity(&self) -> Result<IdentityResponse, IdentityError<S::Error>> {
+        identity(self.slack.sender(), self.slack.token())
+    }
+
+    /// Gets information about a user.
+    ///
+    /// Wraps https://api.slack.com/methods/users.info
+    pub fn info(&self, request: &InfoRequest) -> Result<InfoResponse, InfoError<S::Error>> {
+        info(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Lists all users in a Slack team.
+    ///
+    /// Wraps https://api.slack.com/methods/users.list
+    pub fn list(&self, request: &ListRequest) -> Result<ListResponse, ListError<S::Error>> {
+        list(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Marks a user as active.
+    ///
+    /// Wraps https://api.slack.com/methods/users.setActive
+    pub fn set_active(&self) -> Result<SetActiveResponse, SetActiveError<S::Error>> {
+        set_active(self.slack.sender(), self.slack.token())
+    }
+
+    /// Set the user profile photo
+    ///
+    /// Wraps https://api.slack.com/methods/users.setPhoto
+    pub fn set_photo(
+        &self,
+        request: &SetPhotoRequest,
+    ) -> Result<SetPhotoResponse, SetPhotoError<S::Error>> {
+        set_photo(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Manually sets user presence.
+    ///
+    /// Wraps https://api.slack.com/methods/users.setPresence
+    pub fn set_presence(
+        &self,
+        request: &SetPresenceRequest,
+    ) -> Result<SetPresenceResponse, SetPresenceError<S::Error>> {
+        set_presence(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -6,14 +6,12 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-use serde_json;
 
-use requests::SlackWebRequestSender;
+use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender};
 
 /// Retrieves a user's profile information.
 ///
 /// Wraps https://api.slack.com/methods/users.profile.get
-
 pub fn get<R>(
     client: &R,
     token: &str,
@@ -22,21 +20,23 @@ pub fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
         request.include_labels.map(|include_labels| {
             ("include_labels", if include_labels { "1" } else { "0" })
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.profile.get");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.get");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(GetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result).map_err(GetError::MalformedResponse)
+            serde_json::from_str::<GetResponse>(&result.body).map_err(GetError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -44,26 +44,67 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct GetRequest<'a> {
     /// User to retrieve profile info for
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
     /// Include labels for each ID in custom profile fields
     pub include_labels: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> GetRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> GetRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`GetRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct GetRequestBuilder {
+    user: Option<crate::UserId>,
+    include_labels: Option<bool>,
+}
+
+impl GetRequestBuilder {
+    /// User to retrieve profile info for
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Include labels for each ID in custom profile fields
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.include_labels = Some(include_labels);
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<GetRequest<'_>, crate::MissingField> {
+        Ok(GetRequest {
+            user: self.user.as_ref(),
+            include_labels: self.include_labels,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub profile: Option<::UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<crate::UserProfile>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetResponse> for Result<GetResponse, GetError<E>> {
+    fn from(response: GetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -126,51 +167,29 @@ impl<'a, E: Error> From<&'a str> for GetError<E> {
 
 impl<E: Error> fmt::Display for GetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for GetError<E> {
-    fn description(&self) -> &str {
         match *self {
-            GetError::UserNotFound => "user_not_found: Value passed for user was invalid.",
-            GetError::NotAuthed => "not_authed: No authentication token provided.",
-            GetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            GetError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            GetError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            GetError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            GetError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            GetError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            GetError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            GetError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            GetError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            GetError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            GetError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            GetError::MalformedResponse(ref e) => e.description(),
-            GetError::Unknown(ref s) => s,
-            GetError::Client(ref inner) => inner.description(),
+            GetError::UserNotFound => write!(f, "user_not_found: Value passed for user was invalid."),
+            GetError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            GetError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            GetError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            GetError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            GetError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            GetError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            GetError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            GetError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            GetError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            GetError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            GetError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            GetError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            GetError::MalformedResponse(ref e) => write!(f, "{}", e),
+            GetError::Unknown(ref s) => write!(f, "{}", s),
+            GetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for GetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             GetError::MalformedResponse(ref e) => Some(e),
             GetError::Client(ref inner) => Some(inner),
@@ -179,10 +198,32 @@ impl<E: Error> Error for GetError<E> {
     }
 }
 
+impl<E: Error> From<GetError<E>> for crate::SlackError<E> {
+    fn from(err: GetError<E>) -> Self {
+        match err {
+            GetError::UserNotFound => crate::SlackError::Api("user_not_found".to_owned()),
+            GetError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            GetError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            GetError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            GetError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            GetError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            GetError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            GetError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            GetError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            GetError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            GetError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            GetError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            GetError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            GetError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            GetError::Unknown(s) => crate::SlackError::Api(s),
+            GetError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
 /// Set the profile information for a user.
 ///
 /// Wraps https://api.slack.com/methods/users.profile.set
-
 pub fn set<R>(
     client: &R,
     token: &str,
@@ -191,21 +232,23 @@ pub fn set<R>(
 where
     R: SlackWebRequestSender,
 {
-
     let params = vec![
-        Some(("token", token)),
-        request.user.map(|user| ("user", user)),
+        request.user.map(|user| ("user", user.as_str())),
         request.profile.map(|profile| ("profile", profile)),
         request.name.map(|name| ("name", name)),
         request.value.map(|value| ("value", value)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
-    let url = ::get_slack_url_for_method("users.profile.set");
+    let params = params.into_iter().flatten().collect::<Vec<_>>();
+    let url = crate::get_slack_url_for_method(client.base_url(), "users.profile.set");
     client
-        .send(&url, &params[..])
+        .send(SlackWebRequest {
+            url: &url,
+            token: Some(token),
+            body: RequestBody::Form(&params[..]),
+        })
         .map_err(SetError::Client)
         .and_then(|result| {
-            serde_json::from_str::<SetResponse>(&result).map_err(SetError::MalformedResponse)
+            serde_json::from_str::<SetResponse>(&result.body).map_err(SetError::MalformedResponse)
         })
         .and_then(|o| o.into())
 }
@@ -213,7 +256,7 @@ where
 #[derive(Clone, Default, Debug)]
 pub struct SetRequest<'a> {
     /// ID of user to change. This argument may only be specified by team admins on paid teams.
-    pub user: Option<&'a str>,
+    pub user: Option<&'a crate::UserId>,
     /// Collection of key:value pairs presented as a URL-encoded JSON hash.
     pub profile: Option<&'a str>,
     /// Name of a single key to set. Usable only if profile is not passed.
@@ -222,21 +265,78 @@ pub struct SetRequest<'a> {
     pub value: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl<'a> SetRequest<'a> {
+    /// Returns a builder for an owned version of this request.
+    pub fn builder() -> SetRequestBuilder {
+        Default::default()
+    }
+}
+
+/// An owned builder for a [`SetRequest`], which can be kept and sent between threads.
+#[derive(Clone, Default, Debug)]
+pub struct SetRequestBuilder {
+    user: Option<crate::UserId>,
+    profile: Option<String>,
+    name: Option<String>,
+    value: Option<String>,
+}
+
+impl SetRequestBuilder {
+    /// ID of user to change. This argument may only be specified by team admins on paid teams.
+    pub fn user(mut self, user: crate::UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Collection of key:value pairs presented as a URL-encoded JSON hash.
+    pub fn profile<S: Into<String>>(mut self, profile: S) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Name of a single key to set. Usable only if profile is not passed.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Value to set a single key to. Usable only if profile is not passed.
+    pub fn value<S: Into<String>>(mut self, value: S) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Returns the request, or the name of the first required field that has not been set.
+    pub fn build(&self) -> Result<SetRequest<'_>, crate::MissingField> {
+        Ok(SetRequest {
+            user: self.user.as_ref(),
+            profile: self.profile.as_deref(),
+            name: self.name.as_deref(),
+            value: self.value.as_deref(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SetResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
     error: Option<String>,
     #[serde(default)]
     ok: bool,
-    pub profile: Option<::UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<crate::UserProfile>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-impl<E: Error> Into<Result<SetResponse, SetError<E>>> for SetResponse {
-    fn into(self) -> Result<SetResponse, SetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetResponse> for Result<SetResponse, SetError<E>> {
+    fn from(response: SetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_ref().map(String::as_ref).unwrap_or("").into())
         }
     }
 }
@@ -314,62 +414,34 @@ impl<'a, E: Error> From<&'a str> for SetError<E> {
 
 impl<E: Error> fmt::Display for SetError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
-    }
-}
-
-impl<E: Error> Error for SetError<E> {
-    fn description(&self) -> &str {
         match *self {
-            SetError::ReservedName => "reserved_name: First or last name are reserved.",
-            SetError::InvalidProfile => {
-                "invalid_profile: Profile object passed in is not valid JSON (make sure it is URL encoded!)."
-            }
-            SetError::ProfileSetFailed => "profile_set_failed: Failed to set user profile.",
-            SetError::NotAdmin => "not_admin: Only admins can update the profile of another user.",
-            SetError::NotAppAdmin => {
-                "not_app_admin: Only team owners and selected members can update the profile of a bot user."
-            }
-            SetError::CannotUpdateAdminUser => {
-                "cannot_update_admin_user: Only a primary owner can update the profile of an admin."
-            }
-            SetError::NotAuthed => "not_authed: No authentication token provided.",
-            SetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
-            SetError::AccountInactive => {
-                "account_inactive: Authentication token is for a deleted user or team."
-            }
-            SetError::UserIsBot => "user_is_bot: This method cannot be called by a bot user.",
-            SetError::InvalidArgName => {
-                "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."
-            }
-            SetError::InvalidArrayArg => {
-                "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."
-            }
-            SetError::InvalidCharset => {
-                "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."
-            }
-            SetError::InvalidFormData => {
-                "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."
-            }
-            SetError::InvalidPostType => {
-                "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."
-            }
-            SetError::MissingPostType => {
-                "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."
-            }
-            SetError::TeamAddedToOrg => {
-                "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."
-            }
-            SetError::RequestTimeout => {
-                "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."
-            }
-            SetError::MalformedResponse(ref e) => e.description(),
-            SetError::Unknown(ref s) => s,
-            SetError::Client(ref inner) => inner.description(),
+            SetError::ReservedName => write!(f, "reserved_name: First or last name are reserved."),
+            SetError::InvalidProfile => write!(f, "invalid_profile: Profile object passed in is not valid JSON (make sure it is URL encoded!)."),
+            SetError::ProfileSetFailed => write!(f, "profile_set_failed: Failed to set user profile."),
+            SetError::NotAdmin => write!(f, "not_admin: Only admins can update the profile of another user."),
+            SetError::NotAppAdmin => write!(f, "not_app_admin: Only team owners and selected members can update the profile of a bot user."),
+            SetError::CannotUpdateAdminUser => write!(f, "cannot_update_admin_user: Only a primary owner can update the profile of an admin."),
+            SetError::NotAuthed => write!(f, "not_authed: No authentication token provided."),
+            SetError::InvalidAuth => write!(f, "invalid_auth: Invalid authentication token."),
+            SetError::AccountInactive => write!(f, "account_inactive: Authentication token is for a deleted user or team."),
+            SetError::UserIsBot => write!(f, "user_is_bot: This method cannot be called by a bot user."),
+            SetError::InvalidArgName => write!(f, "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call."),
+            SetError::InvalidArrayArg => write!(f, "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API."),
+            SetError::InvalidCharset => write!(f, "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1."),
+            SetError::InvalidFormData => write!(f, "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid."),
+            SetError::InvalidPostType => write!(f, "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain."),
+            SetError::MissingPostType => write!(f, "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header."),
+            SetError::TeamAddedToOrg => write!(f, "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete."),
+            SetError::RequestTimeout => write!(f, "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated."),
+            SetError::MalformedResponse(ref e) => write!(f, "{}", e),
+            SetError::Unknown(ref s) => write!(f, "{}", s),
+            SetError::Client(ref inner) => write!(f, "{}", inner),
         }
     }
+}
 
-    fn cause(&self) -> Option<&Error> {
+impl<E: Error + 'static> Error for SetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
             SetError::MalformedResponse(ref e) => Some(e),
             SetError::Client(ref inner) => Some(inner),
@@ -377,3 +449,64 @@ impl<E: Error> Error for SetError<E> {
         }
     }
 }
+
+impl<E: Error> From<SetError<E>> for crate::SlackError<E> {
+    fn from(err: SetError<E>) -> Self {
+        match err {
+            SetError::ReservedName => crate::SlackError::Api("reserved_name".to_owned()),
+            SetError::InvalidProfile => crate::SlackError::Api("invalid_profile".to_owned()),
+            SetError::ProfileSetFailed => crate::SlackError::Api("profile_set_failed".to_owned()),
+            SetError::NotAdmin => crate::SlackError::Api("not_admin".to_owned()),
+            SetError::NotAppAdmin => crate::SlackError::Api("not_app_admin".to_owned()),
+            SetError::CannotUpdateAdminUser => crate::SlackError::Api("cannot_update_admin_user".to_owned()),
+            SetError::NotAuthed => crate::SlackError::Api("not_authed".to_owned()),
+            SetError::InvalidAuth => crate::SlackError::Api("invalid_auth".to_owned()),
+            SetError::AccountInactive => crate::SlackError::Api("account_inactive".to_owned()),
+            SetError::UserIsBot => crate::SlackError::Api("user_is_bot".to_owned()),
+            SetError::InvalidArgName => crate::SlackError::Api("invalid_arg_name".to_owned()),
+            SetError::InvalidArrayArg => crate::SlackError::Api("invalid_array_arg".to_owned()),
+            SetError::InvalidCharset => crate::SlackError::Api("invalid_charset".to_owned()),
+            SetError::InvalidFormData => crate::SlackError::Api("invalid_form_data".to_owned()),
+            SetError::InvalidPostType => crate::SlackError::Api("invalid_post_type".to_owned()),
+            SetError::MissingPostType => crate::SlackError::Api("missing_post_type".to_owned()),
+            SetError::TeamAddedToOrg => crate::SlackError::Api("team_added_to_org".to_owned()),
+            SetError::RequestTimeout => crate::SlackError::Api("request_timeout".to_owned()),
+            SetError::MalformedResponse(e) => crate::SlackError::MalformedResponse(e),
+            SetError::Unknown(s) => crate::SlackError::Api(s),
+            SetError::Client(inner) => crate::SlackError::Client(inner),
+        }
+    }
+}
+
+
+/// The methods of this module, called with the sender and token of a [`Slack`](../struct.Slack.html)
+/// client.
+pub struct Methods<'a, S> {
+    slack: &'a crate::Slack<S>,
+}
+
+impl<S> crate::Slack<S> {
+    /// Returns the methods of the [`users_profile`](users_profile/index.html) module.
+    pub fn users_profile(&self) -> Methods<'_, S> {
+        Methods { slack: self }
+    }
+}
+
+impl<'a, S> Methods<'a, S>
+where
+    S: SlackWebRequestSender,
+{
+    /// Retrieves a user's profile information.
+    ///
+    /// Wraps https://api.slack.com/methods/users.profile.get
+    pub fn get(&self, request: &GetRequest) -> Result<GetResponse, GetError<S::Error>> {
+        get(self.slack.sender(), self.slack.token(), request)
+    }
+
+    /// Set the profile information for a user.
+    ///
+    /// Wraps https://api.slack.com/methods/users.profile.set
+    pub fn set(&self, request: &SetRequest) -> Result<SetResponse, SetError<S::Error>> {
+        set(self.slack.sender(), self.slack.token(), request)
+    }
+}
@@ -0,0 +1,323 @@
+//! Iterators that walk every page of the methods that return their results in pages.
+//!
+//! Each function takes the same arguments as the method it wraps and returns an iterator over
+//! the individual results, requesting the next page once the previous one has been used up.
+//! The `count` of the request sets how many results are requested per page, and limiting the
+//! number of results with [`Iterator::take`] avoids requesting any pages beyond them.
+//!
+//! An error ends the iteration after it has been returned. With the `async` feature, [`stream`]
+//! has the same functions as `Stream`s that send their requests without blocking.
+//!
+//! ```no_run
+//! # let token = "some_token";
+//! let client = slack_api::default_client().unwrap();
+//! let request = slack_api::channels::HistoryRequest::builder().channel("C1234567890".parse().unwrap());
+//! let request = request.build().unwrap();
+//! for message in slack_api::pagination::channels_history(&client, &token, &request).take(500) {
+//!     println!("{:?}", message.unwrap());
+//! }
+//! ```
+
+use std::vec;
+
+#[cfg(feature = "async")]
+pub mod stream;
+
+use crate::requests::SlackWebRequestSender;
+use crate::{channels, files, groups, im, mpim, reactions, search, stars, File, Message, Paging, Ts};
+
+/// Where the next page of results starts.
+enum Cursor {
+    /// The first page, as given by the request.
+    Start,
+    /// The page of messages before this timestamp.
+    Before(Ts),
+    /// The page with this number.
+    Page(u32),
+    /// There are no more pages.
+    Done,
+}
+
+/// An iterator over the results of every page returned by `fetch`.
+struct Paginated<T, F> {
+    fetch: F,
+    next: Cursor,
+    items: vec::IntoIter<T>,
+}
+
+impl<T, F> Paginated<T, F> {
+    fn new<E>(fetch: F) -> Self
+    where
+        F: FnMut(&Cursor) -> Result<(Vec<T>, Cursor), E>,
+    {
+        Paginated {
+            fetch,
+            next: Cursor::Start,
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<T, E, F> Iterator for Paginated<T, F>
+where
+    F: FnMut(&Cursor) -> Result<(Vec<T>, Cursor), E>,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+            if let Cursor::Done = self.next {
+                return None;
+            }
+            match (self.fetch)(&self.next) {
+                Ok((items, next)) => {
+                    // An empty page can not be followed by another one
+                    self.next = if items.is_empty() { Cursor::Done } else { next };
+                    self.items = items.into_iter();
+                }
+                Err(e) => {
+                    self.next = Cursor::Done;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the cursor of the page after a page of messages.
+fn next_window(messages: &[Message], has_more: Option<bool>) -> Cursor {
+    match (has_more, messages.last().and_then(Message::ts)) {
+        (Some(true), Some(ts)) => Cursor::Before(ts.clone()),
+        _ => Cursor::Done,
+    }
+}
+
+/// Returns the cursor of the page after the page described by `paging`.
+fn next_page(paging: Option<&Paging>) -> Cursor {
+    match paging {
+        Some(&Paging {
+            page: Some(page),
+            pages: Some(pages),
+            ..
+        }) if page < pages => Cursor::Page(page as u32 + 1),
+        _ => Cursor::Done,
+    }
+}
+
+macro_rules! history {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        pub fn $name<'a, R>(
+            client: &'a R,
+            token: &'a str,
+            request: &$module::HistoryRequest<'a>,
+        ) -> impl Iterator<Item = Result<Message, $module::HistoryError<R::Error>>> + 'a
+        where
+            R: SlackWebRequestSender,
+        {
+            let request = request.clone();
+            Paginated::new(move |cursor| {
+                let response = match *cursor {
+                    Cursor::Before(ref latest) => {
+                        let request = $module::HistoryRequest {
+                            latest: Some(latest),
+                            inclusive: None,
+                            ..request.clone()
+                        };
+                        $module::history(client, token, &request)?
+                    }
+                    _ => $module::history(client, token, &request)?,
+                };
+                let messages = response.messages.unwrap_or_default();
+                let next = next_window(&messages, response.has_more);
+                Ok((messages, next))
+            })
+        }
+    };
+}
+
+history!(
+    /// Walks the history of a channel, from the newest message to the oldest.
+    channels_history,
+    channels
+);
+
+history!(
+    /// Walks the history of a private channel, from the newest message to the oldest.
+    groups_history,
+    groups
+);
+
+history!(
+    /// Walks the history of a direct message channel, from the newest message to the oldest.
+    im_history,
+    im
+);
+
+history!(
+    /// Walks the history of a multiparty direct message channel, from the newest message to the
+    /// oldest.
+    mpim_history,
+    mpim
+);
+
+/// Walks every page of files.
+pub fn files_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &files::ListRequest<'a>,
+) -> impl Iterator<Item = Result<File, files::ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let page = match *cursor {
+            Cursor::Page(page) => Some(page),
+            _ => request.page,
+        };
+        let response = files::list(client, token, &files::ListRequest { page, ..request.clone() })?;
+        Ok((response.files.unwrap_or_default(), next_page(response.paging.as_ref())))
+    })
+}
+
+/// Walks every page of items starred by the user.
+pub fn stars_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &stars::ListRequest,
+) -> impl Iterator<Item = Result<crate::Item, stars::ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let page = match *cursor {
+            Cursor::Page(page) => Some(page),
+            _ => request.page,
+        };
+        let response = stars::list(client, token, &stars::ListRequest { page, ..request.clone() })?;
+        Ok((response.items.unwrap_or_default(), next_page(response.paging.as_ref())))
+    })
+}
+
+/// Walks every page of items reacted to by a user.
+pub fn reactions_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &reactions::ListRequest<'a>,
+) -> impl Iterator<Item = Result<crate::Item, reactions::ListError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let page = match *cursor {
+            Cursor::Page(page) => Some(page),
+            _ => request.page,
+        };
+        let response = reactions::list(client, token, &reactions::ListRequest { page, ..request.clone() })?;
+        Ok((response.items.unwrap_or_default(), next_page(response.paging.as_ref())))
+    })
+}
+
+/// Walks every page of messages matching a search query.
+pub fn search_messages<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &search::MessagesRequest<'a>,
+) -> impl Iterator<Item = Result<Message, search::MessagesError<R::Error>>> + 'a
+where
+    R: SlackWebRequestSender,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let page = match *cursor {
+            Cursor::Page(page) => Some(page),
+            _ => request.page,
+        };
+        let response = search::messages(client, token, &search::MessagesRequest { page, ..request.clone() })?;
+        let (matches, paging) = response.messages.map_or((None, None), |m| (m.matches, m.paging));
+        Ok((matches.unwrap_or_default(), next_page(paging.as_ref())))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock::MockSender;
+
+    #[test]
+    fn test_history_walks_timestamp_windows() {
+        let client = MockSender::new();
+        client
+            .expect("channels.history")
+            .param("channel", "C1")
+            .param("inclusive", "1")
+            .respond(r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "3.0", "text": "c"},
+                {"type": "message", "ts": "2.0", "text": "b"}
+            ]}"#);
+        client
+            .expect("channels.history")
+            .param("latest", "2.0")
+            .respond(r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1.0", "text": "a"}
+            ]}"#);
+
+        let request = crate::channels::HistoryRequest::builder()
+            .channel("C1".parse().unwrap())
+            .inclusive(true);
+        let request = request.build().unwrap();
+        let ts = super::channels_history(&client, "xoxb-token", &request)
+            .map(|message| message.unwrap().ts().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["3.0", "2.0", "1.0"], ts);
+        assert!(!client.calls()[1].params.contains_key("inclusive"));
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_list_walks_pages_and_stops_at_limit() {
+        let client = MockSender::new();
+        for page in 1..=2 {
+            client
+                .expect("files.list")
+                .param("page", &page.to_string())
+                .respond(&format!(
+                    r#"{{"ok": true, "files": [{{"id": "F{0}a"}}, {{"id": "F{0}b"}}],
+                        "paging": {{"count": 2, "page": {0}, "pages": 3, "total": 6}}}}"#,
+                    page
+                ));
+        }
+
+        let request = crate::files::ListRequest {
+            page: Some(1),
+            ..Default::default()
+        };
+        let ids = super::files_list(&client, "xoxb-token", &request)
+            .take(3)
+            .map(|file| file.unwrap().id.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["F1a", "F1b", "F2a"], ids);
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_errors_end_iteration() {
+        let client = MockSender::new();
+        client
+            .expect("search.messages")
+            .respond(r#"{"ok": false, "error": "not_authed"}"#);
+
+        let request = crate::search::MessagesRequest {
+            query: "hello",
+            ..Default::default()
+        };
+        let mut messages = super::search_messages(&client, "xoxb-token", &request);
+        assert!(messages.next().unwrap().is_err());
+        assert!(messages.next().is_none());
+    }
+}
@@ -0,0 +1,343 @@
+//! Streams that walk every page of the methods that return their results in pages, without
+//! blocking.
+//!
+//! Each function mirrors the iterator of the same name in [`pagination`](super), taking the same
+//! arguments but sending its requests through an [`AsyncSlackWebRequestSender`]. The next page is
+//! only requested once the previous one has been used up, and an error ends the stream after it
+//! has been returned.
+//!
+//! This module is only available with the `async` feature enabled.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::vec;
+
+use futures_core::Stream;
+
+use super::{next_page, next_window, Cursor};
+use crate::async_impl::{channels, files, groups, im, mpim, reactions, search, stars};
+use crate::requests::{AsyncSlackWebRequestSender, BoxFuture};
+use crate::{File, Message};
+
+/// The request of a page, resolving to its results and where the next page starts.
+type Page<'a, T, E> = BoxFuture<'a, Result<(Vec<T>, Cursor), E>>;
+
+/// A stream of the results of every page that `fetch` requests.
+struct Paginated<'a, T, E, F> {
+    fetch: F,
+    next: Cursor,
+    items: vec::IntoIter<T>,
+    pending: Option<Page<'a, T, E>>,
+}
+
+// The pending request is boxed, and nothing else is ever pinned
+impl<T, E, F> Unpin for Paginated<'_, T, E, F> {}
+
+impl<'a, T, E, F> Paginated<'a, T, E, F>
+where
+    F: FnMut(&Cursor) -> Page<'a, T, E>,
+{
+    fn new(fetch: F) -> Self {
+        Paginated {
+            fetch,
+            next: Cursor::Start,
+            items: Vec::new().into_iter(),
+            pending: None,
+        }
+    }
+}
+
+impl<'a, T, E, F> Stream for Paginated<'a, T, E, F>
+where
+    F: FnMut(&Cursor) -> Page<'a, T, E>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.items.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+            if let Cursor::Done = this.next {
+                return Poll::Ready(None);
+            }
+            let (fetch, next) = (&mut this.fetch, &this.next);
+            let page = this.pending.get_or_insert_with(|| fetch(next));
+            let result = match page.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            };
+            this.pending = None;
+            match result {
+                Ok((items, next)) => {
+                    // An empty page can not be followed by another one
+                    this.next = if items.is_empty() { Cursor::Done } else { next };
+                    this.items = items.into_iter();
+                }
+                Err(e) => {
+                    this.next = Cursor::Done;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+macro_rules! history {
+    ($(#[$attr:meta])* $name:ident, $module:ident) => {
+        $(#[$attr])*
+        pub fn $name<'a, R>(
+            client: &'a R,
+            token: &'a str,
+            request: &$module::HistoryRequest<'a>,
+        ) -> impl Stream<Item = Result<Message, $module::HistoryError<R::Error>>> + 'a
+        where
+            R: AsyncSlackWebRequestSender + Sync,
+        {
+            let request = request.clone();
+            Paginated::new(move |cursor| {
+                let request = request.clone();
+                let latest = match *cursor {
+                    Cursor::Before(ref latest) => Some(latest.clone()),
+                    _ => None,
+                };
+                Box::pin(async move {
+                    let response = match latest {
+                        Some(ref latest) => {
+                            let request = $module::HistoryRequest {
+                                latest: Some(latest),
+                                inclusive: None,
+                                ..request
+                            };
+                            $module::history(client, token, &request).await?
+                        }
+                        None => $module::history(client, token, &request).await?,
+                    };
+                    let messages = response.messages.unwrap_or_default();
+                    let next = next_window(&messages, response.has_more);
+                    Ok((messages, next))
+                })
+            })
+        }
+    };
+}
+
+history!(
+    /// Walks the history of a channel, from the newest message to the oldest.
+    channels_history,
+    channels
+);
+
+history!(
+    /// Walks the history of a private channel, from the newest message to the oldest.
+    groups_history,
+    groups
+);
+
+history!(
+    /// Walks the history of a direct message channel, from the newest message to the oldest.
+    im_history,
+    im
+);
+
+history!(
+    /// Walks the history of a multiparty direct message channel, from the newest message to the
+    /// oldest.
+    mpim_history,
+    mpim
+);
+
+/// Returns the page to request for `cursor`, which starts at the page of the request.
+fn page_of(cursor: &Cursor, first: Option<u32>) -> Option<u32> {
+    match *cursor {
+        Cursor::Page(page) => Some(page),
+        _ => first,
+    }
+}
+
+/// Walks every page of files.
+pub fn files_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &files::ListRequest<'a>,
+) -> impl Stream<Item = Result<File, files::ListError<R::Error>>> + 'a
+where
+    R: AsyncSlackWebRequestSender + Sync,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let request = files::ListRequest {
+            page: page_of(cursor, request.page),
+            ..request.clone()
+        };
+        Box::pin(async move {
+            let response = files::list(client, token, &request).await?;
+            Ok((response.files.unwrap_or_default(), next_page(response.paging.as_ref())))
+        })
+    })
+}
+
+/// Walks every page of items starred by the user.
+pub fn stars_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &stars::ListRequest,
+) -> impl Stream<Item = Result<crate::Item, stars::ListError<R::Error>>> + 'a
+where
+    R: AsyncSlackWebRequestSender + Sync,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let request = stars::ListRequest {
+            page: page_of(cursor, request.page),
+            ..request.clone()
+        };
+        Box::pin(async move {
+            let response = stars::list(client, token, &request).await?;
+            Ok((response.items.unwrap_or_default(), next_page(response.paging.as_ref())))
+        })
+    })
+}
+
+/// Walks every page of items reacted to by a user.
+pub fn reactions_list<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &reactions::ListRequest<'a>,
+) -> impl Stream<Item = Result<crate::Item, reactions::ListError<R::Error>>> + 'a
+where
+    R: AsyncSlackWebRequestSender + Sync,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let request = reactions::ListRequest {
+            page: page_of(cursor, request.page),
+            ..request.clone()
+        };
+        Box::pin(async move {
+            let response = reactions::list(client, token, &request).await?;
+            Ok((response.items.unwrap_or_default(), next_page(response.paging.as_ref())))
+        })
+    })
+}
+
+/// Walks every page of messages matching a search query.
+pub fn search_messages<'a, R>(
+    client: &'a R,
+    token: &'a str,
+    request: &search::MessagesRequest<'a>,
+) -> impl Stream<Item = Result<Message, search::MessagesError<R::Error>>> + 'a
+where
+    R: AsyncSlackWebRequestSender + Sync,
+{
+    let request = request.clone();
+    Paginated::new(move |cursor| {
+        let request = search::MessagesRequest {
+            page: page_of(cursor, request.page),
+            ..request.clone()
+        };
+        Box::pin(async move {
+            let response = search::messages(client, token, &request).await?;
+            let (matches, paging) = response.messages.map_or((None, None), |m| (m.matches, m.paging));
+            Ok((matches.unwrap_or_default(), next_page(paging.as_ref())))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use futures_core::Stream;
+
+    use crate::mock::MockSender;
+
+    /// Returns the first `limit` items of `stream`, whose futures are all ready when polled.
+    fn take<S: Stream>(stream: S, limit: usize) -> Vec<S::Item> {
+        let mut stream = pin!(stream);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut items = Vec::new();
+        while items.len() < limit {
+            match stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Some(item)) => items.push(item),
+                Poll::Ready(None) => break,
+                Poll::Pending => panic!("the mock sender answers straight away"),
+            }
+        }
+        items
+    }
+
+    #[test]
+    fn test_history_walks_timestamp_windows() {
+        let client = MockSender::new();
+        client
+            .expect("channels.history")
+            .param("channel", "C1")
+            .param("inclusive", "1")
+            .respond(r#"{"ok": true, "has_more": true, "messages": [
+                {"type": "message", "ts": "3.0", "text": "c"},
+                {"type": "message", "ts": "2.0", "text": "b"}
+            ]}"#);
+        client
+            .expect("channels.history")
+            .param("latest", "2.0")
+            .respond(r#"{"ok": true, "has_more": false, "messages": [
+                {"type": "message", "ts": "1.0", "text": "a"}
+            ]}"#);
+
+        let request = crate::channels::HistoryRequest::builder()
+            .channel("C1".parse().unwrap())
+            .inclusive(true);
+        let request = request.build().unwrap();
+        let ts = take(super::channels_history(&client, "xoxb-token", &request), usize::MAX)
+            .into_iter()
+            .map(|message| message.unwrap().ts().unwrap().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["3.0", "2.0", "1.0"], ts);
+        assert!(!client.calls()[1].params.contains_key("inclusive"));
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_list_walks_pages_and_stops_at_limit() {
+        let client = MockSender::new();
+        for page in 1..=2 {
+            client
+                .expect("files.list")
+                .param("page", &page.to_string())
+                .respond(&format!(
+                    r#"{{"ok": true, "files": [{{"id": "F{0}a"}}, {{"id": "F{0}b"}}],
+                        "paging": {{"count": 2, "page": {0}, "pages": 3, "total": 6}}}}"#,
+                    page
+                ));
+        }
+
+        let request = crate::files::ListRequest {
+            page: Some(1),
+            ..Default::default()
+        };
+        let ids = take(super::files_list(&client, "xoxb-token", &request), 3)
+            .into_iter()
+            .map(|file| file.unwrap().id.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["F1a", "F1b", "F2a"], ids);
+        client.assert_all_called();
+    }
+
+    #[test]
+    fn test_errors_end_the_stream() {
+        let client = MockSender::new();
+        client
+            .expect("search.messages")
+            .respond(r#"{"ok": false, "error": "not_authed"}"#);
+
+        let request = crate::search::MessagesRequest {
+            query: "hello",
+            ..Default::default()
+        };
+        let messages = take(super::search_messages(&client, "xoxb-token", &request), usize::MAX);
+        assert!(matches!(messages[..], [Err(crate::search::MessagesError::NotAuthed)]));
+    }
+}
@@ -1,6 +1,81 @@
 //! Functionality for sending requests to Slack.
 
 use std::error;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::time::Duration;
+
+/// The URL the Web API methods are found under, unless a sender uses a different one.
+pub const DEFAULT_BASE_URL: &str = "https://slack.com/api";
+
+/// A single call to a Slack Web API method.
+///
+/// Every call is sent as an HTTP `POST` to `url`. The token, if any, must be sent as an
+/// `Authorization: Bearer` header rather than as a parameter, so it never ends up in a URL.
+#[derive(Clone, Copy, Debug)]
+pub struct SlackWebRequest<'a> {
+    /// The full URL of the method being called.
+    pub url: &'a str,
+    /// The token to authenticate with, for methods that require one.
+    pub token: Option<&'a str>,
+    /// The parameters of the call.
+    pub body: RequestBody<'a>,
+}
+
+/// How the parameters of a [`SlackWebRequest`] are sent.
+///
+/// Each generated method picks the encoding it is sent with. Methods that take JSON-encoded
+/// arguments such as `attachments` are sent as JSON, methods that upload a file as multipart,
+/// and everything else as a form.
+#[derive(Clone, Copy, Debug)]
+pub enum RequestBody<'a> {
+    /// Send the parameters as an `application/x-www-form-urlencoded` body.
+    Form(&'a [(&'a str, &'a str)]),
+    /// Send the parameters as an `application/json; charset=utf-8` body.
+    Json(&'a serde_json::Value),
+    /// Send the parameters and a file as a `multipart/form-data` body.
+    Multipart(&'a [(&'a str, &'a str)], MultipartFile<'a>),
+}
+
+/// A file sent as part of a [`RequestBody::Multipart`] body.
+#[derive(Clone, Copy, Debug)]
+pub struct MultipartFile<'a> {
+    /// The name of the form field the file is sent as.
+    pub name: &'a str,
+    /// The filename reported for the file.
+    pub filename: &'a str,
+    /// The contents of the file.
+    pub contents: &'a [u8],
+}
+
+/// The HTTP response to a [`SlackWebRequest`].
+#[derive(Clone, Debug, Default)]
+pub struct SlackWebResponse {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The headers of the response, in the order they were received.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: String,
+}
+
+impl SlackWebResponse {
+    /// Returns the value of the first header called `name`, ignoring case.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| &value[..])
+    }
+
+    /// Returns how long Slack asked to wait before making another request, as sent in the
+    /// `Retry-After` header of rate limited responses.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.header("Retry-After")?.trim().parse().ok().map(Duration::from_secs)
+    }
+}
 
 /// Functionality for sending authenticated and unauthenticated requests to Slack via HTTP.
 ///
@@ -9,34 +84,152 @@ use std::error;
 pub trait SlackWebRequestSender {
     type Error: error::Error;
 
-    /// Make an API call to Slack. Takes a map of parameters that get appended to the request as query
-    /// params.
-    fn send(&self, method: &str, params: &[(&str, &str)]) -> Result<String, Self::Error>;
+    /// Make an API call to Slack and return its response.
+    fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error>;
+
+    /// The URL that method names are appended to when building the URL of a request.
+    ///
+    /// Defaults to [`DEFAULT_BASE_URL`]. Wrap a sender in [`WithBaseUrl`] to use another one.
+    fn base_url(&self) -> &str {
+        DEFAULT_BASE_URL
+    }
+}
+
+/// A boxed future returned by [`AsyncSlackWebRequestSender`].
+#[cfg(feature = "async")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The non-blocking counterpart to [`SlackWebRequestSender`].
+///
+/// This is used by the functions in [`async_impl`](../async_impl/index.html), which share their
+/// request, response and error types with the blocking API. If you just want to send requests, use
+/// the [`default_async_client()`] function to get a simple request sender.
+#[cfg(feature = "async")]
+pub trait AsyncSlackWebRequestSender {
+    type Error: error::Error;
+
+    /// Make an API call to Slack and return its response.
+    fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>>;
+
+    /// The URL that method names are appended to when building the URL of a request.
+    ///
+    /// Defaults to [`DEFAULT_BASE_URL`]. Wrap a sender in [`WithBaseUrl`] to use another one.
+    fn base_url(&self) -> &str {
+        DEFAULT_BASE_URL
+    }
+}
+
+/// A sender that sends requests through another sender, but to methods under a different base URL.
+///
+/// This points every API method at another host, such as a mock server, a proxy or an Enterprise
+/// Grid or GovSlack host.
+///
+/// # Examples
+///
+/// ```
+/// use slack_api::requests::{default_client, WithBaseUrl};
+///
+/// let client = WithBaseUrl::new(default_client().unwrap(), "http://localhost:8080/api");
+/// ```
+#[derive(Clone, Debug)]
+pub struct WithBaseUrl<S> {
+    inner: S,
+    base_url: String,
+}
+
+impl<S> WithBaseUrl<S> {
+    /// Wraps `inner`, sending requests to the methods under `base_url`.
+    pub fn new<U: Into<String>>(inner: S, base_url: U) -> Self {
+        WithBaseUrl {
+            inner,
+            base_url: base_url.into(),
+        }
+    }
+
+    /// Returns the wrapped sender.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwraps this sender, returning the wrapped sender.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: SlackWebRequestSender> SlackWebRequestSender for WithBaseUrl<S> {
+    type Error = S::Error;
+
+    fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error> {
+        self.inner.send(request)
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: AsyncSlackWebRequestSender> AsyncSlackWebRequestSender for WithBaseUrl<S> {
+    type Error = S::Error;
+
+    fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>> {
+        self.inner.send(request)
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
 }
 
 #[cfg(feature = "reqwest")]
 mod reqwest_support {
-    extern crate reqwest;
-    pub use self::reqwest::Client;
-    pub use self::reqwest::Error;
+    pub use reqwest::blocking::Client;
+    pub use reqwest::Error;
 
-    use std::io::Read;
+    use reqwest::blocking::multipart::{Form, Part};
+    use reqwest::header::{HeaderMap, CONTENT_TYPE};
 
-    use super::SlackWebRequestSender;
+    use super::{RequestBody, SlackWebRequest, SlackWebRequestSender, SlackWebResponse};
+
+    /// Copies the headers of a `reqwest` response, skipping any that are not valid strings.
+    pub(super) fn collect_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned())))
+            .collect()
+    }
 
-    impl SlackWebRequestSender for reqwest::Client {
+    impl SlackWebRequestSender for Client {
         type Error = reqwest::Error;
 
-        fn send(&self, method_url: &str, params: &[(&str, &str)]) -> Result<String, Self::Error> {
-            let mut url = reqwest::Url::parse(method_url).expect("Unable to parse url");
+        fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error> {
+            let mut builder = self.post(request.url);
 
-            url.query_pairs_mut().extend_pairs(params);
+            if let Some(token) = request.token {
+                builder = builder.bearer_auth(token);
+            }
 
-            let mut response = self.get(url).send()?;
-            let mut res_str = String::new();
-            response.read_to_string(&mut res_str).map_err(reqwest::HyperError::from)?;
+            builder = match request.body {
+                RequestBody::Form(params) => builder.form(params),
+                RequestBody::Json(json) => builder
+                    .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                    .body(json.to_string()),
+                RequestBody::Multipart(params, file) => {
+                    let form = params
+                        .iter()
+                        .fold(Form::new(), |form, &(name, value)| form.text(name.to_owned(), value.to_owned()));
+                    let part = Part::bytes(file.contents.to_vec()).file_name(file.filename.to_owned());
+                    builder.multipart(form.part(file.name.to_owned(), part))
+                }
+            };
 
-            Ok(res_str)
+            let response = builder.send()?;
+            Ok(SlackWebResponse {
+                status: response.status().as_u16(),
+                headers: collect_headers(response.headers()),
+                body: response.text()?,
+            })
         }
     }
 
@@ -49,10 +242,357 @@ mod reqwest_support {
     /// let client = slack_api::requests::default_client().unwrap();
     /// let response = slack_api::channels::list(&client, &token, &Default::default());
     /// ```
-    pub fn default_client() -> Result<reqwest::Client, reqwest::Error> {
-        reqwest::Client::new()
+    pub fn default_client() -> Result<Client, Error> {
+        Client::builder().build()
     }
 }
 
 #[cfg(feature = "reqwest")]
 pub use self::reqwest_support::*;
+
+#[cfg(all(feature = "reqwest", feature = "async"))]
+mod reqwest_async_support {
+    pub use reqwest::Client as AsyncClient;
+
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::multipart::{Form, Part};
+
+    use super::reqwest_support::collect_headers;
+    use super::{AsyncSlackWebRequestSender, BoxFuture, RequestBody, SlackWebRequest, SlackWebResponse};
+
+    impl AsyncSlackWebRequestSender for AsyncClient {
+        type Error = reqwest::Error;
+
+        fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>> {
+            let mut builder = self.post(request.url);
+
+            if let Some(token) = request.token {
+                builder = builder.bearer_auth(token);
+            }
+
+            builder = match request.body {
+                RequestBody::Form(params) => builder.form(params),
+                RequestBody::Json(json) => builder
+                    .header(CONTENT_TYPE, "application/json; charset=utf-8")
+                    .body(json.to_string()),
+                RequestBody::Multipart(params, file) => {
+                    let form = params
+                        .iter()
+                        .fold(Form::new(), |form, &(name, value)| form.text(name.to_owned(), value.to_owned()));
+                    let part = Part::bytes(file.contents.to_vec()).file_name(file.filename.to_owned());
+                    builder.multipart(form.part(file.name.to_owned(), part))
+                }
+            };
+
+            Box::pin(async move {
+                let response = builder.send().await?;
+                Ok(SlackWebResponse {
+                    status: response.status().as_u16(),
+                    headers: collect_headers(response.headers()),
+                    body: response.text().await?,
+                })
+            })
+        }
+    }
+
+    /// Provides a default asynchronous `reqwest` client to give to the functions in
+    /// [`async_impl`](../async_impl/index.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn run() {
+    /// # let token = "some_token";
+    /// let client = slack_api::requests::default_async_client().unwrap();
+    /// let response = slack_api::async_impl::channels::list(&client, &token, &Default::default()).await;
+    /// # }
+    /// ```
+    pub fn default_async_client() -> Result<AsyncClient, reqwest::Error> {
+        AsyncClient::builder().build()
+    }
+}
+
+#[cfg(all(feature = "reqwest", feature = "async"))]
+pub use self::reqwest_async_support::*;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::fmt;
+
+    use super::{MultipartFile, RequestBody, SlackWebRequest, SlackWebRequestSender, SlackWebResponse};
+
+    #[derive(Debug)]
+    struct NeverError;
+
+    impl fmt::Display for NeverError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "never")
+        }
+    }
+
+    impl std::error::Error for NeverError {}
+
+    #[derive(Debug, PartialEq)]
+    enum RecordedBody {
+        Form(Vec<(String, String)>),
+        Json(serde_json::Value),
+        Multipart(Vec<(String, String)>, String, String, Vec<u8>),
+    }
+
+    fn to_owned_params(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params.iter().map(|&(k, v)| (k.to_owned(), v.to_owned())).collect()
+    }
+
+    #[derive(Default)]
+    struct RecordingSender {
+        last: RefCell<Option<(String, Option<String>, RecordedBody)>>,
+    }
+
+    impl SlackWebRequestSender for RecordingSender {
+        type Error = NeverError;
+
+        fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, NeverError> {
+            let body = match request.body {
+                RequestBody::Form(params) => RecordedBody::Form(to_owned_params(params)),
+                RequestBody::Json(json) => RecordedBody::Json(json.clone()),
+                RequestBody::Multipart(params, file) => RecordedBody::Multipart(
+                    to_owned_params(params),
+                    file.name.to_owned(),
+                    file.filename.to_owned(),
+                    file.contents.to_vec(),
+                ),
+            };
+            *self.last.borrow_mut() = Some((request.url.to_owned(), request.token.map(str::to_owned), body));
+            Ok(SlackWebResponse {
+                status: 200,
+                headers: vec![],
+                body: r#"{"ok": true}"#.to_owned(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_form_methods_send_token_separately() {
+        let client = RecordingSender::default();
+        let request = crate::channels::HistoryRequest::builder()
+            .channel("C1234567890".parse().unwrap())
+            .count(10);
+        crate::channels::history(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+
+        let (url, token, body) = client.last.into_inner().unwrap();
+        assert_eq!("https://slack.com/api/channels.history", url);
+        assert_eq!(Some("xoxb-token".to_owned()), token);
+        assert_eq!(
+            RecordedBody::Form(vec![
+                ("channel".to_owned(), "C1234567890".to_owned()),
+                ("count".to_owned(), "10".to_owned()),
+            ]),
+            body
+        );
+    }
+
+    #[test]
+    fn test_with_base_url_changes_method_urls() {
+        let client = super::WithBaseUrl::new(RecordingSender::default(), "http://localhost:8080/api/");
+        crate::auth::test(&client, "xoxb-token").unwrap();
+
+        let (url, _, _) = client.into_inner().last.into_inner().unwrap();
+        assert_eq!("http://localhost:8080/api/auth.test", url);
+    }
+
+    #[test]
+    fn test_post_message_sends_json_body() {
+        let client = RecordingSender::default();
+        let request = crate::chat::PostMessageRequest::builder()
+            .channel("C1234567890".parse().unwrap())
+            .text("Hello")
+            .as_user(true)
+            .attachments(vec![crate::attachments::Attachment::builder().text("more").build()]);
+        crate::chat::post_message(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+
+        let (_, token, body) = client.last.into_inner().unwrap();
+        assert_eq!(Some("xoxb-token".to_owned()), token);
+        assert_eq!(
+            RecordedBody::Json(serde_json::json!({
+                "channel": "C1234567890",
+                "text": "Hello",
+                "as_user": true,
+                "attachments": [{"text": "more"}],
+            })),
+            body
+        );
+    }
+
+    #[test]
+    fn test_messages_of_only_blocks_are_sent_without_text() {
+        let client = RecordingSender::default();
+        let section = crate::blocks::SectionBlock::new(crate::blocks::Text::mrkdwn("*Hello*"));
+        let request = crate::chat::PostMessageRequest::builder()
+            .channel("C1234567890".parse().unwrap())
+            .blocks(vec![crate::blocks::Block::Section(section)]);
+        crate::chat::post_message(&client, "xoxb-token", &request.build().unwrap()).unwrap();
+
+        let (_, _, body) = client.last.into_inner().unwrap();
+        assert_eq!(
+            RecordedBody::Json(serde_json::json!({
+                "channel": "C1234567890",
+                "blocks": [{"type": "section", "text": {"type": "mrkdwn", "text": "*Hello*"}}],
+            })),
+            body
+        );
+    }
+
+    #[test]
+    fn test_builders_own_their_fields_and_check_required_ones() {
+        let builder = crate::chat::PostMessageRequest::builder().text(String::from("Hello"));
+        assert_eq!(Err(crate::MissingField("channel")), builder.build().map(|_| ()));
+
+        let builder = std::thread::spawn(move || builder.channel("C1234567890".parse().unwrap()).as_user(true))
+            .join()
+            .unwrap();
+        let client = RecordingSender::default();
+        crate::chat::post_message(&client, "xoxb-token", &builder.build().unwrap()).unwrap();
+
+        let (_, _, body) = client.last.into_inner().unwrap();
+        assert_eq!(
+            RecordedBody::Json(serde_json::json!({
+                "channel": "C1234567890",
+                "text": "Hello",
+                "as_user": true,
+            })),
+            body
+        );
+    }
+
+    #[test]
+    fn test_upload_sends_file_as_multipart() {
+        let client = RecordingSender::default();
+        let request = crate::files::UploadRequest {
+            file: Some(b"report contents"),
+            filename: Some("report.txt"),
+            channels: Some("C1234567890"),
+            ..Default::default()
+        };
+        crate::files::upload(&client, "xoxb-token", &request).unwrap();
+
+        let (url, _, body) = client.last.into_inner().unwrap();
+        assert_eq!("https://slack.com/api/files.upload", url);
+        assert_eq!(
+            RecordedBody::Multipart(
+                vec![
+                    ("filename".to_owned(), "report.txt".to_owned()),
+                    ("channels".to_owned(), "C1234567890".to_owned()),
+                ],
+                "file".to_owned(),
+                "report.txt".to_owned(),
+                b"report contents".to_vec(),
+            ),
+            body
+        );
+    }
+
+    #[test]
+    fn test_upload_without_file_sends_form() {
+        let client = RecordingSender::default();
+        let request = crate::files::UploadRequest {
+            content: Some("snippet"),
+            ..Default::default()
+        };
+        crate::files::upload(&client, "xoxb-token", &request).unwrap();
+
+        let (_, _, body) = client.last.into_inner().unwrap();
+        assert_eq!(RecordedBody::Form(vec![("content".to_owned(), "snippet".to_owned())]), body);
+    }
+
+    /// Accepts a single request on `listener`, answers it with an ok response and returns its
+    /// header lines and body.
+    #[cfg(feature = "reqwest")]
+    fn serve_once(listener: std::net::TcpListener) -> std::thread::JoinHandle<(Vec<String>, String)> {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                head.push(line.trim_end().to_owned());
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let response = r#"{"ok": true}"#;
+            write!(reader.get_mut(), "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", response.len(), response)
+                .unwrap();
+            (head, String::from_utf8_lossy(&body).into_owned())
+        })
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_reqwest_client_posts_with_bearer_token() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api/auth.test", listener.local_addr().unwrap());
+        let server = serve_once(listener);
+
+        let client = super::default_client().unwrap();
+        let response = client
+            .send(SlackWebRequest {
+                url: &url,
+                token: Some("xoxb-token"),
+                body: RequestBody::Form(&[("channel", "C1234567890")]),
+            })
+            .unwrap();
+        assert_eq!(200, response.status);
+        assert_eq!(Some("12"), response.header("content-length"));
+        assert_eq!(r#"{"ok": true}"#, response.body);
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!("POST /api/auth.test HTTP/1.1", head[0]);
+        assert!(head.iter().any(|h| h.eq_ignore_ascii_case("authorization: Bearer xoxb-token")));
+        assert!(head
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("content-type: application/x-www-form-urlencoded")));
+        assert_eq!("channel=C1234567890", body);
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[test]
+    fn test_reqwest_client_posts_multipart() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/api/users.setPhoto", listener.local_addr().unwrap());
+        let server = serve_once(listener);
+
+        let client = super::default_client().unwrap();
+        client
+            .send(SlackWebRequest {
+                url: &url,
+                token: Some("xoxb-token"),
+                body: RequestBody::Multipart(
+                    &[("crop_w", "100")],
+                    MultipartFile {
+                        name: "image",
+                        filename: "image",
+                        contents: b"not really a png",
+                    },
+                ),
+            })
+            .unwrap();
+
+        let (head, body) = server.join().unwrap();
+        assert!(head
+            .iter()
+            .any(|h| h.to_lowercase().starts_with("content-type: multipart/form-data; boundary=")));
+        assert!(body.contains("Content-Disposition: form-data; name=\"crop_w\"\r\n\r\n100\r\n"));
+        assert!(body.contains("Content-Disposition: form-data; name=\"image\"; filename=\"image\""));
+        assert!(body.contains("not really a png"));
+    }
+}
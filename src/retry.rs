@@ -0,0 +1,412 @@
+//! Retrying requests that were rate limited or failed transiently.
+
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+#[cfg(feature = "async")]
+use crate::requests::{AsyncSlackWebRequestSender, BoxFuture};
+use crate::requests::{SlackWebRequest, SlackWebRequestSender, SlackWebResponse};
+
+/// A [`SlackWebRequestSender`] that retries the requests it sends through another sender.
+///
+/// A request is retried when Slack responds with `429 Too Many Requests`, after waiting as long
+/// as its `Retry-After` header asks. With [`retry_failures`](#method.retry_failures) enabled, it
+/// is also retried when the response is a server error or the request could not be sent at all,
+/// after an exponential backoff with jitter. Once a request has used up its retry budget of
+/// [`max_retries`](#method.max_retries), the last response or error is returned as is.
+///
+/// Both [`SlackWebRequestSender`] and, with the `async` feature,
+/// [`AsyncSlackWebRequestSender`](../requests/trait.AsyncSlackWebRequestSender.html) are
+/// implemented when the wrapped sender implements them.
+///
+/// # Examples
+///
+/// ```no_run
+/// # let token = "some_token";
+/// use slack_api::retry::RetryingSender;
+///
+/// let client = RetryingSender::new(slack_api::default_client().unwrap()).max_retries(5);
+/// let response = slack_api::channels::list(&client, &token, &Default::default());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryingSender<S> {
+    inner: S,
+    max_retries: u32,
+    retry_failures: bool,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<S> RetryingSender<S> {
+    /// Wraps `inner`, retrying each rate limited request up to 3 times with a backoff starting at
+    /// half a second.
+    pub fn new(inner: S) -> Self {
+        RetryingSender {
+            inner,
+            max_retries: 3,
+            retry_failures: false,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets how many times a single request may be retried.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets whether requests are also retried when the response is a server error or the request
+    /// could not be sent at all, which defaults to no.
+    ///
+    /// Only enable this when every method sent through this sender is safe to repeat: a request
+    /// to `chat.postMessage` whose response was lost may already have posted its message, and
+    /// would post it again.
+    pub fn retry_failures(mut self, retry_failures: bool) -> Self {
+        self.retry_failures = retry_failures;
+        self
+    }
+
+    /// Sets the backoff before the first retry of a failed request, which doubles with every
+    /// further retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest backoff between two retries of a failed request. This does not limit
+    /// waiting for the `Retry-After` of a rate limited request.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns the wrapped sender.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwraps this sender, returning the wrapped sender.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Returns how long to wait before retry number `retry`, counting from 0.
+    fn backoff(&self, retry: u32) -> Duration {
+        backoff(self.initial_backoff, self.max_backoff, retry)
+    }
+
+    /// Returns how long to wait before retrying a request that got `result` after `retry` retries,
+    /// or `None` if `result` should be returned.
+    fn delay<E>(&self, result: &Result<SlackWebResponse, E>, retry: u32) -> Option<Duration> {
+        let delay = match *result {
+            Ok(ref response) if response.status == 429 => {
+                response.retry_after().unwrap_or_else(|| self.backoff(retry))
+            }
+            Ok(ref response) if response.status >= 500 && self.retry_failures => self.backoff(retry),
+            Err(_) if self.retry_failures => self.backoff(retry),
+            _ => return None,
+        };
+        if retry >= self.max_retries {
+            None
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// Returns how long to wait before retry number `retry`, counting from 0, when the backoff starts
+/// at `initial` and doubles up to `max`.
+pub(crate) fn backoff(initial: Duration, max: Duration, retry: u32) -> Duration {
+    let ceiling = initial
+        .checked_mul(1 << retry.min(31))
+        .map_or(max, |backoff| backoff.min(max));
+    // Always wait at least half of the backoff, so that retries keep spreading out
+    let half = ceiling / 2;
+    half + jitter(ceiling - half)
+}
+
+/// Returns a random duration between zero and `max`.
+fn jitter(max: Duration) -> Duration {
+    let max = max.as_nanos() as u64;
+    if max == 0 {
+        return Duration::ZERO;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % (max + 1))
+}
+
+impl<S: SlackWebRequestSender> SlackWebRequestSender for RetryingSender<S> {
+    type Error = S::Error;
+
+    fn send(&self, request: SlackWebRequest) -> Result<SlackWebResponse, Self::Error> {
+        let mut retry = 0;
+        loop {
+            let result = self.inner.send(request);
+            match self.delay(&result, retry) {
+                Some(delay) => thread::sleep(delay),
+                None => return result,
+            }
+            retry += 1;
+        }
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<S: AsyncSlackWebRequestSender + Sync> AsyncSlackWebRequestSender for RetryingSender<S> {
+    type Error = S::Error;
+
+    fn send<'a>(&'a self, request: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, Self::Error>> {
+        Box::pin(async move {
+            let mut retry = 0;
+            loop {
+                let delay = {
+                    let result = self.inner.send(request).await;
+                    match self.delay(&result, retry) {
+                        Some(delay) => delay,
+                        None => return result,
+                    }
+                };
+                Delay::new(delay).await;
+                retry += 1;
+            }
+        })
+    }
+
+    fn base_url(&self) -> &str {
+        self.inner.base_url()
+    }
+}
+
+/// A future that completes once a duration has passed.
+///
+/// The wait is timed by a thread of its own, so that retries do not depend on any particular
+/// async runtime.
+#[cfg(feature = "async")]
+struct Delay {
+    /// Whether the duration has passed, and the waker to wake once it has
+    state: Arc<Mutex<(bool, Option<Waker>)>>,
+}
+
+#[cfg(feature = "async")]
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new((duration.is_zero(), None::<Waker>)));
+        if !duration.is_zero() {
+            let timer = state.clone();
+            thread::spawn(move || {
+                thread::sleep(duration);
+                let mut state = timer.lock().unwrap();
+                state.0 = true;
+                if let Some(waker) = state.1.take() {
+                    waker.wake();
+                }
+            });
+        }
+        Delay { state }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::fmt;
+    use std::time::Duration;
+
+    use super::RetryingSender;
+    use crate::requests::{RequestBody, SlackWebRequest, SlackWebRequestSender, SlackWebResponse};
+
+    /// Replays a fixed list of results, one per request.
+    struct ScriptedSender {
+        results: RefCell<Vec<Result<SlackWebResponse, fmt::Error>>>,
+        sent: RefCell<u32>,
+    }
+
+    impl ScriptedSender {
+        fn new(mut results: Vec<Result<SlackWebResponse, fmt::Error>>) -> Self {
+            results.reverse();
+            ScriptedSender {
+                results: RefCell::new(results),
+                sent: RefCell::new(0),
+            }
+        }
+    }
+
+    impl SlackWebRequestSender for ScriptedSender {
+        type Error = fmt::Error;
+
+        fn send(&self, _: SlackWebRequest) -> Result<SlackWebResponse, fmt::Error> {
+            *self.sent.borrow_mut() += 1;
+            self.results.borrow_mut().pop().expect("no more scripted results")
+        }
+    }
+
+    fn response(status: u16, headers: &[(&str, &str)]) -> Result<SlackWebResponse, fmt::Error> {
+        Ok(SlackWebResponse {
+            status,
+            headers: headers.iter().map(|&(k, v)| (k.to_owned(), v.to_owned())).collect(),
+            body: String::new(),
+        })
+    }
+
+    fn send<S: SlackWebRequestSender>(client: &S) -> Result<SlackWebResponse, S::Error> {
+        client.send(SlackWebRequest {
+            url: "https://slack.com/api/api.test",
+            token: None,
+            body: RequestBody::Form(&[]),
+        })
+    }
+
+    #[test]
+    fn test_retries_rate_limited_and_failed_requests() {
+        let client = RetryingSender::new(ScriptedSender::new(vec![
+            response(429, &[("Retry-After", "0")]),
+            response(503, &[]),
+            Err(fmt::Error),
+            response(200, &[]),
+        ]))
+        .retry_failures(true)
+        .initial_backoff(Duration::from_millis(1));
+
+        assert_eq!(200, send(&client).unwrap().status);
+        assert_eq!(4, *client.get_ref().sent.borrow());
+    }
+
+    #[test]
+    fn test_only_retries_rate_limited_requests_by_default() {
+        let client = RetryingSender::new(ScriptedSender::new(vec![
+            response(429, &[("Retry-After", "0")]),
+            response(503, &[]),
+        ]));
+
+        assert_eq!(503, send(&client).unwrap().status);
+        assert_eq!(2, *client.get_ref().sent.borrow());
+
+        let client = RetryingSender::new(ScriptedSender::new(vec![Err(fmt::Error)]));
+        assert!(send(&client).is_err());
+        assert_eq!(1, *client.get_ref().sent.borrow());
+    }
+
+    #[test]
+    fn test_stops_after_max_retries() {
+        let client = RetryingSender::new(ScriptedSender::new(vec![response(500, &[]), response(502, &[])]))
+            .max_retries(1)
+            .retry_failures(true)
+            .initial_backoff(Duration::from_millis(1));
+
+        assert_eq!(502, send(&client).unwrap().status);
+        assert_eq!(2, *client.get_ref().sent.borrow());
+    }
+
+    #[test]
+    fn test_does_not_retry_client_errors() {
+        let client = RetryingSender::new(ScriptedSender::new(vec![response(404, &[])]));
+
+        assert_eq!(404, send(&client).unwrap().status);
+        assert_eq!(1, *client.get_ref().sent.borrow());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_retries_async_requests() {
+        use std::future::Future;
+        use std::pin::pin;
+        use std::sync::{Arc, Mutex};
+        use std::task::{Context, Poll, Wake};
+        use std::thread::{self, Thread};
+
+        use crate::requests::{AsyncSlackWebRequestSender, BoxFuture};
+
+        struct AsyncScriptedSender(Mutex<Vec<Result<SlackWebResponse, fmt::Error>>>);
+
+        impl AsyncSlackWebRequestSender for AsyncScriptedSender {
+            type Error = fmt::Error;
+
+            fn send<'a>(&'a self, _: SlackWebRequest<'a>) -> BoxFuture<'a, Result<SlackWebResponse, fmt::Error>> {
+                let result = self.0.lock().unwrap().remove(0);
+                Box::pin(async move { result })
+            }
+        }
+
+        struct Unpark(Thread);
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let client = RetryingSender::new(AsyncScriptedSender(Mutex::new(vec![
+            response(429, &[("Retry-After", "0")]),
+            response(500, &[]),
+            response(200, &[]),
+        ])))
+        .retry_failures(true)
+        .initial_backoff(Duration::from_millis(1));
+        let mut future = pin!(AsyncSlackWebRequestSender::send(
+            &client,
+            SlackWebRequest {
+                url: "https://slack.com/api/api.test",
+                token: None,
+                body: RequestBody::Form(&[]),
+            }
+        ));
+        let waker = Arc::new(Unpark(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let response = loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(response) => break response,
+                Poll::Pending => thread::park(),
+            }
+        };
+
+        assert_eq!(200, response.unwrap().status);
+        assert!(client.get_ref().0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_backoff_is_jittered_and_capped() {
+        let client = RetryingSender::new(())
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5));
+
+        for retry in 0..3 {
+            let ceiling = Duration::from_secs(1 << retry);
+            let backoff = client.backoff(retry);
+            assert!(backoff >= ceiling / 2 && backoff <= ceiling, "{:?}", backoff);
+        }
+        for retry in &[3, 10, 64] {
+            let backoff = client.backoff(*retry);
+            assert!(backoff >= Duration::from_millis(2500) && backoff <= Duration::from_secs(5));
+        }
+    }
+}
@@ -0,0 +1,613 @@
+//! A client for the [Real Time Messaging API](https://api.slack.com/rtm), which receives
+//! [`Event`](../enum.Event.html)s and sends messages over a websocket.
+//!
+//! The websocket is opened from the URL returned by [`rtm::connect`](../rtm/fn.connect.html) or
+//! [`rtm::start`](../rtm/fn.start.html), and the client is an iterator over the events read from it:
+//!
+//! ```no_run
+//! use slack_api::rtm_client::RtmClient;
+//!
+//! let slack = slack_api::Slack::builder("some_token").build().unwrap();
+//! let response = slack.rtm().connect().unwrap();
+//!
+//! let mut client = RtmClient::from_connect(&response).unwrap();
+//! let reply = client.send_message(&"C1234567890".parse().unwrap(), "Hello").unwrap();
+//! println!("sent at {:?}", reply.ts);
+//!
+//! for event in client {
+//!     println!("{:?}", event);
+//! }
+//! ```
+//!
+//! With the `rtm-client-async` feature enabled, [`RtmClient::into_stream`] turns the client into a
+//! `Stream` of the same events.
+//!
+//! This module is only available with the `rtm-client` feature enabled.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message as Frame, WebSocket};
+
+use crate::rtm::{ConnectResponse, StartResponse};
+use crate::{ChannelId, Event, Ts, UserId};
+
+mod supervisor;
+pub use self::supervisor::{ConnectionState, DisconnectReason, RtmSupervisor};
+
+#[cfg(feature = "rtm-client-async")]
+pub use self::stream::RtmStream;
+
+/// How long [`RtmClient::wait_for_reply`] waits for a reply unless told otherwise.
+pub const DEFAULT_REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An error receiving events over RTM.
+#[derive(Debug)]
+pub enum RtmError {
+    /// The response that should give the websocket URL did not include one
+    MissingUrl,
+    /// The websocket could not be opened, or failed while open
+    Socket(tungstenite::Error),
+    /// A frame was not parseable as an event
+    MalformedEvent(serde_json::error::Error),
+    /// No reply to a frame that was sent arrived in time
+    Timeout,
+    /// Slack replied to a frame that was sent with an error
+    Reply(ReplyError),
+    /// Asking for the URL of a new websocket, such as with `rtm.connect`, failed
+    Connect(Box<dyn Error + Send + Sync>),
+}
+
+impl fmt::Display for RtmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RtmError::MissingUrl => write!(f, "the response did not include a websocket URL"),
+            RtmError::Socket(ref e) => write!(f, "{}", e),
+            RtmError::MalformedEvent(ref e) => write!(f, "{}", e),
+            RtmError::Timeout => write!(f, "no reply arrived in time"),
+            RtmError::Reply(ref e) => write!(f, "{} (code {})", e.msg, e.code),
+            RtmError::Connect(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for RtmError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RtmError::Socket(ref e) => Some(e),
+            RtmError::MalformedEvent(ref e) => Some(e),
+            RtmError::Connect(ref e) => Some(&**e),
+            RtmError::MissingUrl | RtmError::Timeout | RtmError::Reply(_) => None,
+        }
+    }
+}
+
+impl From<tungstenite::Error> for RtmError {
+    fn from(err: tungstenite::Error) -> Self {
+        RtmError::Socket(err)
+    }
+}
+
+/// A frame sent to Slack, which the client gives an `id` when sending it.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Outgoing<'a> {
+    /// Posts a message with simple formatting, which Slack replies to with its timestamp
+    Message {
+        channel: &'a ChannelId,
+        text: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thread_ts: Option<&'a Ts>,
+    },
+    /// Shows that the user is typing in a channel
+    Typing { channel: &'a ChannelId },
+    /// Checks that the connection is alive, which Slack replies to with a `pong`
+    Ping,
+    /// Subscribes to the `presence_change` events of these users only
+    PresenceSub { ids: &'a [UserId] },
+    /// Asks for a `presence_change` event with the current presence of these users
+    PresenceQuery { ids: &'a [UserId] },
+}
+
+impl Outgoing<'_> {
+    /// Returns whether Slack replies to this frame.
+    fn has_reply(&self) -> bool {
+        matches!(*self, Outgoing::Message { .. } | Outgoing::Ping)
+    }
+}
+
+/// Slack's reply to a frame that was sent with an `id`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Reply {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ReplyError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ok: Option<bool>,
+    /// The `id` of the frame this replies to
+    pub reply_to: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// The timestamp of a message that was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<Ts>,
+    /// Fields that are not modelled
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The error of a [`Reply`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReplyError {
+    pub code: i64,
+    pub msg: String,
+}
+
+/// A frame read from the websocket.
+#[allow(clippy::large_enum_variant)]
+enum Incoming {
+    Event(Event),
+    Reply(Reply),
+}
+
+impl Incoming {
+    fn parse(text: &str) -> Result<Self, serde_json::error::Error> {
+        let value: serde_json::Value = serde_json::from_str(text)?;
+        if value.get("reply_to").is_some() {
+            serde_json::from_value(value).map(Incoming::Reply)
+        } else {
+            serde_json::from_value(value).map(Incoming::Event)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WithId<'a> {
+    id: u64,
+    #[serde(flatten)]
+    frame: &'a Outgoing<'a>,
+}
+
+/// An open RTM websocket.
+///
+/// Iterating over the client waits for each event in turn, and ends once the connection is
+/// closed. A frame that is not parseable as an event is returned as an error without closing the
+/// connection.
+///
+/// Frames are sent with an `id` from a counter, and the replies to them are matched by that `id`.
+/// Events that arrive while waiting for a reply are kept, and returned by the iterator afterwards.
+pub struct RtmClient {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    closed: bool,
+    next_id: u64,
+    reply_timeout: Duration,
+    /// The frames sent that have not had their reply, or been given up on, yet
+    awaiting: HashSet<u64>,
+    replies: HashMap<u64, Reply>,
+    events: VecDeque<Result<Event, RtmError>>,
+}
+
+impl RtmClient {
+    /// Opens the websocket at `url`.
+    pub fn connect(url: &str) -> Result<Self, RtmError> {
+        let (socket, _) = tungstenite::connect(url)?;
+        Ok(RtmClient {
+            socket,
+            closed: false,
+            next_id: 1,
+            reply_timeout: DEFAULT_REPLY_TIMEOUT,
+            awaiting: HashSet::new(),
+            replies: HashMap::new(),
+            events: VecDeque::new(),
+        })
+    }
+
+    /// Opens the websocket of a session started with `rtm.connect`.
+    pub fn from_connect(response: &ConnectResponse) -> Result<Self, RtmError> {
+        Self::connect(response.url.as_deref().ok_or(RtmError::MissingUrl)?)
+    }
+
+    /// Opens the websocket of a session started with `rtm.start`.
+    pub fn from_start(response: &StartResponse) -> Result<Self, RtmError> {
+        Self::connect(response.url.as_deref().ok_or(RtmError::MissingUrl)?)
+    }
+
+    /// Sets how long to wait for the reply to a frame before failing with
+    /// [`RtmError::Timeout`].
+    pub fn set_reply_timeout(&mut self, timeout: Duration) {
+        self.reply_timeout = timeout;
+    }
+
+    /// Waits for the next event, or returns `None` once the connection has been closed.
+    pub fn read_event(&mut self) -> Result<Option<Event>, RtmError> {
+        self.read_event_before(None)
+    }
+
+    /// Like [`read_event`](#method.read_event), but fails with [`RtmError::Timeout`] if no event
+    /// arrived before `deadline`.
+    fn read_event_before(&mut self, deadline: Option<Instant>) -> Result<Option<Event>, RtmError> {
+        if let Some(event) = self.events.pop_front() {
+            return event.map(Some);
+        }
+        loop {
+            match self.read_incoming(deadline)? {
+                Some(Incoming::Event(event)) => return Ok(Some(event)),
+                Some(Incoming::Reply(reply)) => self.keep_reply(reply),
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// Sends `frame`, and returns the `id` it was sent with.
+    pub fn send(&mut self, frame: &Outgoing) -> Result<u64, RtmError> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let text = serde_json::to_string(&WithId { id, frame }).expect("outgoing frames serialize to JSON");
+        self.socket.send(Frame::text(text))?;
+        if frame.has_reply() {
+            self.awaiting.insert(id);
+        }
+        Ok(id)
+    }
+
+    /// Waits for the reply to the frame sent with `id`.
+    ///
+    /// Fails with [`RtmError::Timeout`] if it does not arrive within the reply timeout, and with
+    /// [`RtmError::Reply`] if Slack replied with an error.
+    pub fn wait_for_reply(&mut self, id: u64) -> Result<Reply, RtmError> {
+        let deadline = Instant::now() + self.reply_timeout;
+        loop {
+            if let Some(reply) = self.replies.remove(&id) {
+                return match reply.error {
+                    Some(error) => Err(RtmError::Reply(error)),
+                    None => Ok(reply),
+                };
+            }
+            if !self.awaiting.contains(&id) {
+                return Err(RtmError::Timeout);
+            }
+            match self.read_incoming(Some(deadline)) {
+                Ok(Some(Incoming::Event(event))) => self.events.push_back(Ok(event)),
+                Ok(Some(Incoming::Reply(reply))) => self.keep_reply(reply),
+                Ok(None) => return Err(tungstenite::Error::ConnectionClosed.into()),
+                Err(RtmError::MalformedEvent(e)) => self.events.push_back(Err(RtmError::MalformedEvent(e))),
+                Err(RtmError::Timeout) => {
+                    self.awaiting.remove(&id);
+                    return Err(RtmError::Timeout);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Sends a message to `channel`, and waits for Slack to reply with its timestamp.
+    pub fn send_message(&mut self, channel: &ChannelId, text: &str) -> Result<Reply, RtmError> {
+        let id = self.send(&Outgoing::Message { channel, text, thread_ts: None })?;
+        self.wait_for_reply(id)
+    }
+
+    /// Shows that the user is typing in `channel`.
+    pub fn send_typing(&mut self, channel: &ChannelId) -> Result<(), RtmError> {
+        self.send(&Outgoing::Typing { channel }).map(|_| ())
+    }
+
+    /// Sends a ping, and waits for Slack to reply to it.
+    pub fn ping(&mut self) -> Result<Reply, RtmError> {
+        let id = self.send(&Outgoing::Ping)?;
+        self.wait_for_reply(id)
+    }
+
+    /// Subscribes to the `presence_change` events of `users` only.
+    pub fn subscribe_presence(&mut self, users: &[UserId]) -> Result<(), RtmError> {
+        self.send(&Outgoing::PresenceSub { ids: users }).map(|_| ())
+    }
+
+    /// Asks for `presence_change` events with the current presence of `users`.
+    pub fn query_presence(&mut self, users: &[UserId]) -> Result<(), RtmError> {
+        self.send(&Outgoing::PresenceQuery { ids: users }).map(|_| ())
+    }
+
+    /// Closes the connection, waiting for the server to acknowledge it.
+    pub fn close(&mut self) -> Result<(), RtmError> {
+        if !self.closed {
+            self.socket.close(None)?;
+        }
+        while self.read_incoming(None)?.is_some() {}
+        Ok(())
+    }
+
+    /// Returns the reply to the frame sent with `id`, if it has already arrived.
+    fn take_reply(&mut self, id: u64) -> Option<Reply> {
+        self.replies.remove(&id)
+    }
+
+    /// Keeps a reply until it is waited for, unless nothing is waiting for it.
+    fn keep_reply(&mut self, reply: Reply) {
+        if self.awaiting.remove(&reply.reply_to) {
+            self.replies.insert(reply.reply_to, reply);
+        }
+    }
+
+    /// Reads the next frame, or returns `None` once the connection has been closed. Fails with
+    /// [`RtmError::Timeout`] if nothing was read before `deadline`.
+    fn read_incoming(&mut self, deadline: Option<Instant>) -> Result<Option<Incoming>, RtmError> {
+        while !self.closed {
+            let timeout = match deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(timeout) if !timeout.is_zero() => Some(timeout),
+                    _ => return Err(RtmError::Timeout),
+                },
+                None => None,
+            };
+            if let Some(tcp) = self.tcp() {
+                tcp.set_read_timeout(timeout).map_err(tungstenite::Error::Io)?;
+            }
+            match self.socket.read() {
+                Ok(Frame::Text(text)) => return Incoming::parse(&text).map(Some).map_err(RtmError::MalformedEvent),
+                // Pings and close frames are answered by the socket itself
+                Ok(_) => {}
+                Err(tungstenite::Error::ConnectionClosed) | Err(tungstenite::Error::AlreadyClosed) => {
+                    self.closed = true;
+                }
+                Err(tungstenite::Error::Io(ref e))
+                    if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {}
+                Err(e) => {
+                    self.closed = true;
+                    return Err(e.into());
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the TCP stream under the websocket.
+    fn tcp(&self) -> Option<&TcpStream> {
+        match *self.socket.get_ref() {
+            MaybeTlsStream::Plain(ref tcp) => Some(tcp),
+            MaybeTlsStream::NativeTls(ref tls) => Some(tls.get_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for RtmClient {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RtmClient")
+            .field("peer", &self.tcp().and_then(|tcp| tcp.peer_addr().ok()))
+            .field("closed", &self.closed)
+            .field("next_id", &self.next_id)
+            .finish()
+    }
+}
+
+impl Iterator for RtmClient {
+    type Item = Result<Event, RtmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_event().transpose()
+    }
+}
+
+#[cfg(feature = "rtm-client-async")]
+mod stream {
+    use std::collections::VecDeque;
+    use std::net::{Shutdown, TcpStream};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+
+    use futures_core::Stream;
+
+    use super::{RtmClient, RtmError};
+    use crate::Event;
+
+    /// The events of an [`RtmClient`], as a `Stream`.
+    ///
+    /// The websocket is read on a thread of its own, so the stream works with any executor.
+    /// Dropping the stream shuts the connection down.
+    #[derive(Debug)]
+    pub struct RtmStream {
+        shared: Arc<Mutex<Shared>>,
+        tcp: Option<TcpStream>,
+    }
+
+    #[derive(Debug, Default)]
+    struct Shared {
+        events: VecDeque<Result<Event, RtmError>>,
+        done: bool,
+        waker: Option<Waker>,
+    }
+
+    impl RtmClient {
+        /// Reads events on a background thread, and returns them as a `Stream`.
+        pub fn into_stream(mut self) -> RtmStream {
+            let shared = Arc::new(Mutex::new(Shared::default()));
+            let tcp = self.tcp().and_then(|tcp| tcp.try_clone().ok());
+            let reader = shared.clone();
+            thread::spawn(move || loop {
+                let event = self.next();
+                let mut shared = reader.lock().unwrap();
+                match event {
+                    Some(event) => shared.events.push_back(event),
+                    None => shared.done = true,
+                }
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+                if shared.done {
+                    break;
+                }
+            });
+            RtmStream { shared, tcp }
+        }
+    }
+
+    impl Stream for RtmStream {
+        type Item = Result<Event, RtmError>;
+
+        fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            let mut shared = self.shared.lock().unwrap();
+            if let Some(event) = shared.events.pop_front() {
+                Poll::Ready(Some(event))
+            } else if shared.done {
+                Poll::Ready(None)
+            } else {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+
+    impl Drop for RtmStream {
+        fn drop(&mut self) {
+            if let Some(ref tcp) = self.tcp {
+                let _ = tcp.shutdown(Shutdown::Both);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+    use std::time::Duration;
+
+    use tungstenite::{Message as Frame, WebSocket};
+
+    use super::{RtmClient, RtmError};
+    use crate::Event;
+
+    /// Runs `script` on the websocket of the first client to connect, then closes the connection.
+    fn serve<F>(script: F) -> String
+    where
+        F: FnOnce(&mut WebSocket<TcpStream>) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let mut socket = tungstenite::accept(listener.accept().unwrap().0).unwrap();
+            script(&mut socket);
+            let _ = socket.close(None);
+            while socket.read().is_ok() {}
+        });
+        url
+    }
+
+    fn send_frames(socket: &mut WebSocket<TcpStream>) {
+        for frame in FRAMES {
+            socket.send(Frame::text(*frame)).unwrap();
+        }
+    }
+
+    /// Reads the next frame the client sent.
+    fn receive(socket: &mut WebSocket<TcpStream>) -> serde_json::Value {
+        serde_json::from_str(socket.read().unwrap().to_text().unwrap()).unwrap()
+    }
+
+    const FRAMES: &[&str] = &[
+        r#"{"type": "hello"}"#,
+        r#"{"type": "message", "channel": "C1234567890", "text": "Hi", "ts": "1.000001"}"#,
+        r#"{"type": "sh_room_join", "room": {"id": "R1234567890"}}"#,
+        r#"{"type": "user_typing", "channel": "C1234567890", "user": 42}"#,
+        r#"not an event"#,
+    ];
+
+    fn check_events(events: Vec<Result<Event, RtmError>>) {
+        match &events[..] {
+            [
+                Ok(Event::Hello),
+                Ok(Event::Message(message)),
+                Ok(Event::Unknown(unknown)),
+                Err(RtmError::MalformedEvent(_)),
+                Err(RtmError::MalformedEvent(_)),
+            ] => {
+                assert_eq!(Some("Hi"), message.text());
+                assert_eq!(Some("R1234567890"), unknown["room"]["id"].as_str());
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_reads_events_until_the_connection_closes() {
+        let client = RtmClient::connect(&serve(send_frames)).unwrap();
+        check_events(client.collect());
+    }
+
+    #[test]
+    fn test_replies_are_matched_to_the_frames_sent() {
+        let url = serve(|socket| {
+            let message = receive(socket);
+            assert_eq!(serde_json::json!({"id": 1, "type": "message", "channel": "C1234567890", "text": "Hi"}), message);
+            socket.send(Frame::text(r#"{"type": "hello"}"#)).unwrap();
+            socket.send(Frame::text(r#"{"ok": true, "reply_to": 7, "ts": "1.000001"}"#)).unwrap();
+            socket.send(Frame::text(r#"{"ok": true, "reply_to": 1, "ts": "2.000002", "text": "Hi"}"#)).unwrap();
+            assert_eq!("typing", receive(socket)["type"]);
+            let ping = receive(socket);
+            assert_eq!(serde_json::json!({"id": 3, "type": "ping"}), ping);
+            socket.send(Frame::text(r#"{"type": "pong", "reply_to": 3}"#)).unwrap();
+        });
+        let mut client = RtmClient::connect(&url).unwrap();
+        let channel = "C1234567890".parse().unwrap();
+        let reply = client.send_message(&channel, "Hi").unwrap();
+        assert_eq!(Some("2.000002"), reply.ts.as_ref().map(|ts| ts.as_str()));
+        client.send_typing(&channel).unwrap();
+        client.ping().unwrap();
+        match &client.collect::<Vec<_>>()[..] {
+            [Ok(Event::Hello)] => {}
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sending_fails_on_error_replies_and_timeouts() {
+        let url = serve(|socket| {
+            receive(socket);
+            socket.send(Frame::text(r#"{"ok": false, "reply_to": 1, "error": {"code": 2, "msg": "message text is missing"}}"#)).unwrap();
+            receive(socket);
+            // Reads until the client closes the connection, without replying
+            while socket.read().is_ok() {}
+        });
+        let mut client = RtmClient::connect(&url).unwrap();
+        client.set_reply_timeout(Duration::from_millis(50));
+        let channel = "C1234567890".parse().unwrap();
+        match client.send_message(&channel, "") {
+            Err(RtmError::Reply(error)) => assert_eq!(2, error.code),
+            other => panic!("{:?}", other),
+        }
+        match client.send_message(&channel, "Hi") {
+            Err(RtmError::Timeout) => {}
+            other => panic!("{:?}", other),
+        }
+        client.close().unwrap();
+    }
+
+    #[cfg(feature = "rtm-client-async")]
+    #[test]
+    fn test_streams_events_until_the_connection_closes() {
+        use std::future::{poll_fn, Future};
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        use futures_core::Stream;
+
+        let mut stream = pin!(RtmClient::connect(&serve(send_frames)).unwrap().into_stream());
+        let mut events = vec![];
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            let mut next = pin!(poll_fn(|cx| stream.as_mut().poll_next(cx)));
+            match next.as_mut().poll(&mut cx) {
+                Poll::Ready(Some(event)) => events.push(event),
+                Poll::Ready(None) => break,
+                Poll::Pending => thread::yield_now(),
+            }
+        }
+        check_events(events);
+    }
+}
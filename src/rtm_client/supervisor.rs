@@ -0,0 +1,527 @@
+//! A supervised RTM connection, which reconnects whenever the websocket is lost.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{Outgoing, RtmClient, RtmError};
+use crate::requests::SlackWebRequestSender;
+use crate::{Event, Slack};
+
+/// How many events are remembered to recognize them when they are sent again after a reconnect.
+const REPLAY_WINDOW: usize = 1000;
+
+type Connector = Box<dyn FnMut() -> Result<String, RtmError> + Send>;
+type StateCallback = Box<dyn FnMut(&ConnectionState) + Send>;
+
+/// The state of the connection of an [`RtmSupervisor`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// A websocket is being opened, after `attempt` attempts in a row that failed
+    Connecting { attempt: u32 },
+    /// The websocket is open
+    Connected,
+    /// The websocket was lost, or could not be opened
+    Disconnected(DisconnectReason),
+    /// Waiting this long before the next attempt to connect
+    Waiting(Duration),
+    /// The connection was closed, and will not be opened again
+    Closed,
+}
+
+/// Why an [`RtmSupervisor`] lost its websocket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisconnectReason {
+    /// Slack said `goodbye`
+    Goodbye,
+    /// The team is being migrated to another server
+    Migration,
+    /// Slack did not reply to a ping in time
+    MissedPong,
+    /// The websocket was closed
+    Closed,
+    /// Opening or using the websocket failed with this error
+    Failed(String),
+}
+
+/// An RTM connection that keeps itself alive.
+///
+/// The supervisor pings Slack whenever the connection has been quiet for the
+/// [`ping_interval`](#method.ping_interval), and reconnects when a pong does not arrive in time,
+/// when Slack says `goodbye` or starts a team migration, and when the websocket fails or closes.
+/// The URL of each new websocket comes from the `reconnect_url` event of the previous one when
+/// Slack sent one, and from calling `rtm.connect` otherwise. Attempts to connect that fail in a
+/// row are spaced out by an exponential backoff, and a websocket that is lost before Slack says
+/// `hello` counts as a failed attempt.
+///
+/// Events that were already returned before a reconnect and are sent again after it are skipped.
+///
+/// ```no_run
+/// use slack_api::rtm_client::RtmSupervisor;
+///
+/// let slack = slack_api::Slack::builder("some_token").build().unwrap();
+/// let supervisor = RtmSupervisor::from_slack(slack)
+///     .on_state_change(|state| println!("{:?}", state));
+///
+/// for event in supervisor {
+///     println!("{:?}", event);
+/// }
+/// ```
+pub struct RtmSupervisor {
+    connect: Connector,
+    on_state_change: Option<StateCallback>,
+    ping_interval: Duration,
+    pong_timeout: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_attempts: Option<u32>,
+    state: ConnectionState,
+    client: Option<RtmClient>,
+    reconnect_url: Option<String>,
+    /// How many attempts to connect have failed since Slack last said `hello`
+    attempt: u32,
+    /// Whether Slack said `hello` on the open websocket
+    said_hello: bool,
+    last_activity: Instant,
+    /// The `id` of the ping that is waiting for its pong, and when it was sent
+    ping: Option<(u64, Instant)>,
+    seen: ReplayWindow,
+}
+
+impl RtmSupervisor {
+    /// Creates a supervisor that calls `connect` for the URL of each new websocket.
+    ///
+    /// No websocket is opened until the first event is read.
+    pub fn new<F>(connect: F) -> Self
+    where
+        F: FnMut() -> Result<String, RtmError> + Send + 'static,
+    {
+        RtmSupervisor {
+            connect: Box::new(connect),
+            on_state_change: None,
+            ping_interval: Duration::from_secs(30),
+            pong_timeout: Duration::from_secs(10),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_attempts: None,
+            state: ConnectionState::Connecting { attempt: 0 },
+            client: None,
+            reconnect_url: None,
+            attempt: 0,
+            said_hello: false,
+            last_activity: Instant::now(),
+            ping: None,
+            seen: ReplayWindow::default(),
+        }
+    }
+
+    /// Creates a supervisor that calls `rtm.connect` through `slack` for the URL of each new
+    /// websocket.
+    pub fn from_slack<S>(slack: Slack<S>) -> Self
+    where
+        S: SlackWebRequestSender + Send + 'static,
+        S::Error: Send + Sync + 'static,
+    {
+        Self::new(move || {
+            let response = slack.rtm().connect().map_err(|e| RtmError::Connect(Box::new(e)))?;
+            response.url.ok_or(RtmError::MissingUrl)
+        })
+    }
+
+    /// Sets how long the connection may be quiet before Slack is pinged. Defaults to 30 seconds.
+    pub fn ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    /// Sets how long to wait for the pong to a ping before reconnecting. Defaults to 10 seconds.
+    pub fn pong_timeout(mut self, pong_timeout: Duration) -> Self {
+        self.pong_timeout = pong_timeout;
+        self
+    }
+
+    /// Sets the backoff after the first failed attempt to connect, which doubles with every
+    /// further failed attempt. Defaults to 1 second.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest backoff between two attempts to connect. Defaults to 60 seconds.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets how many attempts to connect may fail in a row before giving up. By default the
+    /// supervisor never gives up.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Calls `callback` with every state the connection goes through.
+    pub fn on_state_change<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&ConnectionState) + Send + 'static,
+    {
+        self.on_state_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Returns the state of the connection.
+    pub fn state(&self) -> &ConnectionState {
+        &self.state
+    }
+
+    /// Returns the client of the open websocket, to send frames through it.
+    pub fn client(&mut self) -> Option<&mut RtmClient> {
+        self.client.as_mut()
+    }
+
+    /// Waits for the next event, reconnecting as often as needed.
+    ///
+    /// Returns `None` once the supervisor was closed, and fails when connecting failed more
+    /// often in a row than [`max_attempts`](#method.max_attempts) allows.
+    pub fn read_event(&mut self) -> Result<Option<Event>, RtmError> {
+        loop {
+            if self.state == ConnectionState::Closed {
+                return Ok(None);
+            }
+            let client = match self.client {
+                Some(ref mut client) => client,
+                None => {
+                    self.reconnect()?;
+                    continue;
+                }
+            };
+            let deadline = match self.ping {
+                Some((_, sent)) => sent + self.pong_timeout,
+                None => self.last_activity + self.ping_interval,
+            };
+            let result = client.read_event_before(Some(deadline));
+            if let Some((id, _)) = self.ping {
+                if client.take_reply(id).is_some() {
+                    self.ping = None;
+                    self.last_activity = Instant::now();
+                }
+            }
+            match result {
+                Ok(Some(event)) => {
+                    self.last_activity = Instant::now();
+                    match event {
+                        Event::Hello => {
+                            self.attempt = 0;
+                            self.said_hello = true;
+                        }
+                        Event::Goodbye => self.disconnect(DisconnectReason::Goodbye),
+                        Event::TeamMigrationStarted => self.disconnect(DisconnectReason::Migration),
+                        Event::ReconnectUrl(ref reconnect) => self.reconnect_url = Some(reconnect.url.clone()),
+                        _ => {}
+                    }
+                    if !self.seen.is_replay(&event) {
+                        return Ok(Some(event));
+                    }
+                }
+                Ok(None) => self.disconnect(DisconnectReason::Closed),
+                Err(RtmError::Timeout) if self.ping.is_some() => self.disconnect(DisconnectReason::MissedPong),
+                Err(RtmError::Timeout) => {
+                    if self.last_activity.elapsed() >= self.ping_interval {
+                        match client.send(&Outgoing::Ping) {
+                            Ok(id) => self.ping = Some((id, Instant::now())),
+                            Err(e) => self.disconnect(DisconnectReason::Failed(e.to_string())),
+                        }
+                    }
+                }
+                Err(e @ RtmError::MalformedEvent(_)) => return Err(e),
+                Err(e) => self.disconnect(DisconnectReason::Failed(e.to_string())),
+            }
+        }
+    }
+
+    /// Closes the websocket, and stops reconnecting.
+    pub fn close(&mut self) -> Result<(), RtmError> {
+        let result = match self.client.take() {
+            Some(mut client) => client.close(),
+            None => Ok(()),
+        };
+        self.set_state(ConnectionState::Closed);
+        result
+    }
+
+    /// Opens a new websocket, after waiting out the backoff if the last attempt failed.
+    fn reconnect(&mut self) -> Result<(), RtmError> {
+        // Only reached with too many failed attempts when the last websockets were lost before
+        // Slack said `hello`, as failing to open one gives up right away
+        if self.max_attempts.is_some_and(|max| self.attempt >= max) {
+            self.set_state(ConnectionState::Closed);
+            return Err(RtmError::Socket(tungstenite::Error::ConnectionClosed));
+        }
+        if self.attempt > 0 {
+            let delay = crate::retry::backoff(self.initial_backoff, self.max_backoff, self.attempt - 1);
+            self.set_state(ConnectionState::Waiting(delay));
+            thread::sleep(delay);
+        }
+        self.set_state(ConnectionState::Connecting { attempt: self.attempt });
+        let url = match self.reconnect_url.take() {
+            Some(url) => Ok(url),
+            None => (self.connect)(),
+        };
+        match url.and_then(|url| RtmClient::connect(&url)) {
+            Ok(client) => {
+                self.client = Some(client);
+                self.said_hello = false;
+                self.last_activity = Instant::now();
+                self.set_state(ConnectionState::Connected);
+                Ok(())
+            }
+            Err(e) => {
+                self.attempt += 1;
+                self.set_state(ConnectionState::Disconnected(DisconnectReason::Failed(e.to_string())));
+                if self.max_attempts.is_some_and(|max| self.attempt >= max) {
+                    self.set_state(ConnectionState::Closed);
+                    return Err(e);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Drops the websocket, to open a new one on the next read. Losing it before Slack said `hello`
+    /// counts as a failed attempt to connect.
+    fn disconnect(&mut self, reason: DisconnectReason) {
+        if !self.said_hello {
+            self.attempt += 1;
+        }
+        self.client = None;
+        self.ping = None;
+        self.set_state(ConnectionState::Disconnected(reason));
+    }
+
+    fn set_state(&mut self, state: ConnectionState) {
+        if let Some(ref mut callback) = self.on_state_change {
+            callback(&state);
+        }
+        self.state = state;
+    }
+}
+
+impl fmt::Debug for RtmSupervisor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RtmSupervisor")
+            .field("state", &self.state)
+            .field("client", &self.client)
+            .field("attempt", &self.attempt)
+            .finish()
+    }
+}
+
+impl Iterator for RtmSupervisor {
+    type Item = Result<Event, RtmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_event().transpose()
+    }
+}
+
+/// The most recent events that can be told apart from others, by their channel and timestamp.
+#[derive(Debug, Default)]
+struct ReplayWindow {
+    keys: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl ReplayWindow {
+    /// Remembers `event`, and returns whether it was seen before.
+    fn is_replay(&mut self, event: &Event) -> bool {
+        let key = match replay_key(event) {
+            Some(key) => key,
+            None => return false,
+        };
+        if !self.keys.insert(key.clone()) {
+            return true;
+        }
+        self.order.push_back(key);
+        if self.order.len() > REPLAY_WINDOW {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        false
+    }
+}
+
+/// Returns what identifies `event` among all others, if anything does.
+fn replay_key(event: &Event) -> Option<String> {
+    match *event {
+        Event::Message(ref message) => Some(format!("message {} {}", message.channel()?, message.ts()?)),
+        _ => {
+            let value = serde_json::to_value(event).ok()?;
+            Some(format!(
+                "{} {}",
+                value.get("type")?.as_str()?,
+                value.get("event_ts")?.as_str()?
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    use tungstenite::Message as Frame;
+
+    use super::{ConnectionState, DisconnectReason, RtmSupervisor};
+    use crate::rtm_client::RtmError;
+    use crate::Event;
+
+    /// Serves each script to one connection in turn, sending its frames and then, unless it ends
+    /// with `None`, closing the connection. Returns a connector for the supervisor.
+    fn serve(scripts: Vec<Vec<Option<&'static str>>>) -> impl FnMut() -> Result<String, RtmError> + Send {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let connections = scripts.len();
+        thread::spawn(move || {
+            for script in scripts {
+                let mut socket = tungstenite::accept(listener.accept().unwrap().0).unwrap();
+                thread::spawn(move || {
+                    for frame in &script {
+                        match *frame {
+                            Some(frame) => socket.send(Frame::text(frame)).unwrap(),
+                            // Goes quiet, reading without replying until the client is gone
+                            None => while socket.read().is_ok() {},
+                        }
+                    }
+                    let _ = socket.close(None);
+                    while socket.read().is_ok() {}
+                });
+            }
+        });
+        let mut calls = 0;
+        move || {
+            calls += 1;
+            if calls > connections {
+                Err(RtmError::MissingUrl)
+            } else {
+                Ok(url.clone())
+            }
+        }
+    }
+
+    fn record_states(supervisor: RtmSupervisor) -> (RtmSupervisor, Arc<Mutex<Vec<ConnectionState>>>) {
+        let states = Arc::new(Mutex::new(vec![]));
+        let recorded = states.clone();
+        let supervisor = supervisor.on_state_change(move |state| recorded.lock().unwrap().push(state.clone()));
+        (supervisor, states)
+    }
+
+    #[test]
+    fn test_reconnects_after_goodbye_and_skips_replayed_events() {
+        let connect = serve(vec![
+            vec![
+                Some(r#"{"type": "hello"}"#),
+                Some(r#"{"type": "message", "channel": "C1234567890", "text": "one", "ts": "1.000001"}"#),
+                Some(r#"{"type": "goodbye"}"#),
+            ],
+            vec![
+                Some(r#"{"type": "hello"}"#),
+                Some(r#"{"type": "message", "channel": "C1234567890", "text": "one", "ts": "1.000001"}"#),
+                Some(r#"{"type": "message", "channel": "C1234567890", "text": "two", "ts": "2.000002"}"#),
+            ],
+        ]);
+        let (mut supervisor, states) = record_states(RtmSupervisor::new(connect).max_attempts(1));
+        let texts = supervisor
+            .by_ref()
+            .filter_map(|event| match event {
+                Ok(Event::Message(message)) => message.text().map(str::to_owned),
+                Ok(_) => None,
+                Err(e) => Some(e.to_string()),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["one", "two", "the response did not include a websocket URL"],
+            texts
+        );
+        assert_eq!(
+            vec![
+                ConnectionState::Connecting { attempt: 0 },
+                ConnectionState::Connected,
+                ConnectionState::Disconnected(DisconnectReason::Goodbye),
+                ConnectionState::Connecting { attempt: 0 },
+                ConnectionState::Connected,
+                ConnectionState::Disconnected(DisconnectReason::Closed),
+                ConnectionState::Connecting { attempt: 0 },
+                ConnectionState::Disconnected(DisconnectReason::Failed(RtmError::MissingUrl.to_string())),
+                ConnectionState::Closed,
+            ],
+            *states.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_reconnects_when_pongs_are_missed() {
+        let connect = serve(vec![
+            vec![Some(r#"{"type": "hello"}"#), None],
+            vec![Some(r#"{"type": "hello"}"#), None],
+        ]);
+        let (supervisor, states) = record_states(
+            RtmSupervisor::new(connect)
+                .ping_interval(Duration::from_millis(20))
+                .pong_timeout(Duration::from_millis(20))
+                .initial_backoff(Duration::from_millis(1))
+                .max_attempts(2),
+        );
+        let events = supervisor.collect::<Vec<_>>();
+        assert_eq!(3, events.len(), "{:?}", events);
+        let states = states.lock().unwrap();
+        assert_eq!(
+            2,
+            states
+                .iter()
+                .filter(|&state| *state == ConnectionState::Disconnected(DisconnectReason::MissedPong))
+                .count()
+        );
+        assert!(states.iter().any(|state| match *state {
+            ConnectionState::Waiting(delay) => delay <= Duration::from_millis(1),
+            _ => false,
+        }));
+        assert_eq!(Some(&ConnectionState::Closed), states.last());
+    }
+
+    #[test]
+    fn test_backs_off_when_connections_close_before_hello() {
+        let connect = serve(vec![vec![], vec![], vec![]]);
+        let (supervisor, states) = record_states(
+            RtmSupervisor::new(connect)
+                .initial_backoff(Duration::from_millis(1))
+                .max_attempts(3),
+        );
+        let events = supervisor.collect::<Vec<_>>();
+        assert!(matches!(events[..], [Err(RtmError::Socket(_))]), "{:?}", events);
+        let states = states.lock().unwrap();
+        let waits = states
+            .iter()
+            .filter_map(|state| match *state {
+                ConnectionState::Waiting(delay) => Some(delay),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        // Each backoff is at least half of its ceiling, which doubles with every attempt
+        assert_eq!(2, waits.len(), "{:?}", waits);
+        assert!(waits[0] <= Duration::from_millis(1) && waits[1] >= Duration::from_millis(1), "{:?}", waits);
+        let attempts = states
+            .iter()
+            .filter_map(|state| match *state {
+                ConnectionState::Connecting { attempt } => Some(attempt),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![0, 1, 2], attempts);
+        assert_eq!(Some(&ConnectionState::Closed), states.last());
+    }
+}
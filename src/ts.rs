@@ -0,0 +1,234 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::InvalidTs;
+
+/// A Slack timestamp, such as the `ts` that identifies a message within a channel.
+///
+/// The timestamp keeps the exact string Slack sent, so that it identifies the same message when it
+/// is sent back. Timestamps compare by the time they stand for, and convert to and from
+/// [`SystemTime`]:
+///
+/// ```
+/// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+/// use slack_api::Ts;
+///
+/// let ts: Ts = "1503435956.000247".parse().unwrap();
+/// assert_eq!("1503435956.000247", ts.as_str());
+/// assert!(ts < "1503435956.000248".parse().unwrap());
+/// assert_eq!(UNIX_EPOCH + Duration::new(1503435956, 247_000), SystemTime::from(&ts));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ts {
+    repr: String,
+    secs: u64,
+    nanos: u32,
+    /// Whether Slack sent this timestamp as a JSON number rather than a string
+    numeric: bool,
+}
+
+impl Ts {
+    /// Returns the timestamp as Slack sent it.
+    pub fn as_str(&self) -> &str {
+        &self.repr
+    }
+
+    fn parse(repr: String, numeric: bool) -> Result<Self, InvalidTs> {
+        let (secs, fraction) = match repr.find('.') {
+            Some(i) => (&repr[..i], &repr[i + 1..]),
+            None => (&repr[..], ""),
+        };
+        let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if secs.is_empty() || !digits(secs) || !digits(fraction) || fraction.len() > 9 {
+            return Err(InvalidTs(repr));
+        }
+        let secs = match secs.parse() {
+            Ok(secs) => secs,
+            Err(_) => return Err(InvalidTs(repr)),
+        };
+        let nanos = format!("{:0<9}", fraction).parse().unwrap_or(0);
+        Ok(Ts {
+            repr,
+            secs,
+            nanos,
+            numeric,
+        })
+    }
+
+    /// Returns the timestamp serialized as a JSON number, for fields that Slack documents as one.
+    pub(crate) fn to_numeric(&self) -> Ts {
+        Ts {
+            numeric: true,
+            ..self.clone()
+        }
+    }
+}
+
+impl TryFrom<String> for Ts {
+    type Error = InvalidTs;
+
+    fn try_from(repr: String) -> Result<Self, InvalidTs> {
+        Ts::parse(repr, false)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Ts {
+    type Error = InvalidTs;
+
+    fn try_from(repr: &'a str) -> Result<Self, InvalidTs> {
+        Ts::parse(repr.to_owned(), false)
+    }
+}
+
+impl FromStr for Ts {
+    type Err = InvalidTs;
+
+    fn from_str(repr: &str) -> Result<Self, InvalidTs> {
+        Ts::try_from(repr)
+    }
+}
+
+/// Times before the Unix epoch become the epoch itself.
+impl From<SystemTime> for Ts {
+    fn from(time: SystemTime) -> Self {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let micros = since_epoch.subsec_micros();
+        Ts {
+            repr: format!("{}.{:06}", since_epoch.as_secs(), micros),
+            secs: since_epoch.as_secs(),
+            nanos: micros * 1000,
+            numeric: false,
+        }
+    }
+}
+
+impl<'a> From<&'a Ts> for SystemTime {
+    fn from(ts: &'a Ts) -> Self {
+        UNIX_EPOCH + Duration::new(ts.secs, ts.nanos)
+    }
+}
+
+impl From<Ts> for SystemTime {
+    fn from(ts: Ts) -> Self {
+        SystemTime::from(&ts)
+    }
+}
+
+impl PartialEq for Ts {
+    fn eq(&self, other: &Ts) -> bool {
+        (self.secs, self.nanos) == (other.secs, other.nanos)
+    }
+}
+
+impl Eq for Ts {}
+
+impl PartialOrd for Ts {
+    fn partial_cmp(&self, other: &Ts) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ts {
+    fn cmp(&self, other: &Ts) -> Ordering {
+        (self.secs, self.nanos).cmp(&(other.secs, other.nanos))
+    }
+}
+
+impl Hash for Ts {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.secs, self.nanos).hash(state);
+    }
+}
+
+impl AsRef<str> for Ts {
+    fn as_ref(&self) -> &str {
+        &self.repr
+    }
+}
+
+impl fmt::Display for Ts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.repr)
+    }
+}
+
+impl ::serde::Serialize for Ts {
+    fn serialize<S: ::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match (self.numeric, self.nanos) {
+            (true, 0) if !self.repr.contains('.') => s.serialize_u64(self.secs),
+            (true, _) => s.serialize_f64(self.secs as f64 + f64::from(self.nanos) / 1e9),
+            (false, _) => s.serialize_str(&self.repr),
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Ts {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use serde::de::{Error, Unexpected, Visitor};
+
+        struct TsVisitor;
+
+        impl<'de> Visitor<'de> for TsVisitor {
+            type Value = Ts;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a timestamp as a string or a number")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Ts, E> {
+                Ts::parse(v.to_owned(), false).map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Ts, E> {
+                Ok(Ts::parse(v.to_string(), true).expect("integers are valid timestamps"))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Ts, E> {
+                Ts::parse(v.to_string(), true).map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_f64<E: Error>(self, v: f64) -> Result<Ts, E> {
+                Ts::parse(format!("{:.6}", v), true).map_err(|_| E::invalid_value(Unexpected::Float(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(TsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::Ts;
+
+    #[test]
+    fn test_ts_keeps_exact_string_and_orders_by_time() {
+        let ts: Ts = "1503435956.000247".parse().unwrap();
+        assert_eq!("1503435956.000247", ts.to_string());
+        assert!(ts > "1503435956.00024".parse().unwrap());
+        assert!(ts < "1503435957".parse().unwrap());
+        assert!("1503435956.x".parse::<Ts>().is_err());
+        assert!(".5".parse::<Ts>().is_err());
+
+        let ts = Ts::from(UNIX_EPOCH + Duration::new(1503435956, 247_000));
+        assert_eq!("1503435956.000247", ts.as_str());
+    }
+
+    #[test]
+    fn test_ts_serializes_as_it_was_sent() {
+        let ts: Ts = serde_json::from_str(r#""1503435956.000247""#).unwrap();
+        assert_eq!(r#""1503435956.000247""#, serde_json::to_string(&ts).unwrap());
+
+        let ts: Ts = serde_json::from_str("1503435956").unwrap();
+        assert_eq!("1503435956", ts.as_str());
+        assert_eq!("1503435956", serde_json::to_string(&ts).unwrap());
+    }
+}
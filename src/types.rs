@@ -1,225 +1,492 @@
 use std::collections::HashMap;
-use std::fmt;
-use serde::de::{Visitor, Error, Unexpected, Deserializer};
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
-pub struct Timestamp {
-    repr: f64,
-}
-
-impl fmt::Display for Timestamp {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.repr)
-    }
-}
-
-/// Deserialize a maybe-string timestamp into a Timestamp.
-pub fn deserialize_timestamp<'d, D: Deserializer<'d>>(d: D) -> Result<Option<Timestamp>, D::Error> {
-    struct TimestampVisitor;
-	impl<'d> Visitor<'d> for TimestampVisitor {
-        type Value = Option<Timestamp>;
-
-        fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-            write!(fmt, "an f64, u64, or parseable string")
-        }
-
-        fn visit_f64<E: Error>(self, v: f64) -> Result<Option<Timestamp>, E> {
-            Ok(Some(Timestamp {
-                repr: v,
-            }))
-        }
-
-        fn visit_str<E: Error>(self, v: &str) -> Result<Option<Timestamp>, E> {
-            Ok(Some(Timestamp {
-                repr: v.parse::<f64>().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))?
-            }))
-        }
-	    
-        fn visit_u64<E: Error>(self, v: u64) -> Result<Option<Timestamp>, E> {
-            Ok(Some(Timestamp {
-                repr: v as f64,
-            }))
-        }
-    }
-
-    d.deserialize_any(TimestampVisitor)
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Bot {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub app_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<BotIcons>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BotIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Channel {
-    pub accepted_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_user: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_channel: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_general: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_member: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_moved: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_org_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_pending_ext_shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_shared: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<crate::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub num_members: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_names: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<ChannelPurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<ChannelTopic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unlinked: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelPurpose {
-    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelTopic {
-    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct File {
-    pub channels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<crate::ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comments_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_as_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edit_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub editable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub external_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filetype: Option<String>,
-    pub groups: Option<Vec<String>>,
-    pub id: Option<String>,
-    pub ims: Option<Vec<String>>,
-    pub initial_comment: Option<::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<crate::ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::FileId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ims: Option<Vec<crate::ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_comment: Option<crate::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_external: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_public: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_starred: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lines: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub lines_more: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mimetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub num_stars: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permalink: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub permalink_public: Option<String>,
-    pub pinned_to: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned_to: Option<Vec<crate::ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretty_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preview: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preview_highlight: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub public_url_shared: Option<bool>,
-    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<crate::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_160: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_gif: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_h: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_360_w: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480_h: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_480_w: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_64: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_80: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url_private: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub url_private_download: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FileComment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
-    pub id: Option<String>,
-    pub reactions: Option<Vec<::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::FileCommentId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<Vec<crate::Reaction>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Group {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<crate::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<GroupPurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<GroupTopic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupPurpose {
-    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GroupTopic {
-    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_set: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Im {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_im: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_user_deleted: Option<bool>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Item {
+    Message(ItemMessage),
+    File(ItemFile),
+    FileComment(ItemFileComment),
+    Channel(ItemChannel),
+    Im(ItemIm),
+    Group(ItemGroup),
+}
+
+impl<'de> ::serde::Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
+
+        const VARIANTS: &[&str] =
+            &["message", "file", "file_comment", "channel", "im", "group"];
+
+        let value = ::serde_json::Value::deserialize(deserializer)?;
+        if let Some(ty_val) = value.get("type") {
+            if let Some(ty) = ty_val.as_str() {
+                match ty {
+                    "message" => {
+                        ::serde_json::from_value::<ItemMessage>(value.clone())
+                            .map(Item::Message)
+                            .map_err(D::Error::custom)
+                    }
+                    "file" => {
+                        ::serde_json::from_value::<ItemFile>(value.clone())
+                            .map(Item::File)
+                            .map_err(D::Error::custom)
+                    }
+                    "file_comment" => {
+                        ::serde_json::from_value::<ItemFileComment>(value.clone())
+                            .map(Item::FileComment)
+                            .map_err(D::Error::custom)
+                    }
+                    "channel" => {
+                        ::serde_json::from_value::<ItemChannel>(value.clone())
+                            .map(Item::Channel)
+                            .map_err(D::Error::custom)
+                    }
+                    "im" => {
+                        ::serde_json::from_value::<ItemIm>(value.clone())
+                            .map(Item::Im)
+                            .map_err(D::Error::custom)
+                    }
+                    "group" => {
+                        ::serde_json::from_value::<ItemGroup>(value.clone())
+                            .map(Item::Group)
+                            .map_err(D::Error::custom)
+                    }
+                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                }
+            } else {
+                Err(D::Error::invalid_type(
+                    ::serde::de::Unexpected::Unit,
+                    &"a string",
+                ))
+            }
+        } else {
+            Err(D::Error::missing_field("type"))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemChannel {
+    pub channel: crate::ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    pub file: crate::File,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemFileComment {
+    pub comment: crate::FileComment,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    pub file: crate::File,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemGroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    pub group: crate::ChannelId,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemIm {
+    pub channel: crate::ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ItemMessage {
+    pub channel: crate::ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    pub message: crate::Message,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
 pub enum Message {
     Standard(MessageStandard),
+    BotAdd(MessageBotAdd),
     BotMessage(MessageBotMessage),
+    BotRemove(MessageBotRemove),
     ChannelArchive(MessageChannelArchive),
+    ChannelConvertToPrivate(MessageChannelConvertToPrivate),
     ChannelJoin(MessageChannelJoin),
     ChannelLeave(MessageChannelLeave),
     ChannelName(MessageChannelName),
+    ChannelPostingPermissions(MessageChannelPostingPermissions),
     ChannelPurpose(MessageChannelPurpose),
     ChannelTopic(MessageChannelTopic),
     ChannelUnarchive(MessageChannelUnarchive),
+    EkmAccessDenied(MessageEkmAccessDenied),
     FileComment(MessageFileComment),
     FileMention(MessageFileMention),
     FileShare(MessageFileShare),
@@ -235,8 +502,13 @@ pub enum Message {
     MessageDeleted(MessageMessageDeleted),
     MessageReplied(MessageMessageReplied),
     PinnedItem(MessagePinnedItem),
+    ReminderAdd(MessageReminderAdd),
     ReplyBroadcast(MessageReplyBroadcast),
+    SlackbotResponse(MessageSlackbotResponse),
+    ThreadBroadcast(MessageThreadBroadcast),
+    Tombstone(MessageTombstone),
     UnpinnedItem(MessageUnpinnedItem),
+    Unknown(MessageUnknown),
 }
 
 impl<'de> ::serde::Deserialize<'de> for Message {
@@ -246,35 +518,6 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     {
         use serde::de::Error as SerdeError;
 
-        const VARIANTS: &'static [&'static str] = &[
-            "standard",
-            "bot_message",
-            "channel_archive",
-            "channel_join",
-            "channel_leave",
-            "channel_name",
-            "channel_purpose",
-            "channel_topic",
-            "channel_unarchive",
-            "file_comment",
-            "file_mention",
-            "file_share",
-            "group_archive",
-            "group_join",
-            "group_leave",
-            "group_name",
-            "group_purpose",
-            "group_topic",
-            "group_unarchive",
-            "me_message",
-            "message_changed",
-            "message_deleted",
-            "message_replied",
-            "pinned_item",
-            "reply_broadcast",
-            "unpinned_item",
-        ];
-
         let value = ::serde_json::Value::deserialize(deserializer)?;
         if let Some(ty_val) = value.get("subtype") {
             if let Some(ty) = ty_val.as_str() {
@@ -282,134 +525,183 @@ impl<'de> ::serde::Deserialize<'de> for Message {
                     "standard" => {
                         ::serde_json::from_value::<MessageStandard>(value.clone())
                             .map(Message::Standard)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "bot_add" => {
+                        ::serde_json::from_value::<MessageBotAdd>(value.clone())
+                            .map(Message::BotAdd)
+                            .map_err(D::Error::custom)
                     }
                     "bot_message" => {
                         ::serde_json::from_value::<MessageBotMessage>(value.clone())
                             .map(Message::BotMessage)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "bot_remove" => {
+                        ::serde_json::from_value::<MessageBotRemove>(value.clone())
+                            .map(Message::BotRemove)
+                            .map_err(D::Error::custom)
                     }
                     "channel_archive" => {
                         ::serde_json::from_value::<MessageChannelArchive>(value.clone())
                             .map(Message::ChannelArchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "channel_convert_to_private" => {
+                        ::serde_json::from_value::<MessageChannelConvertToPrivate>(value.clone())
+                            .map(Message::ChannelConvertToPrivate)
+                            .map_err(D::Error::custom)
                     }
                     "channel_join" => {
                         ::serde_json::from_value::<MessageChannelJoin>(value.clone())
                             .map(Message::ChannelJoin)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_leave" => {
                         ::serde_json::from_value::<MessageChannelLeave>(value.clone())
                             .map(Message::ChannelLeave)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_name" => {
                         ::serde_json::from_value::<MessageChannelName>(value.clone())
                             .map(Message::ChannelName)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "channel_posting_permissions" => {
+                        ::serde_json::from_value::<MessageChannelPostingPermissions>(value.clone())
+                            .map(Message::ChannelPostingPermissions)
+                            .map_err(D::Error::custom)
                     }
                     "channel_purpose" => {
                         ::serde_json::from_value::<MessageChannelPurpose>(value.clone())
                             .map(Message::ChannelPurpose)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_topic" => {
                         ::serde_json::from_value::<MessageChannelTopic>(value.clone())
                             .map(Message::ChannelTopic)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_unarchive" => {
                         ::serde_json::from_value::<MessageChannelUnarchive>(value.clone())
                             .map(Message::ChannelUnarchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "ekm_access_denied" => {
+                        ::serde_json::from_value::<MessageEkmAccessDenied>(value.clone())
+                            .map(Message::EkmAccessDenied)
+                            .map_err(D::Error::custom)
                     }
                     "file_comment" => {
                         ::serde_json::from_value::<MessageFileComment>(value.clone())
                             .map(Message::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "file_mention" => {
                         ::serde_json::from_value::<MessageFileMention>(value.clone())
                             .map(Message::FileMention)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "file_share" => {
                         ::serde_json::from_value::<MessageFileShare>(value.clone())
                             .map(Message::FileShare)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_archive" => {
                         ::serde_json::from_value::<MessageGroupArchive>(value.clone())
                             .map(Message::GroupArchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_join" => {
                         ::serde_json::from_value::<MessageGroupJoin>(value.clone())
                             .map(Message::GroupJoin)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_leave" => {
                         ::serde_json::from_value::<MessageGroupLeave>(value.clone())
                             .map(Message::GroupLeave)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_name" => {
                         ::serde_json::from_value::<MessageGroupName>(value.clone())
                             .map(Message::GroupName)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_purpose" => {
                         ::serde_json::from_value::<MessageGroupPurpose>(value.clone())
                             .map(Message::GroupPurpose)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_topic" => {
                         ::serde_json::from_value::<MessageGroupTopic>(value.clone())
                             .map(Message::GroupTopic)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_unarchive" => {
                         ::serde_json::from_value::<MessageGroupUnarchive>(value.clone())
                             .map(Message::GroupUnarchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "me_message" => {
                         ::serde_json::from_value::<MessageMeMessage>(value.clone())
                             .map(Message::MeMessage)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "message_changed" => {
                         ::serde_json::from_value::<MessageMessageChanged>(value.clone())
                             .map(Message::MessageChanged)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "message_deleted" => {
                         ::serde_json::from_value::<MessageMessageDeleted>(value.clone())
                             .map(Message::MessageDeleted)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "message_replied" => {
                         ::serde_json::from_value::<MessageMessageReplied>(value.clone())
                             .map(Message::MessageReplied)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "pinned_item" => {
                         ::serde_json::from_value::<MessagePinnedItem>(value.clone())
                             .map(Message::PinnedItem)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "reminder_add" => {
+                        ::serde_json::from_value::<MessageReminderAdd>(value.clone())
+                            .map(Message::ReminderAdd)
+                            .map_err(D::Error::custom)
                     }
                     "reply_broadcast" => {
                         ::serde_json::from_value::<MessageReplyBroadcast>(value.clone())
                             .map(Message::ReplyBroadcast)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "slackbot_response" => {
+                        ::serde_json::from_value::<MessageSlackbotResponse>(value.clone())
+                            .map(Message::SlackbotResponse)
+                            .map_err(D::Error::custom)
+                    }
+                    "thread_broadcast" => {
+                        ::serde_json::from_value::<MessageThreadBroadcast>(value.clone())
+                            .map(Message::ThreadBroadcast)
+                            .map_err(D::Error::custom)
+                    }
+                    "tombstone" => {
+                        ::serde_json::from_value::<MessageTombstone>(value.clone())
+                            .map(Message::Tombstone)
+                            .map_err(D::Error::custom)
                     }
                     "unpinned_item" => {
                         ::serde_json::from_value::<MessageUnpinnedItem>(value.clone())
                             .map(Message::UnpinnedItem)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    _ => {
+                        ::serde_json::from_value::<MessageUnknown>(value.clone())
+                            .map(Message::Unknown)
+                            .map_err(D::Error::custom)
                     }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
                 }
             } else {
                 Err(D::Error::invalid_type(
@@ -420,677 +712,1506 @@ impl<'de> ::serde::Deserialize<'de> for Message {
         } else {
             ::serde_json::from_value::<MessageStandard>(value.clone())
                 .map(Message::Standard)
-                .map_err(|e| D::Error::custom(&format!("{}", e)))
+                .map_err(D::Error::custom)
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageBotAdd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessage {
-    pub bot_id: Option<String>,
+    #[serde(deserialize_with = "crate::blocks::deserialize_received")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icons: Option<MessageBotMessageIcons>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageBotMessageIcons {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_36: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageBotRemove {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelArchive {
-    pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageChannelConvertToPrivate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelJoin {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelLeave {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelName {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub old_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageChannelPostingPermissions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageChannelUnarchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageEkmAccessDenied {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileComment {
-    pub comment: Option<::FileComment>,
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<crate::FileComment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileMention {
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageFileShare {
-    pub file: Option<::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<crate::File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub upload: Option<bool>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupArchive {
-    pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupJoin {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupLeave {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupName {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub old_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupPurpose {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub purpose: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupTopic {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub topic: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageGroupUnarchive {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMeMessage {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChanged {
-    pub channel: Option<String>,
-    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<MessageMessageChangedMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_message: Option<MessageMessageChangedPreviousMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessage {
-    pub bot_id: Option<String>,
+    #[serde(deserialize_with = "crate::blocks::deserialize_received")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageChangedMessageEdited>,
-    pub last_read: Option<String>,
-    pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageChangedMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedMessageReply {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessage {
-    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageChangedPreviousMessageEdited>,
-    pub last_read: Option<String>,
-    pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageChangedPreviousMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageChangedPreviousMessageReply {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeleted {
-    pub channel: Option<String>,
-    pub deleted_ts: Option<String>,
-    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub previous_message: Option<MessageMessageDeletedPreviousMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessage {
-    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageDeletedPreviousMessageEdited>,
-    pub last_read: Option<String>,
-    pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageDeletedPreviousMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageReply {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageReplied {
-    pub channel: Option<String>,
-    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<MessageMessageRepliedMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessage {
-    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageMessageRepliedMessageEdited>,
-    pub last_read: Option<String>,
-    pub parent_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_user_id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<MessageMessageRepliedMessageReply>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageMessageRepliedMessageReply {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessagePinnedItem {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<MessagePinnedItemItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessagePinnedItemItem {}
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessagePinnedItemItem {
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageReminderAdd {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcast {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
-    pub channel: Option<String>,
-    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageReplyBroadcastAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_subname: Option<String>,
-    pub channel_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub channel_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub from_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mrkdwn_in: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageSlackbotResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandard {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub attachments: Option<Vec<MessageStandardAttachment>>,
-    pub bot_id: Option<String>,
-    pub channel: Option<String>,
+    #[serde(deserialize_with = "crate::blocks::deserialize_received")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<crate::BotId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub edited: Option<MessageStandardEdited>,
-    pub event_ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_broadcast: Option<bool>,
-    pub source_team: Option<String>,
-    pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_team: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub thread_ts: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardAttachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fallback: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<MessageStandardAttachmentField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub footer_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title_link: Option<String>,
-    #[serde(deserialize_with = "deserialize_timestamp")]
-    #[serde(default)]
-    pub ts: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardAttachmentField {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub short: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageStandardEdited {
-    pub ts: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageThreadBroadcast {
+    #[serde(deserialize_with = "crate::blocks::deserialize_received")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<MessageThreadBroadcastRoot>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageThreadBroadcastRoot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageTombstone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUnpinnedItem {
-    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item: Option<MessageUnpinnedItemItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subtype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
-    pub ts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ts: Option<crate::Ts>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MessageUnpinnedItemItem {
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// A message with a subtype that is not known, which keeps the JSON it was sent as.
+///
+/// The fields most messages have are read from the JSON for the accessors of `Message`. A field that
+/// does not hold what it should is left out rather than failing the message.
+#[derive(Clone, Debug)]
+pub struct MessageUnknown {
+    pub(crate) bot_id: Option<crate::BotId>,
+    pub(crate) channel: Option<crate::ChannelId>,
+    pub(crate) subtype: Option<String>,
+    pub(crate) text: Option<String>,
+    pub(crate) thread_ts: Option<crate::Ts>,
+    pub(crate) ts: Option<crate::Ts>,
+    pub(crate) user: Option<crate::UserId>,
+    json: serde_json::Value,
+}
+
+impl MessageUnknown {
+    /// Returns the message as it was sent.
+    pub fn json(&self) -> &serde_json::Value {
+        &self.json
+    }
+
+    /// Returns the message as it was sent, consuming it.
+    pub fn into_json(self) -> serde_json::Value {
+        self.json
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct MessageUnpinnedItemItem {}
+impl<'de> ::serde::Deserialize<'de> for MessageUnknown {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        fn field<T: ::serde::de::DeserializeOwned>(json: &serde_json::Value, name: &str) -> Option<T> {
+            json.get(name).and_then(|value| T::deserialize(value).ok())
+        }
+
+        let json = <serde_json::Value as ::serde::Deserialize>::deserialize(deserializer)?;
+        Ok(MessageUnknown {
+            bot_id: field(&json, "bot_id"),
+            channel: field(&json, "channel"),
+            subtype: field(&json, "subtype"),
+            text: field(&json, "text"),
+            thread_ts: field(&json, "thread_ts"),
+            ts: field(&json, "ts"),
+            user: field(&json, "user"),
+            json,
+        })
+    }
+}
 
-#[derive(Clone, Debug, Deserialize)]
+impl ::serde::Serialize for MessageUnknown {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        ::serde::Serialize::serialize(&self.json, serializer)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Mpim {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created: Option<i32>,
-    pub creator: Option<String>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_group: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_mpim: Option<bool>,
-    pub last_read: Option<String>,
-    pub latest: Option<::Message>,
-    pub members: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read: Option<crate::Ts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<crate::Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<crate::UserId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub unread_count_display: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Paging {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    pub users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<crate::UserId>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Reminder {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub complete_ts: Option<f32>,
-    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub recurring: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time: Option<f32>,
-    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<crate::UserId>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Team {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email_domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<TeamIcon>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TeamIcon {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_102: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_132: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_34: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_44: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_68: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_88: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_default: Option<bool>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ThreadInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub complete: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<i32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub has_2fa: Option<bool>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_app_user: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_bot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_primary_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_ultra_restricted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    pub profile: Option<::UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<crate::UserProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name: Option<String>,
-    pub team_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub two_factor_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz_label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tz_offset: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub updated: Option<f32>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Usergroup {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub auto_type: Option<String>,
-    pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_create: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_delete: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub date_update: Option<i32>,
-    pub deleted_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_by: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub handle: Option<String>,
-    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<crate::UsergroupId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_external: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_usergroup: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub prefs: Option<UsergroupPrefs>,
-    pub team_id: Option<String>,
-    pub updated_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<crate::UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub user_count: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UsergroupPrefs {
-    pub channels: Option<Vec<String>>,
-    pub groups: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<crate::ChannelId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<crate::ChannelId>>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub display_name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
-    #[serde(deserialize_with = "::optional_struct_or_empty_array")]
+    #[serde(deserialize_with = "crate::optional_struct_or_empty_array")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<HashMap<String, UserProfileFields>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub guest_channels: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_192: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_24: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_32: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_48: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_512: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_72: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub image_original: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub real_name_normalized: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub skype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_text: Option<String>,
-    pub team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<crate::TeamId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct UserProfileFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Fields that are not in the schema
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
+
+#[cfg(test)]
+mod tests;
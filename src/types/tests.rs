@@ -0,0 +1,95 @@
+//! Tests of the generated types, kept apart so that regenerating them keeps the tests.
+
+use super::{Item, Message, User};
+
+#[test]
+fn test_messages_serialize_to_wire_format() {
+    let json = serde_json::json!({
+        "type": "message",
+        "subtype": "bot_message",
+        "bot_id": "B1234567890",
+        "text": "Hello",
+        "ts": "1355517523.000005",
+    });
+    let message: Message = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(json, serde_json::to_value(&message).unwrap());
+}
+
+#[test]
+fn test_tagged_items_serialize_to_wire_format() {
+    let json = serde_json::json!({
+        "type": "message",
+        "channel": "C1234567890",
+        "message": {
+            "type": "message",
+            "text": "Hello",
+            "ts": "1355517523.000005",
+            "attachments": [{"fallback": "more", "ts": 1355517523}],
+        },
+    });
+    let item: Item = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(json, serde_json::to_value(&item).unwrap());
+}
+
+#[test]
+fn test_unknown_message_subtypes_keep_their_json() {
+    let json = serde_json::json!([
+        {"type": "message", "subtype": "tombstone", "hidden": true, "ts": "1.000001"},
+        {
+            "type": "message", "subtype": "huddle_thread", "ts": "2.000002",
+            "user": {"id": "U1"}, "room": {"id": "R1"},
+        },
+    ]);
+    let messages: Vec<Message> = serde_json::from_value(json.clone()).unwrap();
+    match messages[0] {
+        Message::Tombstone(ref m) => assert_eq!(Some(true), m.hidden),
+        ref other => panic!("{:?}", other),
+    }
+    match messages[1] {
+        Message::Unknown(ref m) => assert_eq!("R1", m.json()["room"]["id"]),
+        ref other => panic!("{:?}", other),
+    }
+    assert_eq!(Some("huddle_thread"), messages[1].subtype());
+    assert_eq!("2.000002", messages[1].ts().unwrap().as_str());
+    assert_eq!(None, messages[1].user());
+    assert_eq!(json, serde_json::to_value(&messages).unwrap());
+}
+
+#[test]
+fn test_fields_missing_from_the_schema_are_kept() {
+    let json = serde_json::json!({
+        "id": "U1234567890",
+        "profile": {"real_name": "Ann", "status_expiration": 1503435956},
+        "is_workflow_bot": false,
+    });
+    let user: User = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(Some(&serde_json::json!(false)), user.extra.get("is_workflow_bot"));
+    let profile = user.profile.as_ref().unwrap();
+    assert_eq!(Some(&serde_json::json!(1503435956)), profile.extra.get("status_expiration"));
+    assert_eq!(json, serde_json::to_value(&user).unwrap());
+}
+
+#[test]
+fn test_reactions_get_returns_the_item() {
+    let json = r#"{"ok": true, "type": "file", "file": {"id": "F1234567890", "reactions": []}}"#;
+    let response: crate::reactions::GetResponse = serde_json::from_str(json).unwrap();
+    match response.item {
+        Some(Item::File(ref item)) => assert_eq!("F1234567890", item.file.id.as_ref().unwrap()),
+        ref other => panic!("{:?}", other),
+    }
+    assert!(response.extra.is_empty());
+    assert_eq!(serde_json::from_str::<serde_json::Value>(json).unwrap(), serde_json::to_value(&response).unwrap());
+}
+
+#[test]
+fn test_reactions_get_fails_on_an_unparseable_item() {
+    let json = r#"{"ok": true, "type": "file", "file": "F1234567890"}"#;
+    assert!(serde_json::from_str::<crate::reactions::GetResponse>(json).is_err());
+    assert!(serde_json::from_str::<crate::reactions::GetResponse>(r#"{"ok": true}"#).is_err());
+
+    let json = r#"{"ok": false, "error": "message_not_found", "warning": "superfluous_charset"}"#;
+    let response: crate::reactions::GetResponse = serde_json::from_str(json).unwrap();
+    assert!(response.item.is_none());
+    assert_eq!("superfluous_charset", response.extra["warning"]);
+    assert_eq!(serde_json::from_str::<serde_json::Value>(json).unwrap(), serde_json::to_value(&response).unwrap());
+}
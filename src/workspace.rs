@@ -0,0 +1,632 @@
+//! An in-memory model of a workspace, seeded from `rtm.start` and kept current by events.
+//!
+//! ```
+//! use slack_api::rtm::StartResponse;
+//! use slack_api::{Event, Workspace};
+//!
+//! let json = r#"{"ok": true, "channels": [{"id": "C1234567890", "name": "general"}]}"#;
+//! let response: StartResponse = serde_json::from_str(json).unwrap();
+//! let mut workspace = Workspace::from_start(response);
+//!
+//! let json = r#"{"type": "channel_rename", "channel": {"id": "C1234567890", "name": "lobby"}}"#;
+//! let event: Event = serde_json::from_str(json).unwrap();
+//! workspace.update(&event);
+//! assert!(workspace.channel_by_name("general").is_none());
+//! assert_eq!("C1234567890", workspace.channel_by_name("lobby").unwrap().id.as_ref().unwrap().as_str());
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use crate::event::{ChannelRename, GroupRename, MemberJoinedChannel, MemberLeftChannel};
+use crate::rtm::StartResponse;
+use crate::{Bot, BotId, Channel, ChannelId, Event, Group, Im, Mpim, Team, User, UserId};
+
+/// The channels, groups, IMs, MPIMs, users and bots of a workspace, looked up by ID and by name.
+///
+/// [`update`](#method.update) applies the changes an [`Event`] makes to them, such as a channel
+/// being created, renamed or archived, a member joining a channel or a user changing their
+/// profile. Events that do not change any of them are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Workspace {
+    team: Option<Team>,
+    slf: Option<User>,
+    channels: Index<ChannelId, Channel>,
+    groups: Index<ChannelId, Group>,
+    ims: Index<ChannelId, Im>,
+    mpims: Index<ChannelId, Mpim>,
+    users: Index<UserId, User>,
+    bots: Index<BotId, Bot>,
+}
+
+impl Workspace {
+    /// Creates a workspace from the objects `rtm.start` returned.
+    ///
+    /// Objects without an ID are left out.
+    pub fn from_start(response: StartResponse) -> Self {
+        Workspace {
+            team: response.team,
+            slf: response.slf,
+            channels: response.channels.into_iter().flatten().collect(),
+            groups: response.groups.into_iter().flatten().collect(),
+            ims: response.ims.into_iter().flatten().collect(),
+            mpims: response.mpims.into_iter().flatten().collect(),
+            users: response.users.into_iter().flatten().collect(),
+            bots: response.bots.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Returns the team of the workspace.
+    pub fn team(&self) -> Option<&Team> {
+        self.team.as_ref()
+    }
+
+    /// Returns the user the session was started for, as `rtm.start` described it.
+    pub fn slf(&self) -> Option<&User> {
+        self.slf.as_ref()
+    }
+
+    /// Returns the public channel with the ID `id`.
+    pub fn channel(&self, id: &ChannelId) -> Option<&Channel> {
+        self.channels.get(id)
+    }
+
+    /// Returns the public channel named `name`.
+    pub fn channel_by_name(&self, name: &str) -> Option<&Channel> {
+        self.channels.get_by_name(name)
+    }
+
+    /// Returns every public channel, in no particular order.
+    pub fn channels(&self) -> impl Iterator<Item = &Channel> {
+        self.channels.values()
+    }
+
+    /// Returns the private channel with the ID `id`.
+    pub fn group(&self, id: &ChannelId) -> Option<&Group> {
+        self.groups.get(id)
+    }
+
+    /// Returns the private channel named `name`.
+    pub fn group_by_name(&self, name: &str) -> Option<&Group> {
+        self.groups.get_by_name(name)
+    }
+
+    /// Returns every private channel, in no particular order.
+    pub fn groups(&self) -> impl Iterator<Item = &Group> {
+        self.groups.values()
+    }
+
+    /// Returns the direct message channel with the ID `id`.
+    pub fn im(&self, id: &ChannelId) -> Option<&Im> {
+        self.ims.get(id)
+    }
+
+    /// Returns the direct message channel with `user`.
+    pub fn im_with_user(&self, user: &UserId) -> Option<&Im> {
+        self.ims.get_by_name(user.as_str())
+    }
+
+    /// Returns every direct message channel, in no particular order.
+    pub fn ims(&self) -> impl Iterator<Item = &Im> {
+        self.ims.values()
+    }
+
+    /// Returns the multiparty direct message channel with the ID `id`.
+    pub fn mpim(&self, id: &ChannelId) -> Option<&Mpim> {
+        self.mpims.get(id)
+    }
+
+    /// Returns the multiparty direct message channel named `name`.
+    pub fn mpim_by_name(&self, name: &str) -> Option<&Mpim> {
+        self.mpims.get_by_name(name)
+    }
+
+    /// Returns every multiparty direct message channel, in no particular order.
+    pub fn mpims(&self) -> impl Iterator<Item = &Mpim> {
+        self.mpims.values()
+    }
+
+    /// Returns the user with the ID `id`.
+    pub fn user(&self, id: &UserId) -> Option<&User> {
+        self.users.get(id)
+    }
+
+    /// Returns the user with the username `name`.
+    pub fn user_by_name(&self, name: &str) -> Option<&User> {
+        self.users.get_by_name(name)
+    }
+
+    /// Returns every user, in no particular order.
+    pub fn users(&self) -> impl Iterator<Item = &User> {
+        self.users.values()
+    }
+
+    /// Returns the bot with the ID `id`.
+    pub fn bot(&self, id: &BotId) -> Option<&Bot> {
+        self.bots.get(id)
+    }
+
+    /// Returns the bot named `name`.
+    pub fn bot_by_name(&self, name: &str) -> Option<&Bot> {
+        self.bots.get_by_name(name)
+    }
+
+    /// Returns every bot, in no particular order.
+    pub fn bots(&self) -> impl Iterator<Item = &Bot> {
+        self.bots.values()
+    }
+
+    /// Applies the changes `event` makes to the workspace.
+    pub fn update(&mut self, event: &Event) {
+        match *event {
+            Event::BotAdded(ref added) => self.bots.insert(added.bot.clone()),
+            Event::BotChanged(ref changed) => self.bots.insert(changed.bot.clone()),
+            Event::ChannelArchive(ref archive) => self
+                .channels
+                .update(&archive.channel, |channel| channel.is_archived = Some(true)),
+            Event::ChannelCreated(ref created) => self.channels.insert(created.channel.clone()),
+            Event::ChannelDeleted(ref deleted) => self.channels.remove(&deleted.channel),
+            Event::ChannelJoined(ref joined) => self.channels.insert(joined.channel.clone()),
+            // Channels and groups that are left are kept, without the connected user as a member
+            Event::ChannelLeft(ref left) => {
+                let slf = self.slf.as_ref().and_then(|slf| slf.id.as_ref());
+                self.channels.update(&left.channel, |channel| {
+                    channel.is_member = Some(false);
+                    if let Some(slf) = slf {
+                        remove_member(&mut channel.members, slf);
+                    }
+                })
+            }
+            // The payload of a rename only has the ID, name and creation time, so channels that are
+            // not known yet are left out rather than added with nothing else known about them
+            Event::ChannelRename(ChannelRename { ref channel, .. }) => {
+                if let Some(ref id) = channel.id {
+                    self.channels.update(id, |renamed| {
+                        renamed.name = channel.name.clone();
+                        renamed.name_normalized = channel.name_normalized.clone().or_else(|| channel.name.clone());
+                    })
+                }
+            }
+            Event::ChannelUnarchive(ref unarchive) => self
+                .channels
+                .update(&unarchive.channel, |channel| channel.is_archived = Some(false)),
+            Event::GroupArchive(ref archive) => self
+                .groups
+                .update(&archive.channel, |group| group.is_archived = Some(true)),
+            Event::GroupJoined(ref joined) => self.groups.insert(joined.channel.clone()),
+            Event::GroupLeft(ref left) => {
+                if let Some(slf) = self.slf.as_ref().and_then(|slf| slf.id.as_ref()) {
+                    self.groups
+                        .update(&left.channel, |group| remove_member(&mut group.members, slf))
+                }
+            }
+            Event::GroupRename(GroupRename { ref channel, .. }) => {
+                if let Some(ref id) = channel.id {
+                    self.groups.update(id, |renamed| renamed.name = channel.name.clone())
+                }
+            }
+            Event::GroupUnarchive(ref unarchive) => self
+                .groups
+                .update(&unarchive.channel, |group| group.is_archived = Some(false)),
+            Event::ImCreated(ref created) => {
+                let mut im = created.channel.clone();
+                im.user.get_or_insert_with(|| created.user.clone());
+                self.ims.insert(im);
+            }
+            Event::MemberJoinedChannel(MemberJoinedChannel {
+                ref channel, ref user, ..
+            }) => {
+                let is_slf = self.slf_id() == Some(user);
+                self.channels.update(channel, |channel| {
+                    add_member(&mut channel.members, user);
+                    if is_slf {
+                        channel.is_member = Some(true);
+                    }
+                });
+                self.groups
+                    .update(channel, |group| add_member(&mut group.members, user));
+                self.mpims.update(channel, |mpim| add_member(&mut mpim.members, user));
+            }
+            Event::MemberLeftChannel(MemberLeftChannel {
+                ref channel, ref user, ..
+            }) => {
+                let is_slf = self.slf_id() == Some(user);
+                self.channels.update(channel, |channel| {
+                    remove_member(&mut channel.members, user);
+                    if is_slf {
+                        channel.is_member = Some(false);
+                    }
+                });
+                self.groups
+                    .update(channel, |group| remove_member(&mut group.members, user));
+                self.mpims
+                    .update(channel, |mpim| remove_member(&mut mpim.members, user));
+            }
+            Event::TeamDomainChange(ref change) => {
+                if let Some(ref mut team) = self.team {
+                    team.domain = Some(change.domain.clone());
+                }
+            }
+            Event::TeamJoin(ref join) => self.users.insert(join.user.clone()),
+            Event::TeamRename(ref rename) => {
+                if let Some(ref mut team) = self.team {
+                    team.name = Some(rename.name.clone());
+                }
+            }
+            Event::UserChange(ref change) => self.users.insert(change.user.clone()),
+            _ => {}
+        }
+    }
+
+    fn slf_id(&self) -> Option<&UserId> {
+        self.slf.as_ref().and_then(|slf| slf.id.as_ref())
+    }
+}
+
+/// Adds `user` to `members`, unless the members are not known.
+fn add_member(members: &mut Option<Vec<UserId>>, user: &UserId) {
+    if let Some(ref mut members) = *members {
+        if !members.contains(user) {
+            members.push(user.clone());
+        }
+    }
+}
+
+fn remove_member(members: &mut Option<Vec<UserId>>, user: &UserId) {
+    if let Some(ref mut members) = *members {
+        members.retain(|member| member != user);
+    }
+}
+
+/// An object that a [`Workspace`] keeps, by its ID and by a name.
+trait Entry {
+    type Id: Clone + Eq + Hash;
+
+    fn id(&self) -> Option<&Self::Id>;
+
+    /// Returns the name the object is looked up by, if it has one.
+    fn name(&self) -> Option<&str>;
+}
+
+macro_rules! entry {
+    ($ty:ty, $id:ty, $name:ident) => {
+        impl Entry for $ty {
+            type Id = $id;
+
+            fn id(&self) -> Option<&$id> {
+                self.id.as_ref()
+            }
+
+            fn name(&self) -> Option<&str> {
+                self.$name.as_ref().map(AsRef::as_ref)
+            }
+        }
+    };
+}
+
+entry!(Bot, BotId, name);
+entry!(Channel, ChannelId, name);
+entry!(Group, ChannelId, name);
+// An IM has no name, and is looked up by the user it is with instead
+entry!(Im, ChannelId, user);
+entry!(Mpim, ChannelId, name);
+entry!(User, UserId, name);
+
+/// Objects by their ID, along with the ID of each name.
+#[derive(Clone, Debug)]
+struct Index<K, V> {
+    by_id: HashMap<K, V>,
+    by_name: HashMap<String, K>,
+}
+
+impl<K, V> Default for Index<K, V> {
+    fn default() -> Self {
+        Index {
+            by_id: HashMap::new(),
+            by_name: HashMap::new(),
+        }
+    }
+}
+
+impl<V: Entry> Index<V::Id, V> {
+    fn get(&self, id: &V::Id) -> Option<&V> {
+        self.by_id.get(id)
+    }
+
+    fn get_by_name(&self, name: &str) -> Option<&V> {
+        self.by_name.get(name).and_then(|id| self.by_id.get(id))
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.by_id.values()
+    }
+
+    /// Adds `value`, or replaces the object with its ID.
+    fn insert(&mut self, value: V) {
+        let id = match value.id() {
+            Some(id) => id.clone(),
+            None => return,
+        };
+        self.remove(&id);
+        if let Some(name) = value.name() {
+            self.by_name.insert(name.to_owned(), id.clone());
+        }
+        self.by_id.insert(id, value);
+    }
+
+    /// Changes the object with the ID `id`, if there is one.
+    fn update<F: FnOnce(&mut V)>(&mut self, id: &V::Id, f: F) {
+        if let Some(mut value) = self.take(id) {
+            f(&mut value);
+            self.insert(value);
+        }
+    }
+
+    fn remove(&mut self, id: &V::Id) {
+        self.take(id);
+    }
+
+    fn take(&mut self, id: &V::Id) -> Option<V> {
+        let value = self.by_id.remove(id)?;
+        if let Some(name) = value.name() {
+            if self.by_name.get(name) == Some(id) {
+                self.by_name.remove(name);
+            }
+        }
+        Some(value)
+    }
+}
+
+impl<V: Entry> FromIterator<V> for Index<V::Id, V> {
+    fn from_iter<I: IntoIterator<Item = V>>(values: I) -> Self {
+        let mut index = Index::default();
+        for value in values {
+            index.insert(value);
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Workspace;
+    use crate::rtm::StartResponse;
+    use crate::{ChannelId, Event, UserId};
+
+    /// Returns a workspace where `bot` is the connected user and is in `#general` and `secret`
+    /// with `alice`.
+    fn workspace() -> Workspace {
+        let response: StartResponse = serde_json::from_str(
+            r#"{
+                "ok": true,
+                "self": {"id": "U0000000001", "name": "bot"},
+                "team": {"id": "T0000000001", "name": "Team", "domain": "team"},
+                "channels": [{
+                    "id": "C0000000001", "name": "general", "is_member": true,
+                    "members": ["U0000000001", "U0000000002"]
+                }],
+                "groups": [{"id": "G0000000001", "name": "secret", "members": ["U0000000001", "U0000000002"]}],
+                "users": [{"id": "U0000000001", "name": "bot"}, {"id": "U0000000002", "name": "alice"}],
+                "bots": [{"id": "B0000000001", "name": "helper"}]
+            }"#,
+        )
+        .unwrap();
+        Workspace::from_start(response)
+    }
+
+    fn updated(json: &str) -> Workspace {
+        let mut workspace = workspace();
+        workspace.update(&serde_json::from_str::<Event>(json).unwrap());
+        workspace
+    }
+
+    fn id<T: std::str::FromStr>(id: &str) -> T
+    where
+        T::Err: std::fmt::Debug,
+    {
+        id.parse().unwrap()
+    }
+
+    fn general() -> ChannelId {
+        id("C0000000001")
+    }
+
+    fn secret() -> ChannelId {
+        id("G0000000001")
+    }
+
+    fn members(ids: &[&str]) -> Option<Vec<UserId>> {
+        Some(ids.iter().map(|&user| id(user)).collect())
+    }
+
+    #[test]
+    fn test_bot_added() {
+        let workspace = updated(r#"{"type": "bot_added", "bot": {"id": "B0000000002", "name": "deployer"}}"#);
+        assert_eq!(
+            Some(&id("B0000000002")),
+            workspace.bot_by_name("deployer").unwrap().id.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_bot_changed() {
+        let workspace = updated(r#"{"type": "bot_changed", "bot": {"id": "B0000000001", "name": "assistant"}}"#);
+        assert!(workspace.bot_by_name("helper").is_none());
+        assert_eq!(
+            Some("assistant"),
+            workspace.bot(&id("B0000000001")).unwrap().name.as_deref()
+        );
+    }
+
+    #[test]
+    fn test_channel_archive() {
+        let workspace = updated(r#"{"type": "channel_archive", "channel": "C0000000001", "user": "U0000000002"}"#);
+        assert_eq!(Some(true), workspace.channel(&general()).unwrap().is_archived);
+    }
+
+    #[test]
+    fn test_channel_created() {
+        let workspace = updated(r#"{"type": "channel_created", "channel": {"id": "C0000000002", "name": "random"}}"#);
+        assert_eq!(
+            Some(&id("C0000000002")),
+            workspace.channel_by_name("random").unwrap().id.as_ref()
+        );
+        assert_eq!(2, workspace.channels().count());
+    }
+
+    #[test]
+    fn test_channel_deleted() {
+        let workspace = updated(r#"{"type": "channel_deleted", "channel": "C0000000001"}"#);
+        assert!(workspace.channel(&general()).is_none());
+        assert!(workspace.channel_by_name("general").is_none());
+    }
+
+    #[test]
+    fn test_channel_joined() {
+        let workspace = updated(
+            r#"{"type": "channel_joined", "channel": {"id": "C0000000002", "name": "random", "is_member": true}}"#,
+        );
+        assert_eq!(Some(true), workspace.channel_by_name("random").unwrap().is_member);
+    }
+
+    #[test]
+    fn test_channel_left() {
+        let workspace = updated(r#"{"type": "channel_left", "channel": "C0000000001"}"#);
+        let channel = workspace.channel(&general()).unwrap();
+        assert_eq!(Some(false), channel.is_member);
+        assert_eq!(members(&["U0000000002"]), channel.members);
+    }
+
+    #[test]
+    fn test_channel_rename() {
+        let workspace = updated(r#"{"type": "channel_rename", "channel": {"id": "C0000000001", "name": "lobby"}}"#);
+        assert!(workspace.channel_by_name("general").is_none());
+        assert_eq!(
+            Some(&general()),
+            workspace.channel_by_name("lobby").unwrap().id.as_ref()
+        );
+        assert_eq!(
+            members(&["U0000000001", "U0000000002"]),
+            workspace.channel(&general()).unwrap().members
+        );
+
+        let workspace = updated(r#"{"type": "channel_rename", "channel": {"id": "C0000000009", "name": "elsewhere"}}"#);
+        assert!(workspace.channel_by_name("elsewhere").is_none());
+        assert_eq!(1, workspace.channels().count());
+    }
+
+    #[test]
+    fn test_channel_unarchive() {
+        let mut workspace = updated(r#"{"type": "channel_archive", "channel": "C0000000001"}"#);
+        workspace.update(&serde_json::from_str(r#"{"type": "channel_unarchive", "channel": "C0000000001"}"#).unwrap());
+        assert_eq!(Some(false), workspace.channel(&general()).unwrap().is_archived);
+    }
+
+    #[test]
+    fn test_group_archive() {
+        let workspace = updated(r#"{"type": "group_archive", "channel": "G0000000001"}"#);
+        assert_eq!(Some(true), workspace.group(&secret()).unwrap().is_archived);
+    }
+
+    #[test]
+    fn test_group_joined() {
+        let workspace = updated(r#"{"type": "group_joined", "channel": {"id": "G0000000002", "name": "plans"}}"#);
+        assert_eq!(
+            Some(&id("G0000000002")),
+            workspace.group_by_name("plans").unwrap().id.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_group_left() {
+        let workspace = updated(r#"{"type": "group_left", "channel": "G0000000001"}"#);
+        assert_eq!(
+            members(&["U0000000002"]),
+            workspace.group_by_name("secret").unwrap().members
+        );
+    }
+
+    #[test]
+    fn test_group_rename() {
+        let workspace = updated(r#"{"type": "group_rename", "channel": {"id": "G0000000001", "name": "plans"}}"#);
+        assert!(workspace.group_by_name("secret").is_none());
+        assert_eq!(Some(&secret()), workspace.group_by_name("plans").unwrap().id.as_ref());
+
+        let workspace = updated(r#"{"type": "group_rename", "channel": {"id": "G0000000009", "name": "elsewhere"}}"#);
+        assert!(workspace.group_by_name("elsewhere").is_none());
+        assert_eq!(1, workspace.groups().count());
+    }
+
+    #[test]
+    fn test_group_unarchive() {
+        let mut workspace = updated(r#"{"type": "group_archive", "channel": "G0000000001"}"#);
+        workspace.update(&serde_json::from_str(r#"{"type": "group_unarchive", "channel": "G0000000001"}"#).unwrap());
+        assert_eq!(Some(false), workspace.group(&secret()).unwrap().is_archived);
+    }
+
+    #[test]
+    fn test_im_created() {
+        let workspace = updated(r#"{"type": "im_created", "user": "U0000000002", "channel": {"id": "D0000000001"}}"#);
+        let im = workspace.im_with_user(&id("U0000000002")).unwrap();
+        assert_eq!(Some(&id("D0000000001")), im.id.as_ref());
+    }
+
+    #[test]
+    fn test_member_joined_channel() {
+        let workspace =
+            updated(r#"{"type": "member_joined_channel", "channel": "G0000000001", "user": "U0000000003"}"#);
+        let expected = members(&["U0000000001", "U0000000002", "U0000000003"]);
+        assert_eq!(expected, workspace.group(&secret()).unwrap().members);
+
+        let mut workspace = updated(r#"{"type": "channel_left", "channel": "C0000000001"}"#);
+        let json = r#"{"type": "member_joined_channel", "channel": "C0000000001", "user": "U0000000001"}"#;
+        workspace.update(&serde_json::from_str(json).unwrap());
+        let channel = workspace.channel(&general()).unwrap();
+        assert_eq!(Some(true), channel.is_member);
+        assert_eq!(members(&["U0000000002", "U0000000001"]), channel.members);
+    }
+
+    #[test]
+    fn test_member_left_channel() {
+        let workspace = updated(r#"{"type": "member_left_channel", "channel": "C0000000001", "user": "U0000000002"}"#);
+        let channel = workspace.channel(&general()).unwrap();
+        assert_eq!(Some(true), channel.is_member);
+        assert_eq!(members(&["U0000000001"]), channel.members);
+
+        let workspace = updated(r#"{"type": "member_left_channel", "channel": "C0000000001", "user": "U0000000001"}"#);
+        assert_eq!(Some(false), workspace.channel(&general()).unwrap().is_member);
+    }
+
+    #[test]
+    fn test_team_domain_change() {
+        let workspace =
+            updated(r#"{"type": "team_domain_change", "url": "https://crew.slack.com/", "domain": "crew"}"#);
+        assert_eq!(Some("crew"), workspace.team().unwrap().domain.as_deref());
+    }
+
+    #[test]
+    fn test_team_join() {
+        let workspace = updated(r#"{"type": "team_join", "user": {"id": "U0000000003", "name": "bob"}}"#);
+        assert_eq!(
+            Some(&id("U0000000003")),
+            workspace.user_by_name("bob").unwrap().id.as_ref()
+        );
+    }
+
+    #[test]
+    fn test_team_rename() {
+        let workspace = updated(r#"{"type": "team_rename", "name": "Crew"}"#);
+        assert_eq!(Some("Crew"), workspace.team().unwrap().name.as_deref());
+    }
+
+    #[test]
+    fn test_user_change() {
+        let workspace = updated(r#"{"type": "user_change", "user": {"id": "U0000000002", "name": "alice.b"}}"#);
+        assert!(workspace.user_by_name("alice").is_none());
+        assert_eq!(
+            Some("alice.b"),
+            workspace.user(&id("U0000000002")).unwrap().name.as_deref()
+        );
+    }
+}